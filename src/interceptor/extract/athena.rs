@@ -0,0 +1,430 @@
+//! Athena attribute extraction following the OTel database semantic
+//! conventions.
+//!
+//! This module provides [`AthenaExtractor`], which implements
+//! [`super::super::AttributeExtractor`] for the Athena `StartQueryExecution`,
+//! `GetQueryExecution`, `GetQueryResults` and `StopQueryExecution` SDK calls.
+//! It is automatically used by [`super::super::DefaultExtractor`] when the
+//! `extract-athena` feature is enabled.
+//!
+//! Queries run asynchronously: the query execution ID returned on submission
+//! is recorded on the submit span and on every span polling or reading that
+//! query, so they can be tied together.
+//!
+//! ## Extracted attributes
+//!
+//! **Set from input:**
+//! - `db.system.name` — always `"aws.athena"`
+//! - `db.query.text` — for `StartQueryExecution`, the query with literals
+//!   replaced by `?`
+//! - `db.namespace` — for `StartQueryExecution`, the catalog and database,
+//!   joined with `|` when both are given
+//! - `aws.athena.workgroup` — for `StartQueryExecution`, the workgroup, when
+//!   given
+//! - `aws.athena.query_execution_id` — for `GetQueryExecution`,
+//!   `GetQueryResults` and `StopQueryExecution`, the query polled
+//!
+//! **Set from output:**
+//! - `aws.athena.query_execution_id` — for `StartQueryExecution`, the query
+//!   submitted
+//! - for `GetQueryExecution`, `db.query.text`, `db.namespace` and
+//!   `aws.athena.workgroup` as above, and:
+//!   - `aws.athena.query_execution.state` — the query state, e.g.
+//!     `"SUCCEEDED"`
+//!   - `aws.athena.data_scanned_bytes` — the bytes scanned by the query
+//!   - `aws.athena.engine_execution_time_ms` — the time the query ran, in
+//!     milliseconds
+//!   - `aws.athena.total_execution_time_ms` — the time from submission to
+//!     completion, queueing included, in milliseconds
+//!
+//! Execution parameters and result rows are never recorded.
+
+// Athena attribute extraction — downcasts Input/Output to concrete
+// aws-sdk-athena types and extracts query, database and statistics
+// attributes.
+
+use aws_sdk_athena::operation::{
+    get_query_execution::{GetQueryExecutionInput, GetQueryExecutionOutput},
+    get_query_results::GetQueryResultsInput,
+    start_query_execution::{StartQueryExecutionInput, StartQueryExecutionOutput},
+    stop_query_execution::StopQueryExecutionInput,
+};
+use aws_sdk_athena::types::QueryExecutionContext;
+use aws_smithy_runtime_api::client::interceptors::context;
+use opentelemetry_semantic_conventions::attribute as semco;
+
+use super::super::{AttributeExtractor, SpanWrite};
+use super::sanitize::sanitize_literals;
+use super::set_str;
+
+/// `db.system.name` value for Athena.
+const DB_SYSTEM_NAME_AWS_ATHENA: &str = "aws.athena";
+
+/// Attribute key for the workgroup.
+const AWS_ATHENA_WORKGROUP: &str = "aws.athena.workgroup";
+/// Attribute key for the query execution ID.
+const AWS_ATHENA_QUERY_EXECUTION_ID: &str = "aws.athena.query_execution_id";
+/// Attribute key for the query state.
+const AWS_ATHENA_QUERY_EXECUTION_STATE: &str = "aws.athena.query_execution.state";
+/// Attribute key for the bytes scanned by the query.
+const AWS_ATHENA_DATA_SCANNED_BYTES: &str = "aws.athena.data_scanned_bytes";
+/// Attribute key for the time the query ran, in milliseconds.
+const AWS_ATHENA_ENGINE_EXECUTION_TIME_MS: &str = "aws.athena.engine_execution_time_ms";
+/// Attribute key for the time from submission to completion, in milliseconds.
+const AWS_ATHENA_TOTAL_EXECUTION_TIME_MS: &str = "aws.athena.total_execution_time_ms";
+
+/// Attribute extractor for Athena SDK calls.
+///
+/// `AthenaExtractor` implements [`AttributeExtractor`] and is automatically
+/// used by [`DefaultExtractor`] when the `extract-athena` feature is enabled.
+/// You only need to construct it directly if you are composing a custom
+/// extraction pipeline.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes.
+///
+/// [`DefaultExtractor`]: crate::interceptor::DefaultExtractor
+#[derive(Debug, Default)]
+pub struct AthenaExtractor {
+    _private: (),
+}
+
+impl AthenaExtractor {
+    /// Creates a new `AthenaExtractor`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::extract::athena::AthenaExtractor;
+    ///
+    /// let extractor = AthenaExtractor::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+/// Extracts database OTel attributes from Athena SDK inputs and outputs.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes and which operations they apply to.
+impl<SW: SpanWrite> AttributeExtractor<SW> for AthenaExtractor {
+    fn extract_input(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        input: &context::Input,
+        span: &mut SW,
+    ) {
+        match operation {
+            "StartQueryExecution" => {
+                let i = input
+                    .downcast_ref::<StartQueryExecutionInput>()
+                    .expect("correct type");
+                span.set_attribute(
+                    crate::interceptor::DB_SYSTEM_NAME,
+                    DB_SYSTEM_NAME_AWS_ATHENA,
+                );
+                set_query(
+                    span,
+                    i.query_string(),
+                    i.query_execution_context(),
+                    i.work_group(),
+                );
+            }
+            "GetQueryExecution" => {
+                let i = input
+                    .downcast_ref::<GetQueryExecutionInput>()
+                    .expect("correct type");
+                span.set_attribute(
+                    crate::interceptor::DB_SYSTEM_NAME,
+                    DB_SYSTEM_NAME_AWS_ATHENA,
+                );
+                set_str(span, AWS_ATHENA_QUERY_EXECUTION_ID, i.query_execution_id());
+            }
+            "GetQueryResults" => {
+                let i = input
+                    .downcast_ref::<GetQueryResultsInput>()
+                    .expect("correct type");
+                span.set_attribute(
+                    crate::interceptor::DB_SYSTEM_NAME,
+                    DB_SYSTEM_NAME_AWS_ATHENA,
+                );
+                set_str(span, AWS_ATHENA_QUERY_EXECUTION_ID, i.query_execution_id());
+            }
+            "StopQueryExecution" => {
+                let i = input
+                    .downcast_ref::<StopQueryExecutionInput>()
+                    .expect("correct type");
+                span.set_attribute(
+                    crate::interceptor::DB_SYSTEM_NAME,
+                    DB_SYSTEM_NAME_AWS_ATHENA,
+                );
+                set_str(span, AWS_ATHENA_QUERY_EXECUTION_ID, i.query_execution_id());
+            }
+            _ => {}
+        }
+    }
+
+    fn extract_output(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        output: &context::Output,
+        span: &mut SW,
+    ) {
+        match operation {
+            "StartQueryExecution" => {
+                let o = output
+                    .downcast_ref::<StartQueryExecutionOutput>()
+                    .expect("correct type");
+                set_str(span, AWS_ATHENA_QUERY_EXECUTION_ID, o.query_execution_id());
+            }
+            "GetQueryExecution" => {
+                let o = output
+                    .downcast_ref::<GetQueryExecutionOutput>()
+                    .expect("correct type");
+                let Some(execution) = o.query_execution() else {
+                    return;
+                };
+                set_query(
+                    span,
+                    execution.query(),
+                    execution.query_execution_context(),
+                    execution.work_group(),
+                );
+                set_str(
+                    span,
+                    AWS_ATHENA_QUERY_EXECUTION_STATE,
+                    execution
+                        .status()
+                        .and_then(|status| status.state())
+                        .map(|state| state.as_str()),
+                );
+                if let Some(statistics) = execution.statistics() {
+                    for (key, value) in [
+                        (
+                            AWS_ATHENA_DATA_SCANNED_BYTES,
+                            statistics.data_scanned_in_bytes(),
+                        ),
+                        (
+                            AWS_ATHENA_ENGINE_EXECUTION_TIME_MS,
+                            statistics.engine_execution_time_in_millis(),
+                        ),
+                        (
+                            AWS_ATHENA_TOTAL_EXECUTION_TIME_MS,
+                            statistics.total_execution_time_in_millis(),
+                        ),
+                    ] {
+                        if let Some(value) = value {
+                            span.set_attribute(key, value);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Sets the sanitized query text, the `db.namespace` built from the catalog
+/// and database, and the workgroup of a query.
+fn set_query(
+    span: &mut impl SpanWrite,
+    query: Option<&str>,
+    context: Option<&QueryExecutionContext>,
+    work_group: Option<&str>,
+) {
+    if let Some(query) = query.filter(|s| !s.is_empty()) {
+        span.set_attribute(
+            semco::DB_QUERY_TEXT,
+            sanitize_literals(query, &['\''], &['"', '`'], false, false),
+        );
+    }
+    if let Some(context) = context {
+        let namespace: Vec<&str> = [context.catalog(), context.database()]
+            .into_iter()
+            .flatten()
+            .filter(|s| !s.is_empty())
+            .collect();
+        if !namespace.is_empty() {
+            span.set_attribute(semco::DB_NAMESPACE, namespace.join("|"));
+        }
+    }
+    set_str(span, AWS_ATHENA_WORKGROUP, work_group);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_athena::types::{
+        QueryExecution, QueryExecutionState, QueryExecutionStatistics, QueryExecutionStatus,
+    };
+    use opentelemetry::Value;
+
+    use crate::span_write::{SpanContext, SpanWrite, Status};
+
+    struct TestSpan {
+        attributes: Vec<(&'static str, Value)>,
+    }
+
+    impl TestSpan {
+        fn new() -> Self {
+            Self { attributes: vec![] }
+        }
+
+        fn get(&self, key: &str) -> Option<&Value> {
+            self.attributes
+                .iter()
+                .rev()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v)
+        }
+    }
+
+    impl SpanWrite for TestSpan {
+        fn set_attribute(&mut self, key: &'static str, value: impl Into<Value>) {
+            self.attributes.push((key, value.into()));
+        }
+
+        fn set_status(&mut self, _code: Status) {}
+
+        fn span_context(&self) -> SpanContext {
+            SpanContext::empty_context()
+        }
+    }
+
+    const QUERY_EXECUTION_ID: &str = "a1b2c3d4-5678-90ab-cdef-EXAMPLE11111";
+    const QUERY: &str = "SELECT * FROM \"logs\" WHERE status = 500 AND path = '/login'";
+    const SANITIZED_QUERY: &str = "SELECT * FROM \"logs\" WHERE status = ? AND path = ?";
+
+    fn query_execution_context() -> QueryExecutionContext {
+        QueryExecutionContext::builder()
+            .catalog("AwsDataCatalog")
+            .database("weblogs")
+            .build()
+    }
+
+    // Tests for AthenaExtractor — 2 consolidated tests
+
+    #[test]
+    fn extract_start_query_execution() {
+        let extractor = AthenaExtractor::new();
+
+        let sdk_input = StartQueryExecutionInput::builder()
+            .query_string(QUERY)
+            .query_execution_context(query_execution_context())
+            .work_group("primary")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "Athena",
+            "StartQueryExecution",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(crate::interceptor::DB_SYSTEM_NAME),
+            Some(&Value::from("aws.athena"))
+        );
+        assert_eq!(
+            span.get(semco::DB_QUERY_TEXT),
+            Some(&Value::from(SANITIZED_QUERY))
+        );
+        assert_eq!(
+            span.get(semco::DB_NAMESPACE),
+            Some(&Value::from("AwsDataCatalog|weblogs"))
+        );
+        assert_eq!(
+            span.get(AWS_ATHENA_WORKGROUP),
+            Some(&Value::from("primary"))
+        );
+
+        extractor.extract_output(
+            "Athena",
+            "StartQueryExecution",
+            &context::Output::erase(
+                StartQueryExecutionOutput::builder()
+                    .query_execution_id(QUERY_EXECUTION_ID)
+                    .build(),
+            ),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_ATHENA_QUERY_EXECUTION_ID),
+            Some(&Value::from(QUERY_EXECUTION_ID))
+        );
+    }
+
+    #[test]
+    fn extract_get_query_execution() {
+        let extractor = AthenaExtractor::new();
+
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "Athena",
+            "GetQueryExecution",
+            &context::Input::erase(
+                GetQueryExecutionInput::builder()
+                    .query_execution_id(QUERY_EXECUTION_ID)
+                    .build()
+                    .unwrap(),
+            ),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_ATHENA_QUERY_EXECUTION_ID),
+            Some(&Value::from(QUERY_EXECUTION_ID))
+        );
+
+        let sdk_output = GetQueryExecutionOutput::builder()
+            .query_execution(
+                QueryExecution::builder()
+                    .query_execution_id(QUERY_EXECUTION_ID)
+                    .query(QUERY)
+                    .query_execution_context(query_execution_context())
+                    .work_group("primary")
+                    .status(
+                        QueryExecutionStatus::builder()
+                            .state(QueryExecutionState::Succeeded)
+                            .build(),
+                    )
+                    .statistics(
+                        QueryExecutionStatistics::builder()
+                            .data_scanned_in_bytes(10_485_760)
+                            .engine_execution_time_in_millis(2_300)
+                            .total_execution_time_in_millis(2_750)
+                            .build(),
+                    )
+                    .build(),
+            )
+            .build();
+        extractor.extract_output(
+            "Athena",
+            "GetQueryExecution",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(semco::DB_QUERY_TEXT),
+            Some(&Value::from(SANITIZED_QUERY))
+        );
+        assert_eq!(
+            span.get(AWS_ATHENA_QUERY_EXECUTION_STATE),
+            Some(&Value::from("SUCCEEDED"))
+        );
+        assert_eq!(
+            span.get(AWS_ATHENA_DATA_SCANNED_BYTES),
+            Some(&Value::I64(10_485_760))
+        );
+        assert_eq!(
+            span.get(AWS_ATHENA_ENGINE_EXECUTION_TIME_MS),
+            Some(&Value::I64(2_300))
+        );
+        assert_eq!(
+            span.get(AWS_ATHENA_TOTAL_EXECUTION_TIME_MS),
+            Some(&Value::I64(2_750))
+        );
+    }
+}
@@ -0,0 +1,922 @@
+//! Bedrock Runtime attribute extraction following the OTel GenAI semantic
+//! conventions.
+//!
+//! This module provides [`BedrockExtractor`], which implements
+//! [`super::super::AttributeExtractor`] for the Bedrock Runtime `Converse`,
+//! `ConverseStream`, `InvokeModel` and `InvokeModelWithResponseStream` SDK
+//! calls. It is automatically used by [`super::super::DefaultExtractor`] when
+//! the `extract-bedrock` feature is enabled.
+//!
+//! ## Extracted attributes
+//!
+//! **Set from input:**
+//! - `gen_ai.system` — always `"aws.bedrock"`
+//! - `gen_ai.operation.name` — `"chat"` for `Converse` and `ConverseStream`;
+//!   for `InvokeModel`, `"chat"`, `"text_completion"` or `"embeddings"`
+//!   depending on the model and request body
+//! - `gen_ai.request.model` — the model ID, inference profile or ARN
+//! - `gen_ai.request.max_tokens`, `gen_ai.request.temperature`,
+//!   `gen_ai.request.top_p`, `gen_ai.request.stop_sequences` — the inference
+//!   parameters, when given
+//!
+//! **Set from the response and output:**
+//! - `gen_ai.usage.input_tokens`, `gen_ai.usage.output_tokens` — the token
+//!   usage, from the `Converse` output or the `InvokeModel` response headers
+//! - `gen_ai.response.finish_reasons` — the reasons the model stopped
+//!   generating
+//!
+//! `InvokeModel` bodies are model-specific. The inference parameters and
+//! finish reasons are read from the JSON body of the common model families
+//! (Anthropic, Amazon Nova and Titan, Meta Llama, Mistral, Cohere); other
+//! bodies only yield the model and token usage.
+//!
+//! The response of the streaming operations is consumed after the SDK call
+//! returns, so their spans carry the request attributes only.
+//!
+//! ## Message content
+//!
+//! Prompts and completions may hold sensitive data and are not recorded by
+//! default. When content capture is enabled, the extractor also records:
+//! - `gen_ai.system_instructions` — the system prompt
+//! - `gen_ai.input.messages` — the input messages
+//! - `gen_ai.output.messages` — for `Converse` and `InvokeModel`, the
+//!   generated messages
+//!
+//! each as a JSON array of messages made of typed parts, following the GenAI
+//! semantic conventions. Content capture is enabled by setting the
+//! `OTEL_INSTRUMENTATION_GENAI_CAPTURE_MESSAGE_CONTENT` environment variable
+//! to `true`, or with [`BedrockExtractor::with_content_capture`].
+
+// Bedrock Runtime attribute extraction — downcasts Input/Output to concrete
+// aws-sdk-bedrockruntime types and extracts GenAI request and usage
+// attributes, reading the JSON body of InvokeModel.
+
+use aws_sdk_bedrockruntime::operation::{
+    converse::{ConverseInput, ConverseOutput},
+    converse_stream::ConverseStreamInput,
+    invoke_model::{InvokeModelInput, InvokeModelOutput},
+    invoke_model_with_response_stream::InvokeModelWithResponseStreamInput,
+};
+use aws_sdk_bedrockruntime::types::{
+    ContentBlock, InferenceConfiguration, Message, SystemContentBlock, ToolResultContentBlock,
+};
+use aws_smithy_runtime_api::client::interceptors::context;
+use aws_smithy_runtime_api::http;
+use aws_smithy_types::{Blob, Document, Number};
+use opentelemetry_semantic_conventions::attribute as semco;
+use serde_json::{Value as Json, json};
+
+use super::super::{AttributeExtractor, SpanWrite};
+use super::set_strings;
+
+/// Environment variable that enables message content capture when `true`.
+const CAPTURE_MESSAGE_CONTENT_ENV_VAR: &str = "OTEL_INSTRUMENTATION_GENAI_CAPTURE_MESSAGE_CONTENT";
+
+/// `gen_ai.system` value for Bedrock.
+const GEN_AI_SYSTEM_AWS_BEDROCK: &str = "aws.bedrock";
+/// Attribute key for the system prompt, recorded when content capture is enabled.
+const GEN_AI_SYSTEM_INSTRUCTIONS: &str = "gen_ai.system_instructions";
+/// Attribute key for the input messages, recorded when content capture is enabled.
+const GEN_AI_INPUT_MESSAGES: &str = "gen_ai.input.messages";
+/// Attribute key for the output messages, recorded when content capture is enabled.
+const GEN_AI_OUTPUT_MESSAGES: &str = "gen_ai.output.messages";
+
+/// Response header holding the input token count of `InvokeModel`.
+const INPUT_TOKEN_COUNT_HEADER: &str = "x-amzn-bedrock-input-token-count";
+/// Response header holding the output token count of `InvokeModel`.
+const OUTPUT_TOKEN_COUNT_HEADER: &str = "x-amzn-bedrock-output-token-count";
+
+/// Objects of an `InvokeModel` request body that hold the inference
+/// parameters, besides the body itself.
+const PARAMETER_OBJECTS: [&str; 3] = ["inferenceConfig", "textGenerationConfig", "parameters"];
+/// Keys of the maximum number of tokens to generate, across model families.
+const MAX_TOKENS_KEYS: [&str; 6] = [
+    "max_tokens",
+    "maxTokens",
+    "max_new_tokens",
+    "max_gen_len",
+    "maxTokenCount",
+    "max_tokens_to_sample",
+];
+/// Keys of the nucleus sampling parameter, across model families.
+const TOP_P_KEYS: [&str; 3] = ["top_p", "topP", "p"];
+/// Arrays of an `InvokeModel` response body that hold one entry per choice.
+const CHOICE_ARRAYS: [&str; 4] = ["results", "outputs", "generations", "choices"];
+/// Keys of the finish reason, across model families.
+const FINISH_REASON_KEYS: [&str; 4] = [
+    "stop_reason",
+    "stopReason",
+    "finish_reason",
+    "completionReason",
+];
+
+/// Attribute extractor for Bedrock Runtime SDK calls.
+///
+/// `BedrockExtractor` implements [`AttributeExtractor`] and is automatically
+/// used by [`DefaultExtractor`] when the `extract-bedrock` feature is enabled.
+/// Construct it directly to turn message content capture on or off regardless
+/// of the environment, then hand it to
+/// [`DefaultExtractor::set_bedrock_extractor`].
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes.
+///
+/// [`DefaultExtractor`]: crate::interceptor::DefaultExtractor
+/// [`DefaultExtractor::set_bedrock_extractor`]: crate::interceptor::DefaultExtractor::set_bedrock_extractor
+#[derive(Debug)]
+pub struct BedrockExtractor {
+    capture_content: bool,
+}
+
+impl BedrockExtractor {
+    /// Creates a new `BedrockExtractor`.
+    ///
+    /// Message content capture is enabled when the
+    /// `OTEL_INSTRUMENTATION_GENAI_CAPTURE_MESSAGE_CONTENT` environment
+    /// variable is `true`, and disabled otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::extract::bedrock::BedrockExtractor;
+    ///
+    /// let extractor = BedrockExtractor::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            capture_content: capture_content_enabled(
+                std::env::var(CAPTURE_MESSAGE_CONTENT_ENV_VAR)
+                    .ok()
+                    .as_deref(),
+            ),
+        }
+    }
+
+    /// Enables or disables recording prompts and completions, overriding the
+    /// `OTEL_INSTRUMENTATION_GENAI_CAPTURE_MESSAGE_CONTENT` environment
+    /// variable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::{
+    ///     DefaultInterceptor, extract::bedrock::BedrockExtractor,
+    /// };
+    ///
+    /// let mut interceptor = DefaultInterceptor::new();
+    /// interceptor
+    ///     .extractor
+    ///     .set_bedrock_extractor(BedrockExtractor::new().with_content_capture(true));
+    /// ```
+    pub fn with_content_capture(mut self, capture: bool) -> Self {
+        self.capture_content = capture;
+        self
+    }
+}
+
+impl Default for BedrockExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Extracts GenAI OTel attributes from Bedrock Runtime SDK inputs, responses
+/// and outputs, and optionally the message content.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes and which operations they apply to.
+impl<SW: SpanWrite> AttributeExtractor<SW> for BedrockExtractor {
+    fn extract_input(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        input: &context::Input,
+        span: &mut SW,
+    ) {
+        match operation {
+            "Converse" => {
+                let i = input.downcast_ref::<ConverseInput>().expect("correct type");
+                set_request(span, "chat", i.model_id());
+                set_inference_config(span, i.inference_config());
+                if self.capture_content {
+                    set_converse_content(span, i.system(), i.messages());
+                }
+            }
+            "ConverseStream" => {
+                let i = input
+                    .downcast_ref::<ConverseStreamInput>()
+                    .expect("correct type");
+                set_request(span, "chat", i.model_id());
+                set_inference_config(span, i.inference_config());
+                if self.capture_content {
+                    set_converse_content(span, i.system(), i.messages());
+                }
+            }
+            "InvokeModel" => {
+                let i = input
+                    .downcast_ref::<InvokeModelInput>()
+                    .expect("correct type");
+                self.set_invoke_model_request(span, i.model_id(), i.body());
+            }
+            "InvokeModelWithResponseStream" => {
+                let i = input
+                    .downcast_ref::<InvokeModelWithResponseStreamInput>()
+                    .expect("correct type");
+                self.set_invoke_model_request(span, i.model_id(), i.body());
+            }
+            _ => {}
+        }
+    }
+
+    fn extract_response(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        response: &http::Response,
+        span: &mut SW,
+    ) {
+        if operation != "InvokeModel" {
+            return;
+        }
+        for (header, key) in [
+            (INPUT_TOKEN_COUNT_HEADER, semco::GEN_AI_USAGE_INPUT_TOKENS),
+            (OUTPUT_TOKEN_COUNT_HEADER, semco::GEN_AI_USAGE_OUTPUT_TOKENS),
+        ] {
+            if let Some(count) = response
+                .headers()
+                .get(header)
+                .and_then(|value| value.trim().parse::<i64>().ok())
+            {
+                span.set_attribute(key, count);
+            }
+        }
+    }
+
+    fn extract_output(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        output: &context::Output,
+        span: &mut SW,
+    ) {
+        match operation {
+            "Converse" => {
+                let o = output
+                    .downcast_ref::<ConverseOutput>()
+                    .expect("correct type");
+                if let Some(usage) = o.usage() {
+                    span.set_attribute(
+                        semco::GEN_AI_USAGE_INPUT_TOKENS,
+                        i64::from(usage.input_tokens()),
+                    );
+                    span.set_attribute(
+                        semco::GEN_AI_USAGE_OUTPUT_TOKENS,
+                        i64::from(usage.output_tokens()),
+                    );
+                }
+                let finish_reason = o.stop_reason().as_str();
+                set_strings(span, semco::GEN_AI_RESPONSE_FINISH_REASONS, [finish_reason]);
+                if self.capture_content {
+                    if let Some(message) = o.output().and_then(|output| output.as_message().ok()) {
+                        let mut message = converse_message(message);
+                        message["finish_reason"] = Json::from(finish_reason);
+                        set_json(span, GEN_AI_OUTPUT_MESSAGES, &Json::Array(vec![message]));
+                    }
+                }
+            }
+            "InvokeModel" => {
+                let o = output
+                    .downcast_ref::<InvokeModelOutput>()
+                    .expect("correct type");
+                let Some(body) = parse_body(Some(o.body())) else {
+                    return;
+                };
+                let choices = invoke_model_choices(&body);
+                set_strings(
+                    span,
+                    semco::GEN_AI_RESPONSE_FINISH_REASONS,
+                    choices.iter().filter_map(|choice| choice.finish_reason),
+                );
+                if self.capture_content && !choices.is_empty() {
+                    let messages = choices
+                        .iter()
+                        .map(|choice| {
+                            let mut message = text_message("assistant", choice.text.as_deref());
+                            if let Some(finish_reason) = choice.finish_reason {
+                                message["finish_reason"] = Json::from(finish_reason);
+                            }
+                            message
+                        })
+                        .collect();
+                    set_json(span, GEN_AI_OUTPUT_MESSAGES, &Json::Array(messages));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl BedrockExtractor {
+    /// Sets the request attributes of `InvokeModel` and
+    /// `InvokeModelWithResponseStream`, reading the parameters and, when
+    /// capture is enabled, the messages from the JSON body.
+    fn set_invoke_model_request(
+        &self,
+        span: &mut impl SpanWrite,
+        model_id: Option<&str>,
+        body: Option<&Blob>,
+    ) {
+        let body = parse_body(body);
+        let operation_name = match (&body, model_id) {
+            (_, Some(model_id)) if model_id.contains("embed") => "embeddings",
+            (Some(body), _) if body.get("messages").is_some() => "chat",
+            _ => "text_completion",
+        };
+        set_request(span, operation_name, model_id);
+        let Some(body) = body else {
+            return;
+        };
+
+        if let Some(max_tokens) = find_parameter(&body, &MAX_TOKENS_KEYS).and_then(Json::as_i64) {
+            span.set_attribute(semco::GEN_AI_REQUEST_MAX_TOKENS, max_tokens);
+        }
+        if let Some(temperature) = find_parameter(&body, &["temperature"]).and_then(Json::as_f64) {
+            span.set_attribute(semco::GEN_AI_REQUEST_TEMPERATURE, temperature);
+        }
+        if let Some(top_p) = find_parameter(&body, &TOP_P_KEYS).and_then(Json::as_f64) {
+            span.set_attribute(semco::GEN_AI_REQUEST_TOP_P, top_p);
+        }
+
+        if self.capture_content {
+            if let Some(system) = body.get("system").and_then(json_text) {
+                set_json(
+                    span,
+                    GEN_AI_SYSTEM_INSTRUCTIONS,
+                    &json!([{ "type": "text", "content": system }]),
+                );
+            }
+            let messages = match body.get("messages").and_then(Json::as_array) {
+                Some(messages) => messages
+                    .iter()
+                    .map(|message| {
+                        text_message(
+                            message.get("role").and_then(Json::as_str).unwrap_or("user"),
+                            message.get("content").and_then(json_text).as_deref(),
+                        )
+                    })
+                    .collect(),
+                None => ["prompt", "inputText"]
+                    .iter()
+                    .find_map(|key| body.get(key).and_then(json_text))
+                    .map(|prompt| vec![text_message("user", Some(&prompt))])
+                    .unwrap_or_default(),
+            };
+            if !messages.is_empty() {
+                set_json(span, GEN_AI_INPUT_MESSAGES, &Json::Array(messages));
+            }
+        }
+    }
+}
+
+/// Returns whether the value of the content capture environment variable
+/// enables capture.
+fn capture_content_enabled(value: Option<&str>) -> bool {
+    value.is_some_and(|value| value.trim().eq_ignore_ascii_case("true"))
+}
+
+/// Sets the attributes common to all requests: system, operation and model.
+fn set_request(span: &mut impl SpanWrite, operation_name: &'static str, model_id: Option<&str>) {
+    span.set_attribute(semco::GEN_AI_SYSTEM, GEN_AI_SYSTEM_AWS_BEDROCK);
+    span.set_attribute(semco::GEN_AI_OPERATION_NAME, operation_name);
+    if let Some(model_id) = model_id.filter(|s| !s.is_empty()) {
+        span.set_attribute(semco::GEN_AI_REQUEST_MODEL, model_id.to_owned());
+    }
+}
+
+/// Sets the inference parameters of `Converse` and `ConverseStream`.
+fn set_inference_config(span: &mut impl SpanWrite, config: Option<&InferenceConfiguration>) {
+    let Some(config) = config else {
+        return;
+    };
+    if let Some(max_tokens) = config.max_tokens() {
+        span.set_attribute(semco::GEN_AI_REQUEST_MAX_TOKENS, i64::from(max_tokens));
+    }
+    if let Some(temperature) = config.temperature() {
+        span.set_attribute(semco::GEN_AI_REQUEST_TEMPERATURE, f32_to_f64(temperature));
+    }
+    if let Some(top_p) = config.top_p() {
+        span.set_attribute(semco::GEN_AI_REQUEST_TOP_P, f32_to_f64(top_p));
+    }
+    set_strings(
+        span,
+        semco::GEN_AI_REQUEST_STOP_SEQUENCES,
+        config.stop_sequences().iter().map(String::as_str),
+    );
+}
+
+/// Widens an `f32` parameter to the `f64` it was written as, so that `0.7`
+/// is recorded as `0.7` rather than `0.699999988079071`.
+fn f32_to_f64(value: f32) -> f64 {
+    value.to_string().parse().unwrap_or(f64::from(value))
+}
+
+/// Sets `key` to the JSON serialization of `value`.
+fn set_json(span: &mut impl SpanWrite, key: &'static str, value: &Json) {
+    if let Ok(json) = serde_json::to_string(value) {
+        span.set_attribute(key, json);
+    }
+}
+
+/// Sets the system instructions and input messages of a `Converse` request.
+fn set_converse_content(
+    span: &mut impl SpanWrite,
+    system: &[SystemContentBlock],
+    messages: &[Message],
+) {
+    let system: Vec<Json> = system
+        .iter()
+        .filter_map(|block| block.as_text().ok())
+        .map(|text| json!({ "type": "text", "content": text }))
+        .collect();
+    if !system.is_empty() {
+        set_json(span, GEN_AI_SYSTEM_INSTRUCTIONS, &Json::Array(system));
+    }
+    if !messages.is_empty() {
+        set_json(
+            span,
+            GEN_AI_INPUT_MESSAGES,
+            &Json::Array(messages.iter().map(converse_message).collect()),
+        );
+    }
+}
+
+/// Converts a `Converse` message to a GenAI message with typed parts. Text,
+/// tool calls and tool results are kept; media and other blocks are skipped.
+fn converse_message(message: &Message) -> Json {
+    let parts: Vec<Json> = message
+        .content()
+        .iter()
+        .filter_map(|block| match block {
+            ContentBlock::Text(text) => Some(json!({ "type": "text", "content": text })),
+            ContentBlock::ToolUse(tool_use) => Some(json!({
+                "type": "tool_call",
+                "id": tool_use.tool_use_id(),
+                "name": tool_use.name(),
+                "arguments": document_to_json(tool_use.input()),
+            })),
+            ContentBlock::ToolResult(tool_result) => Some(json!({
+                "type": "tool_call_response",
+                "id": tool_result.tool_use_id(),
+                "response": tool_result
+                    .content()
+                    .iter()
+                    .filter_map(|content| match content {
+                        ToolResultContentBlock::Text(text) => Some(Json::from(text.as_str())),
+                        ToolResultContentBlock::Json(document) => Some(document_to_json(document)),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>(),
+            })),
+            _ => None,
+        })
+        .collect();
+    json!({ "role": message.role().as_str(), "parts": parts })
+}
+
+/// Converts a Smithy document to JSON.
+fn document_to_json(document: &Document) -> Json {
+    match document {
+        Document::Object(object) => Json::Object(
+            object
+                .iter()
+                .map(|(key, value)| (key.clone(), document_to_json(value)))
+                .collect(),
+        ),
+        Document::Array(array) => Json::Array(array.iter().map(document_to_json).collect()),
+        Document::Number(Number::PosInt(n)) => Json::from(*n),
+        Document::Number(Number::NegInt(n)) => Json::from(*n),
+        Document::Number(Number::Float(n)) => Json::from(*n),
+        Document::String(s) => Json::from(s.as_str()),
+        Document::Bool(b) => Json::from(*b),
+        Document::Null => Json::Null,
+    }
+}
+
+/// Parses an `InvokeModel` body as JSON.
+fn parse_body(body: Option<&Blob>) -> Option<Json> {
+    serde_json::from_slice(body?.as_ref()).ok()
+}
+
+/// Returns the first of `keys` found in the body or in one of its parameter
+/// objects.
+fn find_parameter<'a>(body: &'a Json, keys: &[&str]) -> Option<&'a Json> {
+    std::iter::once(body)
+        .chain(PARAMETER_OBJECTS.iter().filter_map(|key| body.get(key)))
+        .find_map(|object| keys.iter().find_map(|key| object.get(key)))
+}
+
+/// Returns the text of a JSON content value: a string, an array of strings
+/// or text blocks, or a single text block.
+fn json_text(value: &Json) -> Option<String> {
+    match value {
+        Json::String(text) => Some(text.clone()),
+        Json::Array(blocks) => {
+            let texts: Vec<String> = blocks.iter().filter_map(json_text).collect();
+            (!texts.is_empty()).then(|| texts.join("\n"))
+        }
+        Json::Object(block) => block.get("text").and_then(Json::as_str).map(str::to_owned),
+        _ => None,
+    }
+}
+
+/// Builds a GenAI message with a single text part, or no part when `text` is
+/// `None`.
+fn text_message(role: &str, text: Option<&str>) -> Json {
+    let parts: Vec<Json> = text
+        .map(|text| json!({ "type": "text", "content": text }))
+        .into_iter()
+        .collect();
+    json!({ "role": role, "parts": parts })
+}
+
+/// Returns the finish reason of a response body or of one of its choices.
+fn finish_reason(object: &Json) -> Option<&str> {
+    FINISH_REASON_KEYS
+        .iter()
+        .find_map(|key| object.get(key).and_then(Json::as_str))
+}
+
+/// A generated choice read from an `InvokeModel` response body.
+#[derive(Debug, PartialEq)]
+struct Choice<'a> {
+    text: Option<String>,
+    finish_reason: Option<&'a str>,
+}
+
+/// Reads the generated choices of an `InvokeModel` response body, from the
+/// per-choice arrays of Titan, Mistral and Cohere, or from the body itself
+/// for Anthropic, Nova and Llama.
+fn invoke_model_choices(body: &Json) -> Vec<Choice<'_>> {
+    if let Some(entries) = CHOICE_ARRAYS
+        .iter()
+        .find_map(|key| body.get(key).and_then(Json::as_array))
+    {
+        return entries
+            .iter()
+            .map(|entry| Choice {
+                text: ["outputText", "text"]
+                    .iter()
+                    .find_map(|key| entry.get(key).and_then(json_text))
+                    .or_else(|| entry.pointer("/message/content").and_then(json_text)),
+                finish_reason: finish_reason(entry),
+            })
+            .collect();
+    }
+    let text = body
+        .get("content")
+        .or_else(|| body.pointer("/output/message/content"))
+        .or_else(|| body.get("generation"))
+        .or_else(|| body.get("completion"))
+        .and_then(json_text);
+    let finish_reason = finish_reason(body);
+    if text.is_none() && finish_reason.is_none() {
+        return Vec::new();
+    }
+    vec![Choice {
+        text,
+        finish_reason,
+    }]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_bedrockruntime::types::{
+        ConversationRole, ConverseOutput as ConverseOutputBlock, StopReason, TokenUsage,
+    };
+    use aws_smithy_types::body::SdkBody;
+    use opentelemetry::{Array, Value};
+
+    use crate::span_write::{SpanContext, SpanWrite, Status};
+
+    struct TestSpan {
+        attributes: Vec<(&'static str, Value)>,
+    }
+
+    impl TestSpan {
+        fn new() -> Self {
+            Self { attributes: vec![] }
+        }
+
+        fn get(&self, key: &str) -> Option<&Value> {
+            self.attributes
+                .iter()
+                .rev()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v)
+        }
+
+        fn json(&self, key: &str) -> Json {
+            serde_json::from_str(&self.get(key).expect("attribute set").as_str()).unwrap()
+        }
+    }
+
+    impl SpanWrite for TestSpan {
+        fn set_attribute(&mut self, key: &'static str, value: impl Into<Value>) {
+            self.attributes.push((key, value.into()));
+        }
+
+        fn set_status(&mut self, _code: Status) {}
+
+        fn span_context(&self) -> SpanContext {
+            SpanContext::empty_context()
+        }
+    }
+
+    const MODEL_ID: &str = "anthropic.claude-3-haiku-20240307-v1:0";
+
+    fn converse_input() -> ConverseInput {
+        ConverseInput::builder()
+            .model_id(MODEL_ID)
+            .system(SystemContentBlock::Text("Be brief.".into()))
+            .messages(
+                Message::builder()
+                    .role(ConversationRole::User)
+                    .content(ContentBlock::Text("What is the capital of France?".into()))
+                    .build()
+                    .unwrap(),
+            )
+            .inference_config(
+                InferenceConfiguration::builder()
+                    .max_tokens(256)
+                    .temperature(0.7)
+                    .top_p(0.9)
+                    .build(),
+            )
+            .build()
+            .unwrap()
+    }
+
+    fn converse_output() -> ConverseOutput {
+        ConverseOutput::builder()
+            .output(ConverseOutputBlock::Message(
+                Message::builder()
+                    .role(ConversationRole::Assistant)
+                    .content(ContentBlock::Text("Paris.".into()))
+                    .build()
+                    .unwrap(),
+            ))
+            .stop_reason(StopReason::EndTurn)
+            .usage(
+                TokenUsage::builder()
+                    .input_tokens(14)
+                    .output_tokens(3)
+                    .total_tokens(17)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+
+    // Tests for BedrockExtractor Converse extraction — 2 consolidated tests
+
+    #[test]
+    fn extract_converse_without_content() {
+        let extractor = BedrockExtractor::new().with_content_capture(false);
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "Bedrock Runtime",
+            "Converse",
+            &context::Input::erase(converse_input()),
+            &mut span,
+        );
+        extractor.extract_output(
+            "Bedrock Runtime",
+            "Converse",
+            &context::Output::erase(converse_output()),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(semco::GEN_AI_SYSTEM),
+            Some(&Value::from("aws.bedrock"))
+        );
+        assert_eq!(
+            span.get(semco::GEN_AI_OPERATION_NAME),
+            Some(&Value::from("chat"))
+        );
+        assert_eq!(
+            span.get(semco::GEN_AI_REQUEST_MODEL),
+            Some(&Value::from(MODEL_ID))
+        );
+        assert_eq!(
+            span.get(semco::GEN_AI_REQUEST_MAX_TOKENS),
+            Some(&Value::I64(256))
+        );
+        assert_eq!(
+            span.get(semco::GEN_AI_REQUEST_TEMPERATURE),
+            Some(&Value::F64(0.7))
+        );
+        assert_eq!(
+            span.get(semco::GEN_AI_REQUEST_TOP_P),
+            Some(&Value::F64(0.9))
+        );
+        assert_eq!(
+            span.get(semco::GEN_AI_USAGE_INPUT_TOKENS),
+            Some(&Value::I64(14))
+        );
+        assert_eq!(
+            span.get(semco::GEN_AI_USAGE_OUTPUT_TOKENS),
+            Some(&Value::I64(3))
+        );
+        assert_eq!(
+            span.get(semco::GEN_AI_RESPONSE_FINISH_REASONS),
+            Some(&Value::Array(Array::String(vec!["end_turn".into()])))
+        );
+        assert!(span.attributes.iter().all(|(_, value)| {
+            let value = value.to_string();
+            !value.contains("France") && !value.contains("Paris") && !value.contains("brief")
+        }));
+    }
+
+    #[test]
+    fn extract_converse_with_content() {
+        let extractor = BedrockExtractor::new().with_content_capture(true);
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "Bedrock Runtime",
+            "Converse",
+            &context::Input::erase(converse_input()),
+            &mut span,
+        );
+        extractor.extract_output(
+            "Bedrock Runtime",
+            "Converse",
+            &context::Output::erase(converse_output()),
+            &mut span,
+        );
+        assert_eq!(
+            span.json(GEN_AI_SYSTEM_INSTRUCTIONS),
+            json!([{ "type": "text", "content": "Be brief." }])
+        );
+        assert_eq!(
+            span.json(GEN_AI_INPUT_MESSAGES),
+            json!([{
+                "role": "user",
+                "parts": [{ "type": "text", "content": "What is the capital of France?" }],
+            }])
+        );
+        assert_eq!(
+            span.json(GEN_AI_OUTPUT_MESSAGES),
+            json!([{
+                "role": "assistant",
+                "parts": [{ "type": "text", "content": "Paris." }],
+                "finish_reason": "end_turn",
+            }])
+        );
+    }
+
+    // Tests for BedrockExtractor InvokeModel extraction — 2 consolidated tests
+
+    #[test]
+    fn extract_invoke_model_anthropic_body() {
+        let extractor = BedrockExtractor::new().with_content_capture(true);
+
+        let sdk_input = InvokeModelInput::builder()
+            .model_id(MODEL_ID)
+            .body(Blob::new(
+                json!({
+                    "anthropic_version": "bedrock-2023-05-31",
+                    "max_tokens": 512,
+                    "temperature": 0.5,
+                    "top_p": 0.95,
+                    "system": "Be brief.",
+                    "messages": [{
+                        "role": "user",
+                        "content": [{ "type": "text", "text": "Hello" }],
+                    }],
+                })
+                .to_string(),
+            ))
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "Bedrock Runtime",
+            "InvokeModel",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(semco::GEN_AI_OPERATION_NAME),
+            Some(&Value::from("chat"))
+        );
+        assert_eq!(
+            span.get(semco::GEN_AI_REQUEST_MAX_TOKENS),
+            Some(&Value::I64(512))
+        );
+        assert_eq!(
+            span.get(semco::GEN_AI_REQUEST_TEMPERATURE),
+            Some(&Value::F64(0.5))
+        );
+        assert_eq!(
+            span.get(semco::GEN_AI_REQUEST_TOP_P),
+            Some(&Value::F64(0.95))
+        );
+        assert_eq!(
+            span.json(GEN_AI_INPUT_MESSAGES),
+            json!([{ "role": "user", "parts": [{ "type": "text", "content": "Hello" }] }])
+        );
+
+        let mut response =
+            http::Response::new(http::StatusCode::try_from(200).unwrap(), SdkBody::empty());
+        response
+            .headers_mut()
+            .insert(INPUT_TOKEN_COUNT_HEADER, "12");
+        response
+            .headers_mut()
+            .insert(OUTPUT_TOKEN_COUNT_HEADER, "4");
+        extractor.extract_response("Bedrock Runtime", "InvokeModel", &response, &mut span);
+        assert_eq!(
+            span.get(semco::GEN_AI_USAGE_INPUT_TOKENS),
+            Some(&Value::I64(12))
+        );
+        assert_eq!(
+            span.get(semco::GEN_AI_USAGE_OUTPUT_TOKENS),
+            Some(&Value::I64(4))
+        );
+
+        let sdk_output = InvokeModelOutput::builder()
+            .body(Blob::new(
+                json!({
+                    "content": [{ "type": "text", "text": "Hi!" }],
+                    "stop_reason": "end_turn",
+                })
+                .to_string(),
+            ))
+            .content_type("application/json")
+            .build()
+            .unwrap();
+        extractor.extract_output(
+            "Bedrock Runtime",
+            "InvokeModel",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(semco::GEN_AI_RESPONSE_FINISH_REASONS),
+            Some(&Value::Array(Array::String(vec!["end_turn".into()])))
+        );
+        assert_eq!(
+            span.json(GEN_AI_OUTPUT_MESSAGES),
+            json!([{
+                "role": "assistant",
+                "parts": [{ "type": "text", "content": "Hi!" }],
+                "finish_reason": "end_turn",
+            }])
+        );
+    }
+
+    #[test]
+    fn invoke_model_body_families() {
+        // Titan: parameters nested in textGenerationConfig, one entry per result
+        let titan = json!({
+            "inputText": "Hello",
+            "textGenerationConfig": { "maxTokenCount": 100, "topP": 0.8 },
+        });
+        assert_eq!(find_parameter(&titan, &MAX_TOKENS_KEYS), Some(&json!(100)));
+        assert_eq!(find_parameter(&titan, &TOP_P_KEYS), Some(&json!(0.8)));
+        assert_eq!(
+            invoke_model_choices(&json!({
+                "results": [{ "outputText": "Hi", "completionReason": "FINISH" }],
+            })),
+            vec![Choice {
+                text: Some("Hi".into()),
+                finish_reason: Some("FINISH"),
+            }]
+        );
+
+        // Llama: single generation at the root
+        assert_eq!(
+            invoke_model_choices(&json!({ "generation": "Hi", "stop_reason": "stop" })),
+            vec![Choice {
+                text: Some("Hi".into()),
+                finish_reason: Some("stop"),
+            }]
+        );
+
+        // Embeddings carry no choice
+        assert!(invoke_model_choices(&json!({ "embedding": [0.1, 0.2] })).is_empty());
+    }
+
+    // Tests for content capture configuration — 1 consolidated test
+
+    #[test]
+    fn capture_content_env_var_values() {
+        assert!(capture_content_enabled(Some("true")));
+        assert!(capture_content_enabled(Some(" TRUE ")));
+        assert!(!capture_content_enabled(Some("false")));
+        assert!(!capture_content_enabled(Some("1")));
+        assert!(!capture_content_enabled(None));
+    }
+}
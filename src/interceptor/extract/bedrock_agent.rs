@@ -0,0 +1,487 @@
+//! Bedrock Agent Runtime attribute extraction following the OTel GenAI agent
+//! semantic conventions.
+//!
+//! This module provides [`BedrockAgentExtractor`], which implements
+//! [`super::super::AttributeExtractor`] for the Bedrock Agent Runtime
+//! `InvokeAgent`, `Retrieve` and `RetrieveAndGenerate` SDK calls. It is
+//! automatically used by [`super::super::DefaultExtractor`] when the
+//! `extract-bedrock-agent` feature is enabled.
+//!
+//! Each operation records a distinct `gen_ai.operation.name`, so that the
+//! retrieval of a RAG pipeline built on `Retrieve` can be told apart from its
+//! generation, and from the managed `RetrieveAndGenerate` flow.
+//!
+//! ## Extracted attributes
+//!
+//! **Set from input:**
+//! - `gen_ai.system` — always `"aws.bedrock"`
+//! - `gen_ai.operation.name` — `"invoke_agent"`, `"retrieve"` or
+//!   `"retrieve_and_generate"`
+//! - `gen_ai.agent.id` — for `InvokeAgent`, the agent ID
+//! - `aws.bedrock.agent.alias_id` — for `InvokeAgent`, the agent alias ID
+//! - `gen_ai.data_source.id`, `aws.bedrock.knowledge_base.id` — for
+//!   `Retrieve` and knowledge base `RetrieveAndGenerate`, the knowledge base
+//!   ID
+//! - `gen_ai.request.model` — for `RetrieveAndGenerate`, the ARN of the
+//!   generating model
+//! - `aws.bedrock.guardrail.id` — for `Retrieve` and `RetrieveAndGenerate`,
+//!   the guardrail applied, when configured
+//!
+//! **Set from input or output:**
+//! - `aws.bedrock.session_id.sha256` — for `InvokeAgent` and
+//!   `RetrieveAndGenerate`, the hex-encoded SHA-256 digest of the session ID
+//!
+//! **Set from output:**
+//! - `aws.bedrock.retrieved_count` — for `Retrieve`, the number of retrieval
+//!   results; for `RetrieveAndGenerate`, the number of references cited
+//! - `aws.bedrock.guardrail.action` — for `Retrieve` and
+//!   `RetrieveAndGenerate`, the guardrail action (`"INTERVENED"` or `"NONE"`)
+//!
+//! Session IDs may be derived from user identifiers, so only their digest is
+//! recorded. Queries, agent input text and retrieved content are never
+//! recorded. The response of `InvokeAgent` is a stream consumed after the SDK
+//! call returns, so its span carries the request attributes only.
+
+// Bedrock Agent Runtime attribute extraction — downcasts Input/Output to
+// concrete aws-sdk-bedrockagentruntime types and extracts agent, knowledge
+// base and guardrail attributes.
+
+use aws_sdk_bedrockagentruntime::operation::{
+    invoke_agent::InvokeAgentInput,
+    retrieve::{RetrieveInput, RetrieveOutput},
+    retrieve_and_generate::{RetrieveAndGenerateInput, RetrieveAndGenerateOutput},
+};
+use aws_sdk_bedrockagentruntime::types::{GuadrailAction, GuardrailConfiguration};
+use aws_smithy_runtime_api::client::interceptors::context;
+use opentelemetry_semantic_conventions::attribute as semco;
+
+use super::super::{AttributeExtractor, SpanWrite};
+use super::hash::sha256_hex;
+use super::set_str;
+
+/// `gen_ai.system` value for Bedrock.
+const GEN_AI_SYSTEM_AWS_BEDROCK: &str = "aws.bedrock";
+/// Attribute key for the agent alias ID.
+const AWS_BEDROCK_AGENT_ALIAS_ID: &str = "aws.bedrock.agent.alias_id";
+/// Attribute key for the SHA-256 digest of the session ID.
+const AWS_BEDROCK_SESSION_ID_SHA256: &str = "aws.bedrock.session_id.sha256";
+/// Attribute key for the number of results retrieved or references cited.
+const AWS_BEDROCK_RETRIEVED_COUNT: &str = "aws.bedrock.retrieved_count";
+/// Attribute key for the guardrail action.
+const AWS_BEDROCK_GUARDRAIL_ACTION: &str = "aws.bedrock.guardrail.action";
+
+/// Attribute extractor for Bedrock Agent Runtime SDK calls.
+///
+/// `BedrockAgentExtractor` implements [`AttributeExtractor`] and is
+/// automatically used by [`DefaultExtractor`] when the `extract-bedrock-agent`
+/// feature is enabled. You only need to construct it directly if you are
+/// composing a custom extraction pipeline.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes.
+///
+/// [`DefaultExtractor`]: crate::interceptor::DefaultExtractor
+#[derive(Debug, Default)]
+pub struct BedrockAgentExtractor {
+    _private: (),
+}
+
+impl BedrockAgentExtractor {
+    /// Creates a new `BedrockAgentExtractor`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::extract::bedrock_agent::BedrockAgentExtractor;
+    ///
+    /// let extractor = BedrockAgentExtractor::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+/// Extracts GenAI agent OTel attributes from Bedrock Agent Runtime SDK inputs
+/// and outputs.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes and which operations they apply to.
+impl<SW: SpanWrite> AttributeExtractor<SW> for BedrockAgentExtractor {
+    fn extract_input(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        input: &context::Input,
+        span: &mut SW,
+    ) {
+        match operation {
+            "InvokeAgent" => {
+                let i = input
+                    .downcast_ref::<InvokeAgentInput>()
+                    .expect("correct type");
+                set_operation(span, "invoke_agent");
+                set_str(span, semco::GEN_AI_AGENT_ID, i.agent_id());
+                set_str(span, AWS_BEDROCK_AGENT_ALIAS_ID, i.agent_alias_id());
+                set_session_id(span, i.session_id());
+            }
+            "Retrieve" => {
+                let i = input.downcast_ref::<RetrieveInput>().expect("correct type");
+                set_operation(span, "retrieve");
+                set_knowledge_base_id(span, i.knowledge_base_id());
+                set_guardrail_id(span, i.guardrail_configuration());
+            }
+            "RetrieveAndGenerate" => {
+                let i = input
+                    .downcast_ref::<RetrieveAndGenerateInput>()
+                    .expect("correct type");
+                set_operation(span, "retrieve_and_generate");
+                set_session_id(span, i.session_id());
+                let Some(config) = i.retrieve_and_generate_configuration() else {
+                    return;
+                };
+                if let Some(kb) = config.knowledge_base_configuration() {
+                    set_knowledge_base_id(span, Some(kb.knowledge_base_id()));
+                    set_str(span, semco::GEN_AI_REQUEST_MODEL, Some(kb.model_arn()));
+                    set_guardrail_id(
+                        span,
+                        kb.generation_configuration()
+                            .and_then(|generation| generation.guardrail_configuration()),
+                    );
+                }
+                if let Some(external) = config.external_sources_configuration() {
+                    set_str(
+                        span,
+                        semco::GEN_AI_REQUEST_MODEL,
+                        Some(external.model_arn()),
+                    );
+                    set_guardrail_id(
+                        span,
+                        external
+                            .generation_configuration()
+                            .and_then(|generation| generation.guardrail_configuration()),
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn extract_output(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        output: &context::Output,
+        span: &mut SW,
+    ) {
+        match operation {
+            "Retrieve" => {
+                let o = output
+                    .downcast_ref::<RetrieveOutput>()
+                    .expect("correct type");
+                span.set_attribute(
+                    AWS_BEDROCK_RETRIEVED_COUNT,
+                    o.retrieval_results().len() as i64,
+                );
+                set_guardrail_action(span, o.guardrail_action());
+            }
+            "RetrieveAndGenerate" => {
+                let o = output
+                    .downcast_ref::<RetrieveAndGenerateOutput>()
+                    .expect("correct type");
+                // The service starts a new session when the input has none
+                set_session_id(span, Some(o.session_id()));
+                let cited: usize = o
+                    .citations()
+                    .iter()
+                    .map(|citation| citation.retrieved_references().len())
+                    .sum();
+                span.set_attribute(AWS_BEDROCK_RETRIEVED_COUNT, cited as i64);
+                set_guardrail_action(span, o.guardrail_action());
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Sets the GenAI system and operation name.
+fn set_operation(span: &mut impl SpanWrite, operation_name: &'static str) {
+    span.set_attribute(semco::GEN_AI_SYSTEM, GEN_AI_SYSTEM_AWS_BEDROCK);
+    span.set_attribute(semco::GEN_AI_OPERATION_NAME, operation_name);
+}
+
+/// Sets the digest of a non-empty session ID.
+fn set_session_id(span: &mut impl SpanWrite, session_id: Option<&str>) {
+    if let Some(session_id) = session_id.filter(|s| !s.is_empty()) {
+        span.set_attribute(AWS_BEDROCK_SESSION_ID_SHA256, sha256_hex(session_id));
+    }
+}
+
+/// Sets the knowledge base ID, both as the GenAI data source and as the AWS
+/// semconv knowledge base attribute.
+fn set_knowledge_base_id(span: &mut impl SpanWrite, knowledge_base_id: Option<&str>) {
+    set_str(span, semco::GEN_AI_DATA_SOURCE_ID, knowledge_base_id);
+    set_str(
+        span,
+        semco::AWS_BEDROCK_KNOWLEDGE_BASE_ID,
+        knowledge_base_id,
+    );
+}
+
+/// Sets the ID of the configured guardrail.
+fn set_guardrail_id(span: &mut impl SpanWrite, guardrail: Option<&GuardrailConfiguration>) {
+    set_str(
+        span,
+        semco::AWS_BEDROCK_GUARDRAIL_ID,
+        guardrail.map(|guardrail| guardrail.guardrail_id()),
+    );
+}
+
+/// Sets the guardrail action returned by the service.
+fn set_guardrail_action(span: &mut impl SpanWrite, action: Option<&GuadrailAction>) {
+    set_str(
+        span,
+        AWS_BEDROCK_GUARDRAIL_ACTION,
+        action.map(GuadrailAction::as_str),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_bedrockagentruntime::types::{
+        Citation, KnowledgeBaseQuery, KnowledgeBaseRetrievalResult,
+        KnowledgeBaseRetrieveAndGenerateConfiguration, RetrieveAndGenerateConfiguration,
+        RetrieveAndGenerateType, RetrievedReference,
+    };
+    use opentelemetry::Value;
+
+    use crate::span_write::{SpanContext, SpanWrite, Status};
+
+    struct TestSpan {
+        attributes: Vec<(&'static str, Value)>,
+    }
+
+    impl TestSpan {
+        fn new() -> Self {
+            Self { attributes: vec![] }
+        }
+
+        fn get(&self, key: &str) -> Option<&Value> {
+            self.attributes
+                .iter()
+                .rev()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v)
+        }
+
+        fn contains(&self, needle: &str) -> bool {
+            self.attributes
+                .iter()
+                .any(|(_, value)| value.to_string().contains(needle))
+        }
+    }
+
+    impl SpanWrite for TestSpan {
+        fn set_attribute(&mut self, key: &'static str, value: impl Into<Value>) {
+            self.attributes.push((key, value.into()));
+        }
+
+        fn set_status(&mut self, _code: Status) {}
+
+        fn span_context(&self) -> SpanContext {
+            SpanContext::empty_context()
+        }
+    }
+
+    const KNOWLEDGE_BASE_ID: &str = "H7STPQYOND";
+    const MODEL_ARN: &str =
+        "arn:aws:bedrock:us-east-1::foundation-model/anthropic.claude-3-haiku-20240307-v1:0";
+
+    // Tests for BedrockAgentExtractor — 3 consolidated tests
+
+    #[test]
+    fn extract_invoke_agent() {
+        let sdk_input = InvokeAgentInput::builder()
+            .agent_id("AGENT12345")
+            .agent_alias_id("TSTALIASID")
+            .session_id("user-42@example.com")
+            .input_text("Book a table for two")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        BedrockAgentExtractor::new().extract_input(
+            "Bedrock Agent Runtime",
+            "InvokeAgent",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(semco::GEN_AI_SYSTEM),
+            Some(&Value::from("aws.bedrock"))
+        );
+        assert_eq!(
+            span.get(semco::GEN_AI_OPERATION_NAME),
+            Some(&Value::from("invoke_agent"))
+        );
+        assert_eq!(
+            span.get(semco::GEN_AI_AGENT_ID),
+            Some(&Value::from("AGENT12345"))
+        );
+        assert_eq!(
+            span.get(AWS_BEDROCK_AGENT_ALIAS_ID),
+            Some(&Value::from("TSTALIASID"))
+        );
+        assert_eq!(
+            span.get(AWS_BEDROCK_SESSION_ID_SHA256),
+            Some(&Value::from(sha256_hex("user-42@example.com")))
+        );
+        assert!(!span.contains("user-42"));
+        assert!(!span.contains("table"));
+    }
+
+    #[test]
+    fn extract_retrieve() {
+        let extractor = BedrockAgentExtractor::new();
+
+        let sdk_input = RetrieveInput::builder()
+            .knowledge_base_id(KNOWLEDGE_BASE_ID)
+            .retrieval_query(
+                KnowledgeBaseQuery::builder()
+                    .text("refund policy")
+                    .build()
+                    .unwrap(),
+            )
+            .guardrail_configuration(
+                GuardrailConfiguration::builder()
+                    .guardrail_id("gr-123")
+                    .guardrail_version("1")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "Bedrock Agent Runtime",
+            "Retrieve",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(semco::GEN_AI_OPERATION_NAME),
+            Some(&Value::from("retrieve"))
+        );
+        assert_eq!(
+            span.get(semco::GEN_AI_DATA_SOURCE_ID),
+            Some(&Value::from(KNOWLEDGE_BASE_ID))
+        );
+        assert_eq!(
+            span.get(semco::AWS_BEDROCK_KNOWLEDGE_BASE_ID),
+            Some(&Value::from(KNOWLEDGE_BASE_ID))
+        );
+        assert_eq!(
+            span.get(semco::AWS_BEDROCK_GUARDRAIL_ID),
+            Some(&Value::from("gr-123"))
+        );
+
+        let sdk_output = RetrieveOutput::builder()
+            .retrieval_results(KnowledgeBaseRetrievalResult::builder().build())
+            .retrieval_results(KnowledgeBaseRetrievalResult::builder().build())
+            .guardrail_action(GuadrailAction::None)
+            .build()
+            .unwrap();
+        extractor.extract_output(
+            "Bedrock Agent Runtime",
+            "Retrieve",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(span.get(AWS_BEDROCK_RETRIEVED_COUNT), Some(&Value::I64(2)));
+        assert_eq!(
+            span.get(AWS_BEDROCK_GUARDRAIL_ACTION),
+            Some(&Value::from("NONE"))
+        );
+        assert!(!span.contains("refund"));
+    }
+
+    #[test]
+    fn extract_retrieve_and_generate() {
+        let extractor = BedrockAgentExtractor::new();
+
+        let sdk_input = RetrieveAndGenerateInput::builder()
+            .input(
+                aws_sdk_bedrockagentruntime::types::RetrieveAndGenerateInput::builder()
+                    .text("What is the refund policy?")
+                    .build()
+                    .unwrap(),
+            )
+            .retrieve_and_generate_configuration(
+                RetrieveAndGenerateConfiguration::builder()
+                    .r#type(RetrieveAndGenerateType::KnowledgeBase)
+                    .knowledge_base_configuration(
+                        KnowledgeBaseRetrieveAndGenerateConfiguration::builder()
+                            .knowledge_base_id(KNOWLEDGE_BASE_ID)
+                            .model_arn(MODEL_ARN)
+                            .build()
+                            .unwrap(),
+                    )
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "Bedrock Agent Runtime",
+            "RetrieveAndGenerate",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(semco::GEN_AI_OPERATION_NAME),
+            Some(&Value::from("retrieve_and_generate"))
+        );
+        assert_eq!(
+            span.get(semco::GEN_AI_DATA_SOURCE_ID),
+            Some(&Value::from(KNOWLEDGE_BASE_ID))
+        );
+        assert_eq!(
+            span.get(semco::GEN_AI_REQUEST_MODEL),
+            Some(&Value::from(MODEL_ARN))
+        );
+        assert_eq!(span.get(AWS_BEDROCK_SESSION_ID_SHA256), None);
+
+        let sdk_output = RetrieveAndGenerateOutput::builder()
+            .session_id("session-1")
+            .citations(
+                Citation::builder()
+                    .retrieved_references(RetrievedReference::builder().build())
+                    .retrieved_references(RetrievedReference::builder().build())
+                    .build(),
+            )
+            .citations(
+                Citation::builder()
+                    .retrieved_references(RetrievedReference::builder().build())
+                    .build(),
+            )
+            .guardrail_action(GuadrailAction::Intervened)
+            .build()
+            .unwrap();
+        extractor.extract_output(
+            "Bedrock Agent Runtime",
+            "RetrieveAndGenerate",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_BEDROCK_SESSION_ID_SHA256),
+            Some(&Value::from(sha256_hex("session-1")))
+        );
+        assert_eq!(span.get(AWS_BEDROCK_RETRIEVED_COUNT), Some(&Value::I64(3)));
+        assert_eq!(
+            span.get(AWS_BEDROCK_GUARDRAIL_ACTION),
+            Some(&Value::from("INTERVENED"))
+        );
+        assert!(!span.contains("session-1"));
+    }
+}
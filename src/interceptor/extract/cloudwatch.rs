@@ -0,0 +1,220 @@
+//! CloudWatch attribute extraction.
+//!
+//! This module provides [`CloudWatchExtractor`], which implements
+//! [`super::super::AttributeExtractor`] for the CloudWatch `PutMetricData` SDK
+//! call. It is automatically used by [`super::super::DefaultExtractor`] when
+//! the `extract-cloudwatch` feature is enabled.
+//!
+//! ## Extracted attributes
+//!
+//! **Set from input:**
+//! - `aws.cloudwatch.namespace` — the metric namespace
+//! - `aws.cloudwatch.metric_datum_count` — the number of metric data points,
+//!   including those grouped under an entity
+//! - `aws.cloudwatch.metric_names` — the distinct metric names, sorted and
+//!   capped at 100 entries
+//!
+//! Dimensions and metric values are never recorded.
+
+// CloudWatch attribute extraction — downcasts Input to concrete
+// aws-sdk-cloudwatch types and extracts metric publishing attributes.
+
+use std::collections::BTreeSet;
+
+use aws_sdk_cloudwatch::operation::put_metric_data::PutMetricDataInput;
+use aws_sdk_cloudwatch::types::MetricDatum;
+use aws_smithy_runtime_api::client::interceptors::context;
+use opentelemetry::{Array, StringValue, Value};
+
+use super::super::{AttributeExtractor, SpanWrite};
+
+/// Attribute key for the metric namespace.
+const AWS_CLOUDWATCH_NAMESPACE: &str = "aws.cloudwatch.namespace";
+/// Attribute key for the number of metric data points.
+const AWS_CLOUDWATCH_METRIC_DATUM_COUNT: &str = "aws.cloudwatch.metric_datum_count";
+/// Attribute key for the distinct metric names.
+const AWS_CLOUDWATCH_METRIC_NAMES: &str = "aws.cloudwatch.metric_names";
+
+/// Maximum number of entries recorded in `aws.cloudwatch.metric_names`.
+///
+/// A `PutMetricData` request can carry up to 1000 data points, each with its
+/// own metric name.
+const MAX_METRIC_NAMES: usize = 100;
+
+/// Attribute extractor for CloudWatch SDK calls.
+///
+/// `CloudWatchExtractor` implements [`AttributeExtractor`] and is
+/// automatically used by [`DefaultExtractor`] when the `extract-cloudwatch`
+/// feature is enabled. You only need to construct it directly if you are
+/// composing a custom extraction pipeline.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes.
+///
+/// [`DefaultExtractor`]: crate::interceptor::DefaultExtractor
+#[derive(Debug, Default)]
+pub struct CloudWatchExtractor {
+    _private: (),
+}
+
+impl CloudWatchExtractor {
+    /// Creates a new `CloudWatchExtractor`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::extract::cloudwatch::CloudWatchExtractor;
+    ///
+    /// let extractor = CloudWatchExtractor::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+/// Extracts CloudWatch attributes from SDK inputs.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes and which operations they apply to.
+impl<SW: SpanWrite> AttributeExtractor<SW> for CloudWatchExtractor {
+    fn extract_input(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        input: &context::Input,
+        span: &mut SW,
+    ) {
+        if operation == "PutMetricData" {
+            let i = input
+                .downcast_ref::<PutMetricDataInput>()
+                .expect("correct type");
+            if let Some(namespace) = i.namespace().filter(|s| !s.is_empty()) {
+                span.set_attribute(AWS_CLOUDWATCH_NAMESPACE, namespace.to_owned());
+            }
+
+            let data: Vec<&MetricDatum> = i
+                .metric_data()
+                .iter()
+                .chain(
+                    i.entity_metric_data()
+                        .iter()
+                        .flat_map(|entity| entity.metric_data()),
+                )
+                .collect();
+            span.set_attribute(AWS_CLOUDWATCH_METRIC_DATUM_COUNT, data.len() as i64);
+
+            let names: BTreeSet<&str> = data
+                .iter()
+                .filter_map(|datum| datum.metric_name())
+                .filter(|s| !s.is_empty())
+                .collect();
+            if !names.is_empty() {
+                let names: Vec<StringValue> = names
+                    .into_iter()
+                    .take(MAX_METRIC_NAMES)
+                    .map(|name| StringValue::from(name.to_owned()))
+                    .collect();
+                span.set_attribute(
+                    AWS_CLOUDWATCH_METRIC_NAMES,
+                    Value::Array(Array::String(names)),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_cloudwatch::types::{Dimension, EntityMetricData};
+
+    use crate::span_write::{SpanContext, SpanWrite, Status};
+
+    struct TestSpan {
+        attributes: Vec<(&'static str, Value)>,
+    }
+
+    impl TestSpan {
+        fn new() -> Self {
+            Self { attributes: vec![] }
+        }
+
+        fn get(&self, key: &str) -> Option<&Value> {
+            self.attributes
+                .iter()
+                .rev()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v)
+        }
+    }
+
+    impl SpanWrite for TestSpan {
+        fn set_attribute(&mut self, key: &'static str, value: impl Into<Value>) {
+            self.attributes.push((key, value.into()));
+        }
+
+        fn set_status(&mut self, _code: Status) {}
+
+        fn span_context(&self) -> SpanContext {
+            SpanContext::empty_context()
+        }
+    }
+
+    // Tests for CloudWatchExtractor — 1 consolidated test
+
+    #[test]
+    fn extract_put_metric_data() {
+        let datum = |name: &str, value: f64| {
+            MetricDatum::builder()
+                .metric_name(name)
+                .dimensions(
+                    Dimension::builder()
+                        .name("DeviceId")
+                        .value("sensor-17")
+                        .build(),
+                )
+                .value(value)
+                .build()
+        };
+        let sdk_input = PutMetricDataInput::builder()
+            .namespace("IoT/Ingestion")
+            .metric_data(datum("Latency", 12.0))
+            .metric_data(datum("Errors", 1.0))
+            .metric_data(datum("Latency", 14.0))
+            .entity_metric_data(
+                EntityMetricData::builder()
+                    .metric_data(datum("Throughput", 250.0))
+                    .build(),
+            )
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        CloudWatchExtractor::new().extract_input(
+            "CloudWatch",
+            "PutMetricData",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_CLOUDWATCH_NAMESPACE),
+            Some(&Value::from("IoT/Ingestion"))
+        );
+        assert_eq!(
+            span.get(AWS_CLOUDWATCH_METRIC_DATUM_COUNT),
+            Some(&Value::I64(4))
+        );
+        assert_eq!(
+            span.get(AWS_CLOUDWATCH_METRIC_NAMES),
+            Some(&Value::Array(Array::String(vec![
+                "Errors".into(),
+                "Latency".into(),
+                "Throughput".into(),
+            ])))
+        );
+        assert!(
+            span.attributes
+                .iter()
+                .all(|(_, value)| !value.to_string().contains("sensor-17"))
+        );
+    }
+}
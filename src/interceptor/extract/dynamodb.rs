@@ -22,11 +22,39 @@
 //!   `aws.dynamodb.provisioned_write_capacity` — for `CreateTable`, `UpdateTable`
 //! - `aws.dynamodb.exclusive_start_table` — for `ListTables`
 //!
+//! **PartiQL operations** (`ExecuteStatement`, `BatchExecuteStatement`,
+//! `ExecuteTransaction`):
+//! - `db.query.text` — the statement with its string and numeric literals
+//!   replaced by `?`; `?` parameters are never expanded. Batch and transaction
+//!   statements are joined with `"; "`
+//! - `aws.dynamodb.table_names` — parsed from the `FROM`, `INTO` or `UPDATE`
+//!   clause of each statement, when possible
+//! - `db.operation.batch.size` — the statement count, for
+//!   `BatchExecuteStatement` and `ExecuteTransaction`
+//! - `aws.dynamodb.consistent_read`, `aws.dynamodb.limit` — for
+//!   `ExecuteStatement`
+//!
 //! **Per-operation output attributes:**
 //! - `aws.dynamodb.count`, `aws.dynamodb.scanned_count` — for `Query`, `Scan`
+//! - `aws.dynamodb.has_last_evaluated_key` — for `Query`, `Scan`; `true` when
+//!   the response carries a `LastEvaluatedKey`, meaning more pages exist
 //! - `aws.dynamodb.table_count` — for `ListTables`
 //! - `aws.dynamodb.consumed_capacity` (JSON array) — for all operations that
-//!   return `ConsumedCapacity`
+//!   return `ConsumedCapacity`, PartiQL operations included
+//! - `aws.dynamodb.returned_item_count` — for `BatchGetItem`, the number of
+//!   items returned across all tables
+//! - `aws.dynamodb.unprocessed_keys` (JSON object of per-table counts) — for
+//!   `BatchGetItem`
+//! - `aws.dynamodb.unprocessed_items` (JSON object of per-table counts) — for
+//!   `BatchWriteItem`
+//! - `aws.dynamodb.unprocessed_count` — for `BatchGetItem`, `BatchWriteItem`,
+//!   the total number of unprocessed keys or write requests
+//!
+//! Unprocessed keys and items are silent partial failures: the call succeeds
+//! but the caller must retry the leftovers. When they are non-empty, an event
+//! named after the attribute (`aws.dynamodb.unprocessed_keys` or
+//! `aws.dynamodb.unprocessed_items`) is also added to the span so the
+//! condition stands out in trace viewers. The span status is left untouched.
 //!
 //! ## Deferred attributes
 //!
@@ -53,12 +81,13 @@
 //   - aws.dynamodb.attribute_definitions (string[])
 //   - aws.dynamodb.global_secondary_index_updates (string[])
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 use aws_sdk_dynamodb::operation::{
-    batch_get_item::BatchGetItemInput, batch_write_item::BatchWriteItemInput,
-    create_backup::CreateBackupInput, create_table::CreateTableInput, delete_item::DeleteItemInput,
-    delete_table::DeleteTableInput, describe_continuous_backups::DescribeContinuousBackupsInput,
+    batch_execute_statement::BatchExecuteStatementInput, batch_get_item::BatchGetItemInput,
+    batch_write_item::BatchWriteItemInput, create_backup::CreateBackupInput,
+    create_table::CreateTableInput, delete_item::DeleteItemInput, delete_table::DeleteTableInput,
+    describe_continuous_backups::DescribeContinuousBackupsInput,
     describe_contributor_insights::DescribeContributorInsightsInput,
     describe_kinesis_streaming_destination::DescribeKinesisStreamingDestinationInput,
     describe_table::DescribeTableInput,
@@ -66,6 +95,7 @@ use aws_sdk_dynamodb::operation::{
     describe_time_to_live::DescribeTimeToLiveInput,
     disable_kinesis_streaming_destination::DisableKinesisStreamingDestinationInput,
     enable_kinesis_streaming_destination::EnableKinesisStreamingDestinationInput,
+    execute_statement::ExecuteStatementInput, execute_transaction::ExecuteTransactionInput,
     get_item::GetItemInput, list_backups::ListBackupsInput,
     list_contributor_insights::ListContributorInsightsInput, list_tables::ListTablesInput,
     put_item::PutItemInput, query::QueryInput, scan::ScanInput,
@@ -78,11 +108,12 @@ use aws_sdk_dynamodb::operation::{
     update_time_to_live::UpdateTimeToLiveInput,
 };
 use aws_sdk_dynamodb::operation::{
-    batch_get_item::BatchGetItemOutput, batch_write_item::BatchWriteItemOutput,
-    delete_item::DeleteItemOutput, get_item::GetItemOutput, list_tables::ListTablesOutput,
-    put_item::PutItemOutput, query::QueryOutput, scan::ScanOutput,
-    transact_get_items::TransactGetItemsOutput, transact_write_items::TransactWriteItemsOutput,
-    update_item::UpdateItemOutput,
+    batch_execute_statement::BatchExecuteStatementOutput, batch_get_item::BatchGetItemOutput,
+    batch_write_item::BatchWriteItemOutput, delete_item::DeleteItemOutput,
+    execute_statement::ExecuteStatementOutput, execute_transaction::ExecuteTransactionOutput,
+    get_item::GetItemOutput, list_tables::ListTablesOutput, put_item::PutItemOutput,
+    query::QueryOutput, scan::ScanOutput, transact_get_items::TransactGetItemsOutput,
+    transact_write_items::TransactWriteItemsOutput, update_item::UpdateItemOutput,
 };
 use aws_sdk_dynamodb::types;
 use aws_smithy_runtime_api::client::interceptors::context;
@@ -92,6 +123,19 @@ use serde::Serialize;
 use serde::ser::{SerializeMap, Serializer};
 
 use super::super::{AttributeExtractor, SpanWrite};
+use super::sanitize::sanitize_literals;
+use crate::span_write::KeyValue;
+
+/// Attribute key for whether a `Query` or `Scan` response carried a `LastEvaluatedKey`.
+const AWS_DYNAMODB_HAS_LAST_EVALUATED_KEY: &str = "aws.dynamodb.has_last_evaluated_key";
+/// Attribute key for the number of items returned by `BatchGetItem`.
+const AWS_DYNAMODB_RETURNED_ITEM_COUNT: &str = "aws.dynamodb.returned_item_count";
+/// Attribute and event key for the per-table unprocessed key counts of `BatchGetItem`.
+const AWS_DYNAMODB_UNPROCESSED_KEYS: &str = "aws.dynamodb.unprocessed_keys";
+/// Attribute and event key for the per-table unprocessed item counts of `BatchWriteItem`.
+const AWS_DYNAMODB_UNPROCESSED_ITEMS: &str = "aws.dynamodb.unprocessed_items";
+/// Attribute key for the total number of unprocessed keys or items of a batch operation.
+const AWS_DYNAMODB_UNPROCESSED_COUNT: &str = "aws.dynamodb.unprocessed_count";
 
 /// Attribute extractor for DynamoDB SDK calls.
 ///
@@ -149,6 +193,9 @@ impl<SW: SpanWrite> AttributeExtractor<SW> for DynamoDBExtractor {
             "BatchWriteItem" => extract_batch_write_item_input(input, span),
             "TransactGetItems" => extract_transact_get_items_input(input, span),
             "TransactWriteItems" => extract_transact_write_items_input(input, span),
+            "ExecuteStatement" => extract_execute_statement_input(input, span),
+            "BatchExecuteStatement" => extract_batch_execute_statement_input(input, span),
+            "ExecuteTransaction" => extract_execute_transaction_input(input, span),
             // Operations that only have table_name
             "PutItem" => set_table_names(
                 span,
@@ -305,6 +352,8 @@ impl<SW: SpanWrite> AttributeExtractor<SW> for DynamoDBExtractor {
             "Query" => extract_query_output(output, span),
             "Scan" => extract_scan_output(output, span),
             "ListTables" => extract_list_tables_output(output, span),
+            "BatchGetItem" => extract_batch_get_item_output(output, span),
+            "BatchWriteItem" => extract_batch_write_item_output(output, span),
             "GetItem" => set_consumed_capacity_opt(
                 span,
                 output
@@ -333,31 +382,38 @@ impl<SW: SpanWrite> AttributeExtractor<SW> for DynamoDBExtractor {
                     .expect("correct type")
                     .consumed_capacity(),
             ),
-            "BatchGetItem" => set_consumed_capacity_list(
+            "TransactGetItems" => set_consumed_capacity_list(
                 span,
                 output
-                    .downcast_ref::<BatchGetItemOutput>()
+                    .downcast_ref::<TransactGetItemsOutput>()
                     .expect("correct type")
                     .consumed_capacity(),
             ),
-            "BatchWriteItem" => set_consumed_capacity_list(
+            "TransactWriteItems" => set_consumed_capacity_list(
                 span,
                 output
-                    .downcast_ref::<BatchWriteItemOutput>()
+                    .downcast_ref::<TransactWriteItemsOutput>()
                     .expect("correct type")
                     .consumed_capacity(),
             ),
-            "TransactGetItems" => set_consumed_capacity_list(
+            "ExecuteStatement" => set_consumed_capacity_opt(
                 span,
                 output
-                    .downcast_ref::<TransactGetItemsOutput>()
+                    .downcast_ref::<ExecuteStatementOutput>()
                     .expect("correct type")
                     .consumed_capacity(),
             ),
-            "TransactWriteItems" => set_consumed_capacity_list(
+            "BatchExecuteStatement" => set_consumed_capacity_list(
                 span,
                 output
-                    .downcast_ref::<TransactWriteItemsOutput>()
+                    .downcast_ref::<BatchExecuteStatementOutput>()
+                    .expect("correct type")
+                    .consumed_capacity(),
+            ),
+            "ExecuteTransaction" => set_consumed_capacity_list(
+                span,
+                output
+                    .downcast_ref::<ExecuteTransactionOutput>()
                     .expect("correct type")
                     .consumed_capacity(),
             ),
@@ -509,6 +565,36 @@ fn extract_transact_write_items_input(input: &context::Input, span: &mut impl Sp
     );
 }
 
+/// Extracts input attributes for the `ExecuteStatement` PartiQL operation.
+fn extract_execute_statement_input(input: &context::Input, span: &mut impl SpanWrite) {
+    let i = input
+        .downcast_ref::<ExecuteStatementInput>()
+        .expect("correct type");
+    set_partiql_statements(span, i.statement());
+    set_consistent_read(span, i.consistent_read());
+    set_limit(span, i.limit());
+}
+
+/// Extracts input attributes for the `BatchExecuteStatement` PartiQL operation.
+fn extract_batch_execute_statement_input(input: &context::Input, span: &mut impl SpanWrite) {
+    let statements = input
+        .downcast_ref::<BatchExecuteStatementInput>()
+        .expect("correct type")
+        .statements();
+    set_batch_size(span, statements.len());
+    set_partiql_statements(span, statements.iter().map(|s| s.statement()));
+}
+
+/// Extracts input attributes for the `ExecuteTransaction` PartiQL operation.
+fn extract_execute_transaction_input(input: &context::Input, span: &mut impl SpanWrite) {
+    let statements = input
+        .downcast_ref::<ExecuteTransactionInput>()
+        .expect("correct type")
+        .transact_statements();
+    set_batch_size(span, statements.len());
+    set_partiql_statements(span, statements.iter().map(|s| s.statement()));
+}
+
 // ---------------------------------------------------------------------------
 // Per-operation output helpers
 // ---------------------------------------------------------------------------
@@ -521,6 +607,10 @@ fn extract_query_output(output: &context::Output, span: &mut impl SpanWrite) {
         semco::AWS_DYNAMODB_SCANNED_COUNT,
         Value::I64(i64::from(o.scanned_count())),
     );
+    set_has_last_evaluated_key(
+        span,
+        o.last_evaluated_key().is_some_and(|key| !key.is_empty()),
+    );
     set_consumed_capacity_opt(span, o.consumed_capacity());
 }
 
@@ -532,6 +622,10 @@ fn extract_scan_output(output: &context::Output, span: &mut impl SpanWrite) {
         semco::AWS_DYNAMODB_SCANNED_COUNT,
         Value::I64(i64::from(o.scanned_count())),
     );
+    set_has_last_evaluated_key(
+        span,
+        o.last_evaluated_key().is_some_and(|key| !key.is_empty()),
+    );
     set_consumed_capacity_opt(span, o.consumed_capacity());
 }
 
@@ -546,6 +640,48 @@ fn extract_list_tables_output(output: &context::Output, span: &mut impl SpanWrit
     );
 }
 
+/// Extracts output attributes for the `BatchGetItem` operation.
+fn extract_batch_get_item_output(output: &context::Output, span: &mut impl SpanWrite) {
+    let o = output
+        .downcast_ref::<BatchGetItemOutput>()
+        .expect("correct type");
+    let returned_items = o
+        .responses()
+        .into_iter()
+        .flat_map(|responses| responses.values())
+        .map(|items| items.len())
+        .sum::<usize>();
+    span.set_attribute(
+        AWS_DYNAMODB_RETURNED_ITEM_COUNT,
+        Value::I64(returned_items as i64),
+    );
+    set_unprocessed(
+        span,
+        AWS_DYNAMODB_UNPROCESSED_KEYS,
+        o.unprocessed_keys()
+            .into_iter()
+            .flatten()
+            .map(|(table_name, keys)| (table_name.as_str(), keys.keys().len())),
+    );
+    set_consumed_capacity_list(span, o.consumed_capacity());
+}
+
+/// Extracts output attributes for the `BatchWriteItem` operation.
+fn extract_batch_write_item_output(output: &context::Output, span: &mut impl SpanWrite) {
+    let o = output
+        .downcast_ref::<BatchWriteItemOutput>()
+        .expect("correct type");
+    set_unprocessed(
+        span,
+        AWS_DYNAMODB_UNPROCESSED_ITEMS,
+        o.unprocessed_items()
+            .into_iter()
+            .flatten()
+            .map(|(table_name, requests)| (table_name.as_str(), requests.len())),
+    );
+    set_consumed_capacity_list(span, o.consumed_capacity());
+}
+
 // ---------------------------------------------------------------------------
 // Shared attribute helpers
 // ---------------------------------------------------------------------------
@@ -614,6 +750,122 @@ fn set_attributes_to_get(span: &mut impl SpanWrite, attributes: &[String]) {
     }
 }
 
+/// Sets `db.query.text` and `aws.dynamodb.table_names` from PartiQL statements.
+///
+/// Literals are redacted with [`sanitize_literals`] and the statements are
+/// joined with `"; "`. Table names are deduplicated.
+fn set_partiql_statements<'a>(
+    span: &mut impl SpanWrite,
+    statements: impl IntoIterator<Item = &'a str>,
+) {
+    let mut query_texts = Vec::new();
+    let mut table_names = BTreeSet::new();
+    for statement in statements {
+        query_texts.push(sanitize_literals(
+            statement,
+            &['\'', '`'],
+            &['"'],
+            false,
+            false,
+        ));
+        if let Some(table_name) = partiql_table_name(statement) {
+            table_names.insert(table_name);
+        }
+    }
+    if !query_texts.is_empty() {
+        span.set_attribute(semco::DB_QUERY_TEXT, query_texts.join("; "));
+    }
+    set_table_names(span, table_names.iter().map(String::as_str));
+}
+
+/// Sets the `db.operation.batch.size` attribute.
+fn set_batch_size(span: &mut impl SpanWrite, size: usize) {
+    span.set_attribute(semco::DB_OPERATION_BATCH_SIZE, Value::I64(size as i64));
+}
+
+/// Returns the table targeted by a PartiQL statement.
+///
+/// The table is the identifier following the first `FROM`, `INTO` or `UPDATE`
+/// keyword, either double-quoted (`"my-table"`) or bare (`orders`). An index
+/// suffix (`"table"."index"`) is ignored. Returns `None` when no such clause
+/// is found outside of string literals.
+fn partiql_table_name(statement: &str) -> Option<String> {
+    let mut chars = statement.chars().peekable();
+    let mut expect_table = false;
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' | '`' => {
+                // Skip string and Ion literals, which may contain keywords
+                while let Some(c2) = chars.next() {
+                    if c2 == c && chars.next_if_eq(&c).is_none() {
+                        break;
+                    }
+                }
+                expect_table = false;
+            }
+            '"' => {
+                let mut identifier = String::new();
+                while let Some(c2) = chars.next() {
+                    if c2 == '"' && chars.next_if_eq(&'"').is_none() {
+                        break;
+                    }
+                    identifier.push(c2);
+                }
+                if expect_table {
+                    return Some(identifier);
+                }
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let mut word = String::from(c);
+                while let Some(c2) = chars.next_if(|c2| c2.is_alphanumeric() || *c2 == '_') {
+                    word.push(c2);
+                }
+                if expect_table {
+                    return Some(word);
+                }
+                expect_table = ["FROM", "INTO", "UPDATE"]
+                    .iter()
+                    .any(|keyword| keyword.eq_ignore_ascii_case(&word));
+            }
+            c if c.is_whitespace() => {}
+            _ => expect_table = false,
+        }
+    }
+    None
+}
+
+/// Sets the `aws.dynamodb.has_last_evaluated_key` attribute.
+fn set_has_last_evaluated_key(span: &mut impl SpanWrite, has_last_evaluated_key: bool) {
+    span.set_attribute(AWS_DYNAMODB_HAS_LAST_EVALUATED_KEY, has_last_evaluated_key);
+}
+
+/// Sets the per-table unprocessed counts under `key` and the total under
+/// `aws.dynamodb.unprocessed_count`.
+///
+/// Tables with nothing left to process are skipped. When at least one key or
+/// item is unprocessed, an event named `key` carrying the same attributes is
+/// also added to the span.
+fn set_unprocessed<'a>(
+    span: &mut impl SpanWrite,
+    key: &'static str,
+    per_table: impl IntoIterator<Item = (&'a str, usize)>,
+) {
+    let per_table = per_table
+        .into_iter()
+        .filter(|(_, count)| *count > 0)
+        .collect::<BTreeMap<_, _>>();
+    let total = per_table.values().sum::<usize>() as i64;
+    span.set_attribute(AWS_DYNAMODB_UNPROCESSED_COUNT, Value::I64(total));
+    if total > 0 {
+        let mut attributes = vec![KeyValue::new(AWS_DYNAMODB_UNPROCESSED_COUNT, total)];
+        if let Ok(json) = serde_json::to_string(&per_table) {
+            span.set_attribute(key, json.clone());
+            attributes.push(KeyValue::new(key, json));
+        }
+        span.add_event(key, attributes);
+    }
+}
+
 /// Sets the `aws.dynamodb.provisioned_read_capacity` and `aws.dynamodb.provisioned_write_capacity` attributes if present.
 fn set_provisioned_throughput(
     span: &mut impl SpanWrite,
@@ -764,6 +1016,7 @@ mod tests {
     struct TestSpan {
         attributes: Vec<(&'static str, Value)>,
         status: Option<Status>,
+        events: Vec<(&'static str, Vec<KeyValue>)>,
     }
 
     impl TestSpan {
@@ -771,6 +1024,7 @@ mod tests {
             Self {
                 attributes: vec![],
                 status: None,
+                events: vec![],
             }
         }
 
@@ -791,6 +1045,10 @@ mod tests {
         fn set_status(&mut self, code: Status) {
             self.status = Some(code);
         }
+
+        fn add_event(&mut self, name: &'static str, attributes: Vec<KeyValue>) {
+            self.events.push((name, attributes));
+        }
     }
 
     // ---------------------------------------------------------------------------
@@ -1415,6 +1673,159 @@ mod tests {
         );
     }
 
+    // ---------------------------------------------------------------------------
+    // partiql_table_name — consolidated_2tests
+    // ---------------------------------------------------------------------------
+
+    #[test]
+    fn partiql_table_name_found() {
+        assert_eq!(
+            partiql_table_name(r#"SELECT * FROM "orders" WHERE pk = ?"#).as_deref(),
+            Some("orders")
+        );
+        assert_eq!(
+            partiql_table_name(r#"select id from "orders"."status-index""#).as_deref(),
+            Some("orders")
+        );
+        assert_eq!(
+            partiql_table_name("INSERT INTO orders VALUE {'pk': ?}").as_deref(),
+            Some("orders")
+        );
+        assert_eq!(
+            partiql_table_name(r#"UPDATE "my""table" SET n = 1 WHERE pk = ?"#).as_deref(),
+            Some("my\"table")
+        );
+        assert_eq!(
+            partiql_table_name(r#"DELETE FROM "orders" WHERE pk = ?"#).as_deref(),
+            Some("orders")
+        );
+    }
+
+    #[test]
+    fn partiql_table_name_not_found() {
+        // Keyword inside a string literal is ignored
+        assert_eq!(partiql_table_name("SELECT 'FROM x'"), None);
+        assert_eq!(partiql_table_name(""), None);
+        assert_eq!(partiql_table_name("SELECT * FROM"), None);
+    }
+
+    // ---------------------------------------------------------------------------
+    // PartiQL input helpers — consolidated_2tests
+    // ---------------------------------------------------------------------------
+
+    #[test]
+    fn extract_execute_statement_input_full() {
+        use aws_sdk_dynamodb::operation::execute_statement::ExecuteStatementInput;
+
+        let sdk_input = ExecuteStatementInput::builder()
+            .statement(r#"SELECT * FROM "orders" WHERE pk = ? AND status = 'open'"#)
+            .parameters(types::AttributeValue::S("secret-pk".to_string()))
+            .consistent_read(true)
+            .limit(10)
+            .build()
+            .unwrap();
+        let input = context::Input::erase(sdk_input);
+        let mut span = TestSpan::new();
+
+        extract_execute_statement_input(&input, &mut span);
+
+        assert_eq!(
+            span.get(opentelemetry_semantic_conventions::attribute::DB_QUERY_TEXT),
+            Some(&Value::from(
+                r#"SELECT * FROM "orders" WHERE pk = ? AND status = ?"#
+            ))
+        );
+        assert_eq!(
+            span.get(opentelemetry_semantic_conventions::attribute::AWS_DYNAMODB_TABLE_NAMES),
+            Some(&Value::Array(opentelemetry::Array::String(vec![
+                "orders".into()
+            ])))
+        );
+        assert_eq!(
+            span.get(opentelemetry_semantic_conventions::attribute::AWS_DYNAMODB_CONSISTENT_READ),
+            Some(&Value::Bool(true))
+        );
+        assert_eq!(
+            span.get(opentelemetry_semantic_conventions::attribute::AWS_DYNAMODB_LIMIT),
+            Some(&Value::I64(10))
+        );
+        assert!(
+            span.get(opentelemetry_semantic_conventions::attribute::DB_OPERATION_BATCH_SIZE)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn extract_batch_and_transaction_statement_inputs() {
+        use aws_sdk_dynamodb::operation::{
+            batch_execute_statement::BatchExecuteStatementInput,
+            execute_transaction::ExecuteTransactionInput,
+        };
+        use aws_sdk_dynamodb::types::{BatchStatementRequest, ParameterizedStatement};
+
+        let sdk_input = BatchExecuteStatementInput::builder()
+            .statements(
+                BatchStatementRequest::builder()
+                    .statement(r#"SELECT * FROM "orders" WHERE pk = ?"#)
+                    .build()
+                    .unwrap(),
+            )
+            .statements(
+                BatchStatementRequest::builder()
+                    .statement(r#"SELECT * FROM "customers" WHERE pk = 42"#)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let input = context::Input::erase(sdk_input);
+        let mut span = TestSpan::new();
+
+        extract_batch_execute_statement_input(&input, &mut span);
+
+        assert_eq!(
+            span.get(opentelemetry_semantic_conventions::attribute::DB_QUERY_TEXT),
+            Some(&Value::from(
+                r#"SELECT * FROM "orders" WHERE pk = ?; SELECT * FROM "customers" WHERE pk = ?"#
+            ))
+        );
+        assert_eq!(
+            span.get(opentelemetry_semantic_conventions::attribute::AWS_DYNAMODB_TABLE_NAMES),
+            Some(&Value::Array(opentelemetry::Array::String(vec![
+                "customers".into(),
+                "orders".into()
+            ])))
+        );
+        assert_eq!(
+            span.get(opentelemetry_semantic_conventions::attribute::DB_OPERATION_BATCH_SIZE),
+            Some(&Value::I64(2))
+        );
+
+        // ExecuteTransaction with a single statement
+        let sdk_input = ExecuteTransactionInput::builder()
+            .transact_statements(
+                ParameterizedStatement::builder()
+                    .statement(r#"UPDATE "orders" SET n = 1 WHERE pk = ?"#)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let input = context::Input::erase(sdk_input);
+        let mut span = TestSpan::new();
+
+        extract_execute_transaction_input(&input, &mut span);
+
+        assert_eq!(
+            span.get(opentelemetry_semantic_conventions::attribute::DB_QUERY_TEXT),
+            Some(&Value::from(r#"UPDATE "orders" SET n = ? WHERE pk = ?"#))
+        );
+        assert_eq!(
+            span.get(opentelemetry_semantic_conventions::attribute::DB_OPERATION_BATCH_SIZE),
+            Some(&Value::I64(1))
+        );
+    }
+
     // ---------------------------------------------------------------------------
     // extract_query_output — single_comprehensive
     // ---------------------------------------------------------------------------
@@ -1431,6 +1842,7 @@ mod tests {
             .count(42)
             .scanned_count(100)
             .consumed_capacity(cc)
+            .last_evaluated_key("pk", types::AttributeValue::S("last".to_string()))
             .build();
         let output = context::Output::erase(sdk_output);
         let mut span = TestSpan::new();
@@ -1445,6 +1857,10 @@ mod tests {
             span.get(opentelemetry_semantic_conventions::attribute::AWS_DYNAMODB_SCANNED_COUNT),
             Some(&Value::I64(100))
         );
+        assert_eq!(
+            span.get(AWS_DYNAMODB_HAS_LAST_EVALUATED_KEY),
+            Some(&Value::Bool(true))
+        );
         assert!(
             span.get(opentelemetry_semantic_conventions::attribute::AWS_DYNAMODB_CONSUMED_CAPACITY)
                 .is_some()
@@ -1465,6 +1881,10 @@ mod tests {
             span.get(opentelemetry_semantic_conventions::attribute::AWS_DYNAMODB_COUNT),
             Some(&Value::I64(5))
         );
+        assert_eq!(
+            span.get(AWS_DYNAMODB_HAS_LAST_EVALUATED_KEY),
+            Some(&Value::Bool(false))
+        );
         assert_eq!(
             span.get(opentelemetry_semantic_conventions::attribute::AWS_DYNAMODB_SCANNED_COUNT),
             Some(&Value::I64(5))
@@ -1475,6 +1895,124 @@ mod tests {
         );
     }
 
+    // ---------------------------------------------------------------------------
+    // extract_batch_*_output — consolidated_2tests
+    // ---------------------------------------------------------------------------
+
+    #[test]
+    fn extract_batch_outputs_with_unprocessed() {
+        use aws_sdk_dynamodb::operation::{
+            batch_get_item::BatchGetItemOutput, batch_write_item::BatchWriteItemOutput,
+        };
+        use aws_sdk_dynamodb::types::{
+            AttributeValue, DeleteRequest, KeysAndAttributes, WriteRequest,
+        };
+
+        let key = std::collections::HashMap::from([(
+            "pk".to_string(),
+            AttributeValue::S("v".to_string()),
+        )]);
+
+        // BatchGetItem: 3 items returned over 2 tables, 2 keys left unprocessed
+        let sdk_output = BatchGetItemOutput::builder()
+            .responses("table-a", vec![key.clone(), key.clone()])
+            .responses("table-b", vec![key.clone()])
+            .unprocessed_keys(
+                "table-a",
+                KeysAndAttributes::builder()
+                    .keys(key.clone())
+                    .keys(key.clone())
+                    .build()
+                    .unwrap(),
+            )
+            .build();
+        let output = context::Output::erase(sdk_output);
+        let mut span = TestSpan::new();
+
+        extract_batch_get_item_output(&output, &mut span);
+
+        assert_eq!(
+            span.get(AWS_DYNAMODB_RETURNED_ITEM_COUNT),
+            Some(&Value::I64(3))
+        );
+        assert_eq!(
+            span.get(AWS_DYNAMODB_UNPROCESSED_COUNT),
+            Some(&Value::I64(2))
+        );
+        assert_eq!(
+            span.get(AWS_DYNAMODB_UNPROCESSED_KEYS),
+            Some(&Value::from("{\"table-a\":2}"))
+        );
+        assert_eq!(span.events.len(), 1);
+        assert_eq!(span.events[0].0, AWS_DYNAMODB_UNPROCESSED_KEYS);
+        assert!(span.status.is_none());
+
+        // BatchWriteItem: 1 unprocessed request per table, sorted by table name
+        let request = WriteRequest::builder()
+            .delete_request(DeleteRequest::builder().set_key(Some(key)).build().unwrap())
+            .build();
+        let sdk_output = BatchWriteItemOutput::builder()
+            .unprocessed_items("table-b", vec![request.clone()])
+            .unprocessed_items("table-a", vec![request])
+            .build();
+        let output = context::Output::erase(sdk_output);
+        let mut span = TestSpan::new();
+
+        extract_batch_write_item_output(&output, &mut span);
+
+        assert_eq!(
+            span.get(AWS_DYNAMODB_UNPROCESSED_COUNT),
+            Some(&Value::I64(2))
+        );
+        assert_eq!(
+            span.get(AWS_DYNAMODB_UNPROCESSED_ITEMS),
+            Some(&Value::from("{\"table-a\":1,\"table-b\":1}"))
+        );
+        assert_eq!(span.events.len(), 1);
+        assert_eq!(span.events[0].0, AWS_DYNAMODB_UNPROCESSED_ITEMS);
+    }
+
+    #[test]
+    fn extract_batch_outputs_fully_processed() {
+        use aws_sdk_dynamodb::operation::{
+            batch_get_item::BatchGetItemOutput, batch_write_item::BatchWriteItemOutput,
+        };
+
+        // BatchGetItem with an empty UnprocessedKeys map
+        let sdk_output = BatchGetItemOutput::builder()
+            .set_unprocessed_keys(Some(std::collections::HashMap::new()))
+            .build();
+        let output = context::Output::erase(sdk_output);
+        let mut span = TestSpan::new();
+
+        extract_batch_get_item_output(&output, &mut span);
+
+        assert_eq!(
+            span.get(AWS_DYNAMODB_RETURNED_ITEM_COUNT),
+            Some(&Value::I64(0))
+        );
+        assert_eq!(
+            span.get(AWS_DYNAMODB_UNPROCESSED_COUNT),
+            Some(&Value::I64(0))
+        );
+        assert!(span.get(AWS_DYNAMODB_UNPROCESSED_KEYS).is_none());
+        assert!(span.events.is_empty());
+
+        // BatchWriteItem without UnprocessedItems
+        let sdk_output = BatchWriteItemOutput::builder().build();
+        let output = context::Output::erase(sdk_output);
+        let mut span = TestSpan::new();
+
+        extract_batch_write_item_output(&output, &mut span);
+
+        assert_eq!(
+            span.get(AWS_DYNAMODB_UNPROCESSED_COUNT),
+            Some(&Value::I64(0))
+        );
+        assert!(span.get(AWS_DYNAMODB_UNPROCESSED_ITEMS).is_none());
+        assert!(span.events.is_empty());
+    }
+
     // ---------------------------------------------------------------------------
     // extract_list_tables_output — single_comprehensive
     // ---------------------------------------------------------------------------
@@ -0,0 +1,472 @@
+//! EventBridge attribute extraction.
+//!
+//! This module provides [`EventBridgeExtractor`], which implements
+//! [`super::super::AttributeExtractor`] for EventBridge SDK calls. It is
+//! automatically used by [`super::super::DefaultExtractor`] when the
+//! `extract-eventbridge` feature is enabled.
+//!
+//! ## Extracted attributes
+//!
+//! **Set from `PutEvents` input:**
+//! - `aws.eventbridge.event_buses` — the distinct event bus names or ARNs of
+//!   the entries (`"default"` for entries that do not name one)
+//! - `aws.eventbridge.sources` — the distinct `Source` values of the entries
+//! - `aws.eventbridge.detail_types` — the distinct `DetailType` values of the
+//!   entries
+//! - `aws.eventbridge.entry_count` — the number of entries sent
+//! - `aws.eventbridge.endpoint_id` — the global endpoint ID, when set
+//!
+//! **Set from `PutEvents` output:**
+//! - `aws.eventbridge.failed_entry_count` — the `FailedEntryCount`
+//! - `aws.eventbridge.failed_entry_codes` — the distinct error codes of the
+//!   failed entries
+//!
+//! `PutEvents` reports per-entry failures inside a successful response.
+//! When at least one entry failed, the span status is set to error.
+//!
+//! Event details are never recorded.
+//!
+//! ## Trace context propagation
+//!
+//! When enabled with [`EventBridgeExtractor::with_trace_header_injection`], the
+//! extractor sets the `TraceHeader` field of every `PutEvents` entry that does
+//! not already have one to the X-Ray trace header of the current span.
+//! EventBridge forwards it to the rule targets (as the `AWSTraceHeader` of an
+//! SQS message, or the trace context of a Lambda invocation), which continue the
+//! trace.
+//!
+//! X-Ray only accepts trace IDs whose first 32 bits are the trace start time in
+//! epoch seconds. Pair this option with the X-Ray ID generator of the
+//! `export-xray` feature when the downstream targets report to X-Ray.
+
+// EventBridge attribute extraction — downcasts Input/Output to concrete
+// aws-sdk-eventbridge types and extracts event bus and entry attributes.
+
+use std::collections::BTreeSet;
+
+use aws_sdk_eventbridge::operation::put_events::{PutEventsInput, PutEventsOutput};
+use aws_smithy_runtime_api::client::interceptors::context;
+use opentelemetry::{Array, StringValue, Value};
+
+use super::super::{AttributeExtractor, SpanWrite};
+use crate::span_write::Status;
+use crate::xray::XRayTraceHeader;
+
+/// Name of the event bus used by entries that do not name one.
+const DEFAULT_EVENT_BUS: &str = "default";
+
+/// Attribute key for the distinct event buses of the `PutEvents` entries.
+const AWS_EVENTBRIDGE_EVENT_BUSES: &str = "aws.eventbridge.event_buses";
+/// Attribute key for the distinct sources of the `PutEvents` entries.
+const AWS_EVENTBRIDGE_SOURCES: &str = "aws.eventbridge.sources";
+/// Attribute key for the distinct detail types of the `PutEvents` entries.
+const AWS_EVENTBRIDGE_DETAIL_TYPES: &str = "aws.eventbridge.detail_types";
+/// Attribute key for the number of entries sent by `PutEvents`.
+const AWS_EVENTBRIDGE_ENTRY_COUNT: &str = "aws.eventbridge.entry_count";
+/// Attribute key for the global endpoint ID of `PutEvents`.
+const AWS_EVENTBRIDGE_ENDPOINT_ID: &str = "aws.eventbridge.endpoint_id";
+/// Attribute key for the `FailedEntryCount` of `PutEvents`.
+const AWS_EVENTBRIDGE_FAILED_ENTRY_COUNT: &str = "aws.eventbridge.failed_entry_count";
+/// Attribute key for the distinct error codes of the failed entries of `PutEvents`.
+const AWS_EVENTBRIDGE_FAILED_ENTRY_CODES: &str = "aws.eventbridge.failed_entry_codes";
+
+/// Attribute extractor for EventBridge SDK calls.
+///
+/// `EventBridgeExtractor` implements [`AttributeExtractor`] and is
+/// automatically used by [`DefaultExtractor`] when the `extract-eventbridge`
+/// feature is enabled. Construct it directly to enable trace header injection,
+/// then hand it to [`DefaultExtractor::set_eventbridge_extractor`].
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes.
+///
+/// [`DefaultExtractor`]: crate::interceptor::DefaultExtractor
+/// [`DefaultExtractor::set_eventbridge_extractor`]: crate::interceptor::DefaultExtractor::set_eventbridge_extractor
+#[derive(Debug, Default)]
+pub struct EventBridgeExtractor {
+    inject_trace_header: bool,
+}
+
+impl EventBridgeExtractor {
+    /// Creates a new `EventBridgeExtractor`, with trace header injection
+    /// disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::extract::eventbridge::EventBridgeExtractor;
+    ///
+    /// let extractor = EventBridgeExtractor::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables or disables setting the `TraceHeader` of `PutEvents` entries
+    /// from the current span.
+    ///
+    /// Entries that already carry a `TraceHeader` are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::{
+    ///     DefaultInterceptor, extract::eventbridge::EventBridgeExtractor,
+    /// };
+    ///
+    /// let mut interceptor = DefaultInterceptor::new();
+    /// interceptor.extractor.set_eventbridge_extractor(
+    ///     EventBridgeExtractor::new().with_trace_header_injection(true),
+    /// );
+    /// ```
+    pub fn with_trace_header_injection(mut self, inject: bool) -> Self {
+        self.inject_trace_header = inject;
+        self
+    }
+}
+
+/// Extracts EventBridge-specific OTel attributes from SDK inputs and outputs,
+/// and optionally injects the trace header into `PutEvents` entries.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes and which operations they apply to.
+impl<SW: SpanWrite> AttributeExtractor<SW> for EventBridgeExtractor {
+    fn extract_input(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        input: &context::Input,
+        span: &mut SW,
+    ) {
+        if operation == "PutEvents" {
+            let i = input
+                .downcast_ref::<PutEventsInput>()
+                .expect("correct type");
+            let entries = i.entries();
+            span.set_attribute(AWS_EVENTBRIDGE_ENTRY_COUNT, entries.len() as i64);
+
+            set_string_set(
+                span,
+                AWS_EVENTBRIDGE_EVENT_BUSES,
+                entries
+                    .iter()
+                    .map(|entry| entry.event_bus_name().unwrap_or(DEFAULT_EVENT_BUS)),
+            );
+            set_string_set(
+                span,
+                AWS_EVENTBRIDGE_SOURCES,
+                entries.iter().filter_map(|entry| entry.source()),
+            );
+            set_string_set(
+                span,
+                AWS_EVENTBRIDGE_DETAIL_TYPES,
+                entries.iter().filter_map(|entry| entry.detail_type()),
+            );
+            if let Some(endpoint_id) = i.endpoint_id() {
+                span.set_attribute(AWS_EVENTBRIDGE_ENDPOINT_ID, endpoint_id.to_owned());
+            }
+        }
+    }
+
+    fn modify_input(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        input: &mut context::Input,
+        span: &mut SW,
+    ) {
+        if !self.inject_trace_header || operation != "PutEvents" {
+            return;
+        }
+        let Some(header) = XRayTraceHeader::from_span_context(&span.span_context()) else {
+            return;
+        };
+        let header = header.to_string();
+        let i = input
+            .downcast_mut::<PutEventsInput>()
+            .expect("correct type");
+        for entry in i.entries.iter_mut().flatten() {
+            entry.trace_header.get_or_insert_with(|| header.clone());
+        }
+    }
+
+    fn extract_output(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        output: &context::Output,
+        span: &mut SW,
+    ) {
+        if operation == "PutEvents" {
+            let o = output
+                .downcast_ref::<PutEventsOutput>()
+                .expect("correct type");
+            set_failed_entries(span, o);
+        }
+    }
+}
+
+/// Sets `key` to the distinct, sorted `values`, unless there are none.
+fn set_string_set<'a>(
+    span: &mut impl SpanWrite,
+    key: &'static str,
+    values: impl Iterator<Item = &'a str>,
+) {
+    let values = values.collect::<BTreeSet<_>>();
+    if values.is_empty() {
+        return;
+    }
+    span.set_attribute(
+        key,
+        Value::Array(Array::String(
+            values
+                .into_iter()
+                .map(|value| StringValue::from(value.to_owned()))
+                .collect(),
+        )),
+    );
+}
+
+/// Sets the `FailedEntryCount` of `PutEvents` and the distinct error codes of
+/// the failed entries, and an error status when at least one entry failed.
+fn set_failed_entries(span: &mut impl SpanWrite, output: &PutEventsOutput) {
+    let failed = output.failed_entry_count();
+    span.set_attribute(AWS_EVENTBRIDGE_FAILED_ENTRY_COUNT, i64::from(failed));
+    if failed <= 0 {
+        return;
+    }
+    set_string_set(
+        span,
+        AWS_EVENTBRIDGE_FAILED_ENTRY_CODES,
+        output
+            .entries()
+            .iter()
+            .filter_map(|entry| entry.error_code()),
+    );
+    span.set_status(Status::error(format!(
+        "{} of {} entries failed",
+        failed,
+        output.entries().len()
+    )));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_eventbridge::types::{PutEventsRequestEntry, PutEventsResultEntry};
+    use opentelemetry::Value;
+
+    use crate::span_write::{SpanContext, SpanWrite, Status};
+
+    struct TestSpan {
+        attributes: Vec<(&'static str, Value)>,
+        status: Option<Status>,
+        span_context: SpanContext,
+    }
+
+    impl TestSpan {
+        fn new() -> Self {
+            Self {
+                attributes: vec![],
+                status: None,
+                span_context: SpanContext::empty_context(),
+            }
+        }
+
+        fn get(&self, key: &str) -> Option<&Value> {
+            self.attributes
+                .iter()
+                .rev()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v)
+        }
+    }
+
+    impl SpanWrite for TestSpan {
+        fn set_attribute(&mut self, key: &'static str, value: impl Into<Value>) {
+            self.attributes.push((key, value.into()));
+        }
+
+        fn set_status(&mut self, code: Status) {
+            self.status = Some(code);
+        }
+
+        fn span_context(&self) -> SpanContext {
+            self.span_context.clone()
+        }
+    }
+
+    fn entry(bus: Option<&str>, source: &str, detail_type: &str) -> PutEventsRequestEntry {
+        PutEventsRequestEntry::builder()
+            .set_event_bus_name(bus.map(str::to_owned))
+            .source(source)
+            .detail_type(detail_type)
+            .detail("{\"secret\":true}")
+            .build()
+    }
+
+    // Tests for EventBridgeExtractor::extract_input / extract_output — 2 consolidated tests
+
+    #[test]
+    fn extract_put_events_input() {
+        let extractor = EventBridgeExtractor::new();
+
+        let sdk_input = PutEventsInput::builder()
+            .entries(entry(Some("orders"), "app.orders", "OrderPlaced"))
+            .entries(entry(Some("orders"), "app.billing", "OrderPlaced"))
+            .entries(entry(Some("orders"), "app.orders", "OrderShipped"))
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "EventBridge",
+            "PutEvents",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(span.get(AWS_EVENTBRIDGE_ENTRY_COUNT), Some(&Value::I64(3)));
+        assert_eq!(
+            span.get(AWS_EVENTBRIDGE_EVENT_BUSES),
+            Some(&Value::Array(Array::String(vec!["orders".into()])))
+        );
+        assert_eq!(
+            span.get(AWS_EVENTBRIDGE_SOURCES),
+            Some(&Value::Array(Array::String(vec![
+                "app.billing".into(),
+                "app.orders".into()
+            ])))
+        );
+        assert_eq!(
+            span.get(AWS_EVENTBRIDGE_DETAIL_TYPES),
+            Some(&Value::Array(Array::String(vec![
+                "OrderPlaced".into(),
+                "OrderShipped".into()
+            ])))
+        );
+        assert!(
+            span.attributes
+                .iter()
+                .all(|(_, value)| !value.as_str().contains("secret"))
+        );
+
+        // Mixed buses: every bus recorded; a missing bus name is the default bus
+        let sdk_input = PutEventsInput::builder()
+            .entries(entry(Some("orders"), "app.orders", "OrderPlaced"))
+            .entries(entry(None, "app.orders", "OrderPlaced"))
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "EventBridge",
+            "PutEvents",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_EVENTBRIDGE_EVENT_BUSES),
+            Some(&Value::Array(Array::String(vec![
+                "default".into(),
+                "orders".into()
+            ])))
+        );
+
+        let sdk_input = PutEventsInput::builder()
+            .entries(entry(None, "app.orders", "OrderPlaced"))
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "EventBridge",
+            "PutEvents",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_EVENTBRIDGE_EVENT_BUSES),
+            Some(&Value::Array(Array::String(vec!["default".into()])))
+        );
+    }
+
+    #[test]
+    fn extract_put_events_output_partial_failure() {
+        let sdk_output = PutEventsOutput::builder()
+            .failed_entry_count(1)
+            .entries(PutEventsResultEntry::builder().event_id("e-1").build())
+            .entries(
+                PutEventsResultEntry::builder()
+                    .error_code("ThrottlingException")
+                    .build(),
+            )
+            .build();
+        let mut span = TestSpan::new();
+        EventBridgeExtractor::new().extract_output(
+            "EventBridge",
+            "PutEvents",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_EVENTBRIDGE_FAILED_ENTRY_COUNT),
+            Some(&Value::I64(1))
+        );
+        assert_eq!(
+            span.get(AWS_EVENTBRIDGE_FAILED_ENTRY_CODES),
+            Some(&Value::Array(Array::String(vec![
+                "ThrottlingException".into()
+            ])))
+        );
+        assert_eq!(span.status, Some(Status::error("1 of 2 entries failed")));
+    }
+
+    // Tests for trace header injection — 1 consolidated test
+
+    #[test]
+    fn modify_input_injects_trace_header() {
+        use opentelemetry::trace::{SpanId, TraceFlags, TraceId, TraceState};
+
+        let mut with_header = entry(None, "app.orders", "OrderPlaced");
+        with_header.trace_header = Some("Root=1-00000000-000000000000000000000001".to_owned());
+        let sdk_input = || {
+            PutEventsInput::builder()
+                .entries(entry(None, "app.orders", "OrderPlaced"))
+                .entries(with_header.clone())
+                .build()
+                .unwrap()
+        };
+
+        // Disabled by default
+        let mut span = TestSpan::new();
+        span.span_context = SpanContext::new(
+            TraceId::from_hex("5759e988bd862e3fe1be46a994272793").unwrap(),
+            SpanId::from_hex("53995c3f42cd8ad8").unwrap(),
+            TraceFlags::SAMPLED,
+            false,
+            TraceState::default(),
+        );
+        let mut input = context::Input::erase(sdk_input());
+        EventBridgeExtractor::new().modify_input("EventBridge", "PutEvents", &mut input, &mut span);
+        assert!(
+            input.downcast_ref::<PutEventsInput>().unwrap().entries()[0]
+                .trace_header()
+                .is_none()
+        );
+
+        let extractor = EventBridgeExtractor::new().with_trace_header_injection(true);
+        extractor.modify_input("EventBridge", "PutEvents", &mut input, &mut span);
+        let entries = input.downcast_ref::<PutEventsInput>().unwrap().entries();
+        assert_eq!(
+            entries[0].trace_header(),
+            Some("Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=1")
+        );
+        assert_eq!(entries[1].trace_header(), with_header.trace_header());
+
+        // Invalid span context: nothing injected
+        let mut input = context::Input::erase(sdk_input());
+        extractor.modify_input("EventBridge", "PutEvents", &mut input, &mut TestSpan::new());
+        assert!(
+            input.downcast_ref::<PutEventsInput>().unwrap().entries()[0]
+                .trace_header()
+                .is_none()
+        );
+    }
+}
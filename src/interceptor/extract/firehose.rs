@@ -0,0 +1,427 @@
+//! Amazon Data Firehose attribute extraction.
+//!
+//! This module provides [`FirehoseExtractor`], which implements
+//! [`super::super::AttributeExtractor`] for Firehose SDK calls. It is
+//! automatically used by [`super::super::DefaultExtractor`] when the
+//! `extract-firehose` feature is enabled.
+//!
+//! ## Extracted attributes
+//!
+//! **Set for operations that target a delivery stream:**
+//! - `aws.firehose.delivery_stream_name` — the delivery stream name
+//!
+//! Delivery stream operations are `PutRecord`, `PutRecordBatch`,
+//! `CreateDeliveryStream`, `DeleteDeliveryStream`, `DescribeDeliveryStream`,
+//! `UpdateDestination`, `StartDeliveryStreamEncryption`,
+//! `StopDeliveryStreamEncryption` and the tagging operations.
+//!
+//! **Set from input:**
+//! - `aws.firehose.record_count` — for `PutRecordBatch`, the number of records
+//!   sent
+//!
+//! **Set from output:**
+//! - `aws.firehose.record_id` — for `PutRecord`, the ID of the stored record
+//! - `aws.firehose.encrypted` — for `PutRecord` and `PutRecordBatch`, whether
+//!   server-side encryption was used for the delivery stream
+//! - `aws.firehose.failed_put_count` — for `PutRecordBatch`, the number of
+//!   records that failed
+//! - `aws.firehose.failed_record_codes`, `aws.firehose.failed_record_code_counts`
+//!   — for `PutRecordBatch`, the distinct error codes of the failed records and,
+//!   at the same index, the number of records that failed with that code
+//!
+//! `PutRecordBatch` reports per-record failures inside a successful response.
+//! When at least one record failed, the span status is set to error.
+
+// Firehose attribute extraction — downcasts Input/Output to concrete
+// aws-sdk-firehose types and extracts delivery stream and record attributes.
+
+use std::collections::BTreeMap;
+
+use aws_sdk_firehose::operation::{
+    create_delivery_stream::CreateDeliveryStreamInput,
+    delete_delivery_stream::DeleteDeliveryStreamInput,
+    describe_delivery_stream::DescribeDeliveryStreamInput,
+    list_tags_for_delivery_stream::ListTagsForDeliveryStreamInput,
+    put_record::{PutRecordInput, PutRecordOutput},
+    put_record_batch::{PutRecordBatchInput, PutRecordBatchOutput},
+    start_delivery_stream_encryption::StartDeliveryStreamEncryptionInput,
+    stop_delivery_stream_encryption::StopDeliveryStreamEncryptionInput,
+    tag_delivery_stream::TagDeliveryStreamInput,
+    untag_delivery_stream::UntagDeliveryStreamInput,
+    update_destination::UpdateDestinationInput,
+};
+use aws_smithy_runtime_api::client::interceptors::context;
+use opentelemetry::{Array, StringValue, Value};
+
+use super::super::{AttributeExtractor, SpanWrite};
+use crate::span_write::Status;
+
+/// Attribute key for the delivery stream name.
+const AWS_FIREHOSE_DELIVERY_STREAM_NAME: &str = "aws.firehose.delivery_stream_name";
+/// Attribute key for the number of records sent by `PutRecordBatch`.
+const AWS_FIREHOSE_RECORD_COUNT: &str = "aws.firehose.record_count";
+/// Attribute key for the ID of the record stored by `PutRecord`.
+const AWS_FIREHOSE_RECORD_ID: &str = "aws.firehose.record_id";
+/// Attribute key flagging that server-side encryption was used.
+const AWS_FIREHOSE_ENCRYPTED: &str = "aws.firehose.encrypted";
+/// Attribute key for the `FailedPutCount` of `PutRecordBatch`.
+const AWS_FIREHOSE_FAILED_PUT_COUNT: &str = "aws.firehose.failed_put_count";
+/// Attribute key for the distinct error codes of the failed records of `PutRecordBatch`.
+const AWS_FIREHOSE_FAILED_RECORD_CODES: &str = "aws.firehose.failed_record_codes";
+/// Attribute key for the number of failed records per error code, in the order
+/// of [`AWS_FIREHOSE_FAILED_RECORD_CODES`].
+const AWS_FIREHOSE_FAILED_RECORD_CODE_COUNTS: &str = "aws.firehose.failed_record_code_counts";
+
+/// Attribute extractor for Amazon Data Firehose SDK calls.
+///
+/// `FirehoseExtractor` implements [`AttributeExtractor`] and is automatically
+/// used by [`DefaultExtractor`] when the `extract-firehose` feature is enabled.
+/// You only need to construct it directly if you are composing a custom
+/// extraction pipeline.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes.
+///
+/// [`DefaultExtractor`]: crate::interceptor::DefaultExtractor
+#[derive(Debug, Default)]
+pub struct FirehoseExtractor {
+    _private: (),
+}
+
+impl FirehoseExtractor {
+    /// Creates a new `FirehoseExtractor`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::extract::firehose::FirehoseExtractor;
+    ///
+    /// let extractor = FirehoseExtractor::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+/// Extracts Firehose-specific OTel attributes from SDK inputs and outputs.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes and which operations they apply to.
+impl<SW: SpanWrite> AttributeExtractor<SW> for FirehoseExtractor {
+    fn extract_input(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        input: &context::Input,
+        span: &mut SW,
+    ) {
+        match operation {
+            "PutRecord" => set_delivery_stream_name(
+                span,
+                input
+                    .downcast_ref::<PutRecordInput>()
+                    .expect("correct type")
+                    .delivery_stream_name(),
+            ),
+            "PutRecordBatch" => {
+                let i = input
+                    .downcast_ref::<PutRecordBatchInput>()
+                    .expect("correct type");
+                set_delivery_stream_name(span, i.delivery_stream_name());
+                span.set_attribute(AWS_FIREHOSE_RECORD_COUNT, i.records().len() as i64);
+            }
+            "CreateDeliveryStream" => set_delivery_stream_name(
+                span,
+                input
+                    .downcast_ref::<CreateDeliveryStreamInput>()
+                    .expect("correct type")
+                    .delivery_stream_name(),
+            ),
+            "DeleteDeliveryStream" => set_delivery_stream_name(
+                span,
+                input
+                    .downcast_ref::<DeleteDeliveryStreamInput>()
+                    .expect("correct type")
+                    .delivery_stream_name(),
+            ),
+            "DescribeDeliveryStream" => set_delivery_stream_name(
+                span,
+                input
+                    .downcast_ref::<DescribeDeliveryStreamInput>()
+                    .expect("correct type")
+                    .delivery_stream_name(),
+            ),
+            "UpdateDestination" => set_delivery_stream_name(
+                span,
+                input
+                    .downcast_ref::<UpdateDestinationInput>()
+                    .expect("correct type")
+                    .delivery_stream_name(),
+            ),
+            "StartDeliveryStreamEncryption" => set_delivery_stream_name(
+                span,
+                input
+                    .downcast_ref::<StartDeliveryStreamEncryptionInput>()
+                    .expect("correct type")
+                    .delivery_stream_name(),
+            ),
+            "StopDeliveryStreamEncryption" => set_delivery_stream_name(
+                span,
+                input
+                    .downcast_ref::<StopDeliveryStreamEncryptionInput>()
+                    .expect("correct type")
+                    .delivery_stream_name(),
+            ),
+            "TagDeliveryStream" => set_delivery_stream_name(
+                span,
+                input
+                    .downcast_ref::<TagDeliveryStreamInput>()
+                    .expect("correct type")
+                    .delivery_stream_name(),
+            ),
+            "UntagDeliveryStream" => set_delivery_stream_name(
+                span,
+                input
+                    .downcast_ref::<UntagDeliveryStreamInput>()
+                    .expect("correct type")
+                    .delivery_stream_name(),
+            ),
+            "ListTagsForDeliveryStream" => set_delivery_stream_name(
+                span,
+                input
+                    .downcast_ref::<ListTagsForDeliveryStreamInput>()
+                    .expect("correct type")
+                    .delivery_stream_name(),
+            ),
+            _ => {}
+        }
+    }
+
+    fn extract_output(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        output: &context::Output,
+        span: &mut SW,
+    ) {
+        match operation {
+            "PutRecord" => {
+                let o = output
+                    .downcast_ref::<PutRecordOutput>()
+                    .expect("correct type");
+                if !o.record_id().is_empty() {
+                    span.set_attribute(AWS_FIREHOSE_RECORD_ID, o.record_id().to_owned());
+                }
+                if let Some(encrypted) = o.encrypted() {
+                    span.set_attribute(AWS_FIREHOSE_ENCRYPTED, encrypted);
+                }
+            }
+            "PutRecordBatch" => {
+                let o = output
+                    .downcast_ref::<PutRecordBatchOutput>()
+                    .expect("correct type");
+                if let Some(encrypted) = o.encrypted() {
+                    span.set_attribute(AWS_FIREHOSE_ENCRYPTED, encrypted);
+                }
+                set_failed_records(span, o);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Sets `aws.firehose.delivery_stream_name`.
+fn set_delivery_stream_name(span: &mut impl SpanWrite, delivery_stream_name: Option<&str>) {
+    if let Some(name) = delivery_stream_name.filter(|s| !s.is_empty()) {
+        span.set_attribute(AWS_FIREHOSE_DELIVERY_STREAM_NAME, name.to_owned());
+    }
+}
+
+/// Sets the `FailedPutCount` of `PutRecordBatch` and its per-error-code
+/// breakdown, and an error status when at least one record failed.
+fn set_failed_records(span: &mut impl SpanWrite, output: &PutRecordBatchOutput) {
+    let failed = output.failed_put_count();
+    span.set_attribute(AWS_FIREHOSE_FAILED_PUT_COUNT, i64::from(failed));
+    if failed <= 0 {
+        return;
+    }
+    let mut code_counts = BTreeMap::<&str, i64>::new();
+    for code in output
+        .request_responses()
+        .iter()
+        .filter_map(|entry| entry.error_code())
+    {
+        *code_counts.entry(code).or_default() += 1;
+    }
+    span.set_attribute(
+        AWS_FIREHOSE_FAILED_RECORD_CODES,
+        Value::Array(Array::String(
+            code_counts
+                .keys()
+                .map(|code| StringValue::from((*code).to_owned()))
+                .collect(),
+        )),
+    );
+    span.set_attribute(
+        AWS_FIREHOSE_FAILED_RECORD_CODE_COUNTS,
+        Value::Array(Array::I64(code_counts.into_values().collect())),
+    );
+    span.set_status(Status::error(format!(
+        "{} of {} records failed",
+        failed,
+        output.request_responses().len()
+    )));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::Value;
+
+    use crate::span_write::{SpanWrite, Status};
+
+    struct TestSpan {
+        attributes: Vec<(&'static str, Value)>,
+        status: Option<Status>,
+    }
+
+    impl TestSpan {
+        fn new() -> Self {
+            Self {
+                attributes: vec![],
+                status: None,
+            }
+        }
+
+        fn get(&self, key: &str) -> Option<&Value> {
+            self.attributes
+                .iter()
+                .rev()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v)
+        }
+    }
+
+    impl SpanWrite for TestSpan {
+        fn set_attribute(&mut self, key: &'static str, value: impl Into<Value>) {
+            self.attributes.push((key, value.into()));
+        }
+
+        fn set_status(&mut self, code: Status) {
+            self.status = Some(code);
+        }
+    }
+
+    // Tests for FirehoseExtractor — 2 consolidated tests
+
+    #[test]
+    fn extract_put_record() {
+        use aws_sdk_firehose::types::Record;
+        use aws_smithy_types::Blob;
+
+        let extractor = FirehoseExtractor::new();
+
+        let sdk_input = PutRecordInput::builder()
+            .delivery_stream_name("app-logs")
+            .record(Record::builder().data(Blob::new("line")).build().unwrap())
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "Firehose",
+            "PutRecord",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_FIREHOSE_DELIVERY_STREAM_NAME),
+            Some(&Value::from("app-logs"))
+        );
+
+        let sdk_output = PutRecordOutput::builder()
+            .record_id("rec-1")
+            .encrypted(true)
+            .build()
+            .unwrap();
+        extractor.extract_output(
+            "Firehose",
+            "PutRecord",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_FIREHOSE_RECORD_ID),
+            Some(&Value::from("rec-1"))
+        );
+        assert_eq!(span.get(AWS_FIREHOSE_ENCRYPTED), Some(&Value::Bool(true)));
+        assert!(span.status.is_none());
+    }
+
+    #[test]
+    fn extract_put_record_batch_partial_failure() {
+        use aws_sdk_firehose::types::{PutRecordBatchResponseEntry, Record};
+        use aws_smithy_types::Blob;
+
+        let extractor = FirehoseExtractor::new();
+
+        let record = Record::builder().data(Blob::new("line")).build().unwrap();
+        let sdk_input = PutRecordBatchInput::builder()
+            .delivery_stream_name("app-logs")
+            .records(record.clone())
+            .records(record.clone())
+            .records(record.clone())
+            .records(record)
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "Firehose",
+            "PutRecordBatch",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(span.get(AWS_FIREHOSE_RECORD_COUNT), Some(&Value::I64(4)));
+
+        let failed = |code: &str| {
+            PutRecordBatchResponseEntry::builder()
+                .error_code(code)
+                .build()
+        };
+        let sdk_output = PutRecordBatchOutput::builder()
+            .failed_put_count(3)
+            .encrypted(false)
+            .request_responses(
+                PutRecordBatchResponseEntry::builder()
+                    .record_id("rec-1")
+                    .build(),
+            )
+            .request_responses(failed("ServiceUnavailableException"))
+            .request_responses(failed("InternalFailure"))
+            .request_responses(failed("ServiceUnavailableException"))
+            .build()
+            .unwrap();
+        extractor.extract_output(
+            "Firehose",
+            "PutRecordBatch",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(span.get(AWS_FIREHOSE_ENCRYPTED), Some(&Value::Bool(false)));
+        assert_eq!(
+            span.get(AWS_FIREHOSE_FAILED_PUT_COUNT),
+            Some(&Value::I64(3))
+        );
+        assert_eq!(
+            span.get(AWS_FIREHOSE_FAILED_RECORD_CODES),
+            Some(&Value::Array(Array::String(vec![
+                "InternalFailure".into(),
+                "ServiceUnavailableException".into()
+            ])))
+        );
+        assert_eq!(
+            span.get(AWS_FIREHOSE_FAILED_RECORD_CODE_COUNTS),
+            Some(&Value::Array(Array::I64(vec![1, 2])))
+        );
+        assert_eq!(span.status, Some(Status::error("3 of 4 records failed")));
+    }
+}
@@ -0,0 +1,25 @@
+//! Value hashing shared by the extractors that record identifiers which may be
+//! sensitive (partition keys, task tokens, session IDs).
+//!
+//! A hashed value still lets spans be grouped or correlated by that value
+//! without exposing it.
+
+use sha2::{Digest, Sha256};
+
+/// Returns the lowercase hex-encoded SHA-256 digest of `value`.
+pub(super) fn sha256_hex(value: &str) -> String {
+    format!("{:x}", Sha256::digest(value.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sha256_hex_digest() {
+        assert_eq!(
+            sha256_hex("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
@@ -0,0 +1,578 @@
+//! Kinesis Data Streams attribute extraction following OTel semantic conventions.
+//!
+//! This module provides [`KinesisExtractor`], which implements
+//! [`super::super::AttributeExtractor`] for Kinesis SDK calls. It is
+//! automatically used by [`super::super::DefaultExtractor`] when the
+//! `extract-kinesis` feature is enabled.
+//!
+//! ## Extracted attributes
+//!
+//! **Set for operations that target a stream:**
+//! - `aws.kinesis.stream_name` — the stream name, given directly or taken from
+//!   the stream ARN
+//! - `aws.kinesis.stream_arn` — the stream ARN, when the call identifies the
+//!   stream by ARN
+//!
+//! Stream operations are `PutRecord`, `PutRecords`, `GetRecords`,
+//! `GetShardIterator`, `CreateStream`, `DeleteStream`, `DescribeStream`,
+//! `DescribeStreamSummary`, `ListShards`, `MergeShards`, `SplitShard`,
+//! `UpdateShardCount`, `RegisterStreamConsumer` and `ListStreamConsumers`.
+//!
+//! **Set from input:**
+//! - `aws.kinesis.partition_key` — for `PutRecord`, the partition key, or its
+//!   SHA-256 hex digest when [`KinesisExtractor::with_partition_key_hashing`]
+//!   is enabled
+//! - `aws.kinesis.shard_id` — for `GetShardIterator` and `SubscribeToShard`
+//! - `aws.kinesis.record_count` — for `PutRecords`, the number of records sent
+//!
+//! **Set from output:**
+//! - `aws.kinesis.shard_id`, `aws.kinesis.sequence_number` — for `PutRecord`,
+//!   where the record was stored
+//! - `aws.kinesis.failed_record_count`, `aws.kinesis.failed_record_codes` — for
+//!   `PutRecords`, the `FailedRecordCount` and the distinct error codes of the
+//!   failed records
+//! - `aws.kinesis.record_count` — for `GetRecords`, the number of records read
+//! - `aws.kinesis.millis_behind_latest` — for `GetRecords`, how far the shard
+//!   iterator is behind the tip of the stream, in milliseconds
+//!
+//! `PutRecords` reports per-record failures inside a successful response.
+//! When at least one record failed, the span status is set to error.
+
+// Kinesis attribute extraction — downcasts Input/Output to concrete
+// aws-sdk-kinesis types and extracts stream, shard and record attributes.
+
+use std::collections::BTreeSet;
+
+use aws_sdk_kinesis::operation::{
+    create_stream::CreateStreamInput,
+    delete_stream::DeleteStreamInput,
+    describe_stream::DescribeStreamInput,
+    describe_stream_summary::DescribeStreamSummaryInput,
+    get_records::{GetRecordsInput, GetRecordsOutput},
+    get_shard_iterator::GetShardIteratorInput,
+    list_shards::ListShardsInput,
+    list_stream_consumers::ListStreamConsumersInput,
+    merge_shards::MergeShardsInput,
+    put_record::{PutRecordInput, PutRecordOutput},
+    put_records::{PutRecordsInput, PutRecordsOutput},
+    register_stream_consumer::RegisterStreamConsumerInput,
+    split_shard::SplitShardInput,
+    subscribe_to_shard::SubscribeToShardInput,
+    update_shard_count::UpdateShardCountInput,
+};
+use aws_smithy_runtime_api::client::interceptors::context;
+use opentelemetry::{Array, StringValue, Value};
+use opentelemetry_semantic_conventions::attribute as semco;
+
+use super::super::{AttributeExtractor, SpanWrite};
+use super::hash::sha256_hex;
+use crate::span_write::Status;
+
+/// Attribute key for the stream ARN.
+const AWS_KINESIS_STREAM_ARN: &str = "aws.kinesis.stream_arn";
+/// Attribute key for the partition key of `PutRecord` (possibly hashed).
+const AWS_KINESIS_PARTITION_KEY: &str = "aws.kinesis.partition_key";
+/// Attribute key for the shard ID.
+const AWS_KINESIS_SHARD_ID: &str = "aws.kinesis.shard_id";
+/// Attribute key for the sequence number of the record stored by `PutRecord`.
+const AWS_KINESIS_SEQUENCE_NUMBER: &str = "aws.kinesis.sequence_number";
+/// Attribute key for the number of records sent by `PutRecords` or read by `GetRecords`.
+const AWS_KINESIS_RECORD_COUNT: &str = "aws.kinesis.record_count";
+/// Attribute key for the number of records `PutRecords` failed to store.
+const AWS_KINESIS_FAILED_RECORD_COUNT: &str = "aws.kinesis.failed_record_count";
+/// Attribute key for the distinct error codes of the failed records of `PutRecords`.
+const AWS_KINESIS_FAILED_RECORD_CODES: &str = "aws.kinesis.failed_record_codes";
+/// Attribute key for the `MillisBehindLatest` consumer lag of `GetRecords`.
+const AWS_KINESIS_MILLIS_BEHIND_LATEST: &str = "aws.kinesis.millis_behind_latest";
+
+/// Attribute extractor for Kinesis Data Streams SDK calls.
+///
+/// `KinesisExtractor` implements [`AttributeExtractor`] and is automatically
+/// used by [`DefaultExtractor`] when the `extract-kinesis` feature is enabled.
+/// Construct it directly to enable partition key hashing, then hand it to
+/// [`DefaultExtractor::set_kinesis_extractor`].
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes.
+///
+/// [`DefaultExtractor`]: crate::interceptor::DefaultExtractor
+/// [`DefaultExtractor::set_kinesis_extractor`]: crate::interceptor::DefaultExtractor::set_kinesis_extractor
+#[derive(Debug, Default)]
+pub struct KinesisExtractor {
+    hash_partition_keys: bool,
+}
+
+impl KinesisExtractor {
+    /// Creates a new `KinesisExtractor`, recording partition keys as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::extract::kinesis::KinesisExtractor;
+    ///
+    /// let extractor = KinesisExtractor::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the SHA-256 hex digest of partition keys instead of the keys
+    /// themselves.
+    ///
+    /// Enable this when partition keys carry user identifiers or other values
+    /// that must not end up in traces. Records with the same partition key
+    /// still share the same attribute value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::{
+    ///     DefaultInterceptor, extract::kinesis::KinesisExtractor,
+    /// };
+    ///
+    /// let mut interceptor = DefaultInterceptor::new();
+    /// interceptor
+    ///     .extractor
+    ///     .set_kinesis_extractor(KinesisExtractor::new().with_partition_key_hashing(true));
+    /// ```
+    pub fn with_partition_key_hashing(mut self, hash: bool) -> Self {
+        self.hash_partition_keys = hash;
+        self
+    }
+}
+
+/// Extracts Kinesis-specific OTel attributes from SDK inputs and outputs.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes and which operations they apply to.
+impl<SW: SpanWrite> AttributeExtractor<SW> for KinesisExtractor {
+    fn extract_input(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        input: &context::Input,
+        span: &mut SW,
+    ) {
+        match operation {
+            "PutRecord" => {
+                let i = input
+                    .downcast_ref::<PutRecordInput>()
+                    .expect("correct type");
+                set_stream_attrs(span, i.stream_name(), i.stream_arn());
+                if let Some(partition_key) = i.partition_key() {
+                    let partition_key = if self.hash_partition_keys {
+                        sha256_hex(partition_key)
+                    } else {
+                        partition_key.to_owned()
+                    };
+                    span.set_attribute(AWS_KINESIS_PARTITION_KEY, partition_key);
+                }
+            }
+            "PutRecords" => {
+                let i = input
+                    .downcast_ref::<PutRecordsInput>()
+                    .expect("correct type");
+                set_stream_attrs(span, i.stream_name(), i.stream_arn());
+                span.set_attribute(AWS_KINESIS_RECORD_COUNT, i.records().len() as i64);
+            }
+            "GetRecords" => set_stream_attrs(
+                span,
+                None,
+                input
+                    .downcast_ref::<GetRecordsInput>()
+                    .expect("correct type")
+                    .stream_arn(),
+            ),
+            "GetShardIterator" => {
+                let i = input
+                    .downcast_ref::<GetShardIteratorInput>()
+                    .expect("correct type");
+                set_stream_attrs(span, i.stream_name(), i.stream_arn());
+                set_shard_id(span, i.shard_id());
+            }
+            "SubscribeToShard" => set_shard_id(
+                span,
+                input
+                    .downcast_ref::<SubscribeToShardInput>()
+                    .expect("correct type")
+                    .shard_id(),
+            ),
+            "CreateStream" => set_stream_attrs(
+                span,
+                input
+                    .downcast_ref::<CreateStreamInput>()
+                    .expect("correct type")
+                    .stream_name(),
+                None,
+            ),
+            "DeleteStream" => {
+                let i = input
+                    .downcast_ref::<DeleteStreamInput>()
+                    .expect("correct type");
+                set_stream_attrs(span, i.stream_name(), i.stream_arn());
+            }
+            "DescribeStream" => {
+                let i = input
+                    .downcast_ref::<DescribeStreamInput>()
+                    .expect("correct type");
+                set_stream_attrs(span, i.stream_name(), i.stream_arn());
+            }
+            "DescribeStreamSummary" => {
+                let i = input
+                    .downcast_ref::<DescribeStreamSummaryInput>()
+                    .expect("correct type");
+                set_stream_attrs(span, i.stream_name(), i.stream_arn());
+            }
+            "ListShards" => {
+                let i = input
+                    .downcast_ref::<ListShardsInput>()
+                    .expect("correct type");
+                set_stream_attrs(span, i.stream_name(), i.stream_arn());
+            }
+            "MergeShards" => {
+                let i = input
+                    .downcast_ref::<MergeShardsInput>()
+                    .expect("correct type");
+                set_stream_attrs(span, i.stream_name(), i.stream_arn());
+            }
+            "SplitShard" => {
+                let i = input
+                    .downcast_ref::<SplitShardInput>()
+                    .expect("correct type");
+                set_stream_attrs(span, i.stream_name(), i.stream_arn());
+            }
+            "UpdateShardCount" => {
+                let i = input
+                    .downcast_ref::<UpdateShardCountInput>()
+                    .expect("correct type");
+                set_stream_attrs(span, i.stream_name(), i.stream_arn());
+            }
+            "RegisterStreamConsumer" => set_stream_attrs(
+                span,
+                None,
+                input
+                    .downcast_ref::<RegisterStreamConsumerInput>()
+                    .expect("correct type")
+                    .stream_arn(),
+            ),
+            "ListStreamConsumers" => set_stream_attrs(
+                span,
+                None,
+                input
+                    .downcast_ref::<ListStreamConsumersInput>()
+                    .expect("correct type")
+                    .stream_arn(),
+            ),
+            _ => {}
+        }
+    }
+
+    fn extract_output(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        output: &context::Output,
+        span: &mut SW,
+    ) {
+        match operation {
+            "PutRecord" => {
+                let o = output
+                    .downcast_ref::<PutRecordOutput>()
+                    .expect("correct type");
+                set_shard_id(span, Some(o.shard_id()));
+                if !o.sequence_number().is_empty() {
+                    span.set_attribute(AWS_KINESIS_SEQUENCE_NUMBER, o.sequence_number().to_owned());
+                }
+            }
+            "PutRecords" => {
+                let o = output
+                    .downcast_ref::<PutRecordsOutput>()
+                    .expect("correct type");
+                set_failed_records(span, o);
+            }
+            "GetRecords" => {
+                let o = output
+                    .downcast_ref::<GetRecordsOutput>()
+                    .expect("correct type");
+                span.set_attribute(AWS_KINESIS_RECORD_COUNT, o.records().len() as i64);
+                if let Some(millis_behind_latest) = o.millis_behind_latest() {
+                    span.set_attribute(AWS_KINESIS_MILLIS_BEHIND_LATEST, millis_behind_latest);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Sets `aws.kinesis.stream_name` and `aws.kinesis.stream_arn`, taking the
+/// stream name from the ARN when it is not given directly.
+fn set_stream_attrs(
+    span: &mut impl SpanWrite,
+    stream_name: Option<&str>,
+    stream_arn: Option<&str>,
+) {
+    if let Some(arn) = stream_arn {
+        span.set_attribute(AWS_KINESIS_STREAM_ARN, arn.to_owned());
+    }
+
+    // Kinesis stream ARNs follow the pattern:
+    //   arn:<partition>:kinesis:<region>:<account-id>:stream/<stream-name>
+    let stream_name = stream_name.or_else(|| {
+        stream_arn
+            .and_then(|arn| arn.rsplit_once(":stream/"))
+            .map(|(_, name)| name)
+    });
+    if let Some(stream_name) = stream_name.filter(|s| !s.is_empty()) {
+        span.set_attribute(semco::AWS_KINESIS_STREAM_NAME, stream_name.to_owned());
+    }
+}
+
+/// Sets `aws.kinesis.shard_id`.
+fn set_shard_id(span: &mut impl SpanWrite, shard_id: Option<&str>) {
+    if let Some(shard_id) = shard_id.filter(|s| !s.is_empty()) {
+        span.set_attribute(AWS_KINESIS_SHARD_ID, shard_id.to_owned());
+    }
+}
+
+/// Sets the failed-record count and distinct error codes of `PutRecords`, and
+/// an error status when at least one of its records failed.
+fn set_failed_records(span: &mut impl SpanWrite, output: &PutRecordsOutput) {
+    let failed = output.failed_record_count().unwrap_or_default();
+    span.set_attribute(AWS_KINESIS_FAILED_RECORD_COUNT, i64::from(failed));
+    if failed <= 0 {
+        return;
+    }
+    let codes = output
+        .records()
+        .iter()
+        .filter_map(|record| record.error_code())
+        .collect::<BTreeSet<_>>();
+    span.set_attribute(
+        AWS_KINESIS_FAILED_RECORD_CODES,
+        Value::Array(Array::String(
+            codes
+                .into_iter()
+                .map(|code| StringValue::from(code.to_owned()))
+                .collect(),
+        )),
+    );
+    span.set_status(Status::error(format!(
+        "{} of {} records failed",
+        failed,
+        output.records().len()
+    )));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::Value;
+    use opentelemetry_semantic_conventions::attribute as semco;
+
+    use crate::span_write::{SpanWrite, Status};
+
+    struct TestSpan {
+        attributes: Vec<(&'static str, Value)>,
+        status: Option<Status>,
+    }
+
+    impl TestSpan {
+        fn new() -> Self {
+            Self {
+                attributes: vec![],
+                status: None,
+            }
+        }
+
+        fn get(&self, key: &str) -> Option<&Value> {
+            self.attributes
+                .iter()
+                .rev()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v)
+        }
+    }
+
+    impl SpanWrite for TestSpan {
+        fn set_attribute(&mut self, key: &'static str, value: impl Into<Value>) {
+            self.attributes.push((key, value.into()));
+        }
+
+        fn set_status(&mut self, code: Status) {
+            self.status = Some(code);
+        }
+    }
+
+    const STREAM_ARN: &str = "arn:aws:kinesis:us-east-1:123456789012:stream/clicks";
+
+    // Tests for KinesisExtractor::extract_input — 2 consolidated tests
+
+    #[test]
+    fn extract_put_record_input_and_output() {
+        let sdk_input = PutRecordInput::builder()
+            .stream_arn(STREAM_ARN)
+            .partition_key("user-42")
+            .build()
+            .unwrap();
+        let input = context::Input::erase(sdk_input);
+
+        let mut span = TestSpan::new();
+        KinesisExtractor::new().extract_input("Kinesis", "PutRecord", &input, &mut span);
+        assert_eq!(
+            span.get(AWS_KINESIS_STREAM_ARN),
+            Some(&Value::from(STREAM_ARN))
+        );
+        assert_eq!(
+            span.get(semco::AWS_KINESIS_STREAM_NAME),
+            Some(&Value::from("clicks"))
+        );
+        assert_eq!(
+            span.get(AWS_KINESIS_PARTITION_KEY),
+            Some(&Value::from("user-42"))
+        );
+
+        // Hashed partition key
+        let extractor = KinesisExtractor::new().with_partition_key_hashing(true);
+        let mut span = TestSpan::new();
+        extractor.extract_input("Kinesis", "PutRecord", &input, &mut span);
+        assert_eq!(
+            span.get(AWS_KINESIS_PARTITION_KEY),
+            Some(&Value::from(sha256_hex("user-42")))
+        );
+
+        let sdk_output = PutRecordOutput::builder()
+            .shard_id("shardId-000000000001")
+            .sequence_number("4960")
+            .build()
+            .unwrap();
+        extractor.extract_output(
+            "Kinesis",
+            "PutRecord",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_KINESIS_SHARD_ID),
+            Some(&Value::from("shardId-000000000001"))
+        );
+        assert_eq!(
+            span.get(AWS_KINESIS_SEQUENCE_NUMBER),
+            Some(&Value::from("4960"))
+        );
+    }
+
+    #[test]
+    fn extract_input_stream_name_takes_precedence() {
+        let sdk_input = DescribeStreamSummaryInput::builder()
+            .stream_name("orders")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        KinesisExtractor::new().extract_input(
+            "Kinesis",
+            "DescribeStreamSummary",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(semco::AWS_KINESIS_STREAM_NAME),
+            Some(&Value::from("orders"))
+        );
+        assert!(span.get(AWS_KINESIS_STREAM_ARN).is_none());
+
+        // An ARN without a stream resource yields no stream name
+        let mut span = TestSpan::new();
+        set_stream_attrs(&mut span, None, Some("arn:aws:kinesis:us-east-1:1:nope"));
+        assert!(span.get(semco::AWS_KINESIS_STREAM_NAME).is_none());
+    }
+
+    // Tests for KinesisExtractor::extract_output — 2 consolidated tests
+
+    #[test]
+    fn extract_put_records_output_partial_failure() {
+        use aws_sdk_kinesis::types::PutRecordsResultEntry;
+
+        let ok = PutRecordsResultEntry::builder()
+            .shard_id("shardId-000000000000")
+            .sequence_number("1")
+            .build();
+        let throttled = PutRecordsResultEntry::builder()
+            .error_code("ProvisionedThroughputExceededException")
+            .build();
+        let sdk_output = PutRecordsOutput::builder()
+            .failed_record_count(2)
+            .records(ok)
+            .records(throttled.clone())
+            .records(throttled)
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        KinesisExtractor::new().extract_output(
+            "Kinesis",
+            "PutRecords",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_KINESIS_FAILED_RECORD_COUNT),
+            Some(&Value::I64(2))
+        );
+        assert_eq!(
+            span.get(AWS_KINESIS_FAILED_RECORD_CODES),
+            Some(&Value::Array(Array::String(vec![
+                "ProvisionedThroughputExceededException".into()
+            ])))
+        );
+        assert_eq!(span.status, Some(Status::error("2 of 3 records failed")));
+
+        // No failure: count only, no status
+        let sdk_output = PutRecordsOutput::builder()
+            .failed_record_count(0)
+            .set_records(Some(vec![]))
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        KinesisExtractor::new().extract_output(
+            "Kinesis",
+            "PutRecords",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_KINESIS_FAILED_RECORD_COUNT),
+            Some(&Value::I64(0))
+        );
+        assert!(span.get(AWS_KINESIS_FAILED_RECORD_CODES).is_none());
+        assert!(span.status.is_none());
+    }
+
+    #[test]
+    fn extract_get_records_output_lag() {
+        use aws_sdk_kinesis::types::Record;
+        use aws_smithy_types::Blob;
+
+        let record = Record::builder()
+            .sequence_number("1")
+            .data(Blob::new("x"))
+            .partition_key("k")
+            .build()
+            .unwrap();
+        let sdk_output = GetRecordsOutput::builder()
+            .records(record)
+            .millis_behind_latest(125_000)
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        KinesisExtractor::new().extract_output(
+            "Kinesis",
+            "GetRecords",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(span.get(AWS_KINESIS_RECORD_COUNT), Some(&Value::I64(1)));
+        assert_eq!(
+            span.get(AWS_KINESIS_MILLIS_BEHIND_LATEST),
+            Some(&Value::I64(125_000))
+        );
+    }
+}
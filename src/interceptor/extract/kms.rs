@@ -0,0 +1,358 @@
+//! KMS attribute extraction.
+//!
+//! This module provides [`KMSExtractor`], which implements
+//! [`super::super::AttributeExtractor`] for the KMS `Encrypt`, `Decrypt`,
+//! `GenerateDataKey` and `Sign` SDK calls. It is automatically used by
+//! [`super::super::DefaultExtractor`] when the `extract-kms` feature is
+//! enabled.
+//!
+//! ## Extracted attributes
+//!
+//! **Set from input:**
+//! - `aws.kms.key_id` — the key ID, key ARN, alias name or alias ARN given
+//! - `aws.kms.grant_token_count` — the number of grant tokens sent, when any
+//! - `aws.kms.encryption_algorithm` — for `Encrypt` and `Decrypt`, the
+//!   algorithm requested
+//! - `aws.kms.signing_algorithm`, `aws.kms.message_type` — for `Sign`
+//! - `aws.kms.key_spec`, `aws.kms.number_of_bytes` — for `GenerateDataKey`,
+//!   the data key length requested
+//! - `aws.kms.plaintext_size` — for `Encrypt`, the plaintext size in bytes
+//! - `aws.kms.ciphertext_size` — for `Decrypt`, the ciphertext size in bytes
+//! - `aws.kms.message_size` — for `Sign`, the message size in bytes
+//!
+//! **Set from output:**
+//! - `aws.kms.key_arn` — the ARN of the key that was used, which resolves an
+//!   alias given as input
+//! - `aws.kms.encryption_algorithm`, `aws.kms.signing_algorithm` — the
+//!   algorithm that was used
+//! - `aws.kms.ciphertext_size` — for `Encrypt` and `GenerateDataKey`, the
+//!   ciphertext size in bytes
+//! - `aws.kms.plaintext_size` — for `Decrypt` and `GenerateDataKey`, the
+//!   plaintext size in bytes
+//!
+//! Only the sizes of plaintexts, ciphertexts and messages are recorded, never
+//! their bytes. Grant tokens and encryption contexts are never recorded.
+
+// KMS attribute extraction — downcasts Input/Output to concrete aws-sdk-kms
+// types and extracts key identifiers, algorithms and payload sizes.
+
+use aws_sdk_kms::operation::{
+    decrypt::{DecryptInput, DecryptOutput},
+    encrypt::{EncryptInput, EncryptOutput},
+    generate_data_key::{GenerateDataKeyInput, GenerateDataKeyOutput},
+    sign::{SignInput, SignOutput},
+};
+use aws_smithy_runtime_api::client::interceptors::context;
+use aws_smithy_types::Blob;
+
+use super::super::{AttributeExtractor, SpanWrite};
+use super::set_str;
+
+/// Attribute key for the key identifier given as input.
+const AWS_KMS_KEY_ID: &str = "aws.kms.key_id";
+/// Attribute key for the ARN of the key that was used.
+const AWS_KMS_KEY_ARN: &str = "aws.kms.key_arn";
+/// Attribute key for the number of grant tokens sent.
+const AWS_KMS_GRANT_TOKEN_COUNT: &str = "aws.kms.grant_token_count";
+/// Attribute key for the encryption algorithm.
+const AWS_KMS_ENCRYPTION_ALGORITHM: &str = "aws.kms.encryption_algorithm";
+/// Attribute key for the signing algorithm.
+const AWS_KMS_SIGNING_ALGORITHM: &str = "aws.kms.signing_algorithm";
+/// Attribute key for the type of message signed by `Sign`.
+const AWS_KMS_MESSAGE_TYPE: &str = "aws.kms.message_type";
+/// Attribute key for the data key spec requested by `GenerateDataKey`.
+const AWS_KMS_KEY_SPEC: &str = "aws.kms.key_spec";
+/// Attribute key for the data key length in bytes requested by `GenerateDataKey`.
+const AWS_KMS_NUMBER_OF_BYTES: &str = "aws.kms.number_of_bytes";
+/// Attribute key for the plaintext size in bytes.
+const AWS_KMS_PLAINTEXT_SIZE: &str = "aws.kms.plaintext_size";
+/// Attribute key for the ciphertext size in bytes.
+const AWS_KMS_CIPHERTEXT_SIZE: &str = "aws.kms.ciphertext_size";
+/// Attribute key for the size in bytes of the message signed by `Sign`.
+const AWS_KMS_MESSAGE_SIZE: &str = "aws.kms.message_size";
+
+/// Attribute extractor for KMS SDK calls.
+///
+/// `KMSExtractor` implements [`AttributeExtractor`] and is automatically used
+/// by [`DefaultExtractor`] when the `extract-kms` feature is enabled. You only
+/// need to construct it directly if you are composing a custom extraction
+/// pipeline.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes.
+///
+/// [`DefaultExtractor`]: crate::interceptor::DefaultExtractor
+#[derive(Debug, Default)]
+pub struct KMSExtractor {
+    _private: (),
+}
+
+impl KMSExtractor {
+    /// Creates a new `KMSExtractor`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::extract::kms::KMSExtractor;
+    ///
+    /// let extractor = KMSExtractor::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+/// Extracts KMS OTel attributes from SDK inputs and outputs.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes and which operations they apply to.
+impl<SW: SpanWrite> AttributeExtractor<SW> for KMSExtractor {
+    fn extract_input(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        input: &context::Input,
+        span: &mut SW,
+    ) {
+        match operation {
+            "Encrypt" => {
+                let i = input.downcast_ref::<EncryptInput>().expect("correct type");
+                set_key(span, i.key_id(), i.grant_tokens());
+                set_str(
+                    span,
+                    AWS_KMS_ENCRYPTION_ALGORITHM,
+                    i.encryption_algorithm().map(|a| a.as_str()),
+                );
+                set_size(span, AWS_KMS_PLAINTEXT_SIZE, i.plaintext());
+            }
+            "Decrypt" => {
+                let i = input.downcast_ref::<DecryptInput>().expect("correct type");
+                set_key(span, i.key_id(), i.grant_tokens());
+                set_str(
+                    span,
+                    AWS_KMS_ENCRYPTION_ALGORITHM,
+                    i.encryption_algorithm().map(|a| a.as_str()),
+                );
+                set_size(span, AWS_KMS_CIPHERTEXT_SIZE, i.ciphertext_blob());
+            }
+            "GenerateDataKey" => {
+                let i = input
+                    .downcast_ref::<GenerateDataKeyInput>()
+                    .expect("correct type");
+                set_key(span, i.key_id(), i.grant_tokens());
+                set_str(span, AWS_KMS_KEY_SPEC, i.key_spec().map(|s| s.as_str()));
+                if let Some(number_of_bytes) = i.number_of_bytes() {
+                    span.set_attribute(AWS_KMS_NUMBER_OF_BYTES, i64::from(number_of_bytes));
+                }
+            }
+            "Sign" => {
+                let i = input.downcast_ref::<SignInput>().expect("correct type");
+                set_key(span, i.key_id(), i.grant_tokens());
+                set_str(
+                    span,
+                    AWS_KMS_SIGNING_ALGORITHM,
+                    i.signing_algorithm().map(|a| a.as_str()),
+                );
+                set_str(
+                    span,
+                    AWS_KMS_MESSAGE_TYPE,
+                    i.message_type().map(|t| t.as_str()),
+                );
+                set_size(span, AWS_KMS_MESSAGE_SIZE, i.message());
+            }
+            _ => {}
+        }
+    }
+
+    fn extract_output(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        output: &context::Output,
+        span: &mut SW,
+    ) {
+        match operation {
+            "Encrypt" => {
+                let o = output
+                    .downcast_ref::<EncryptOutput>()
+                    .expect("correct type");
+                set_str(span, AWS_KMS_KEY_ARN, o.key_id());
+                set_str(
+                    span,
+                    AWS_KMS_ENCRYPTION_ALGORITHM,
+                    o.encryption_algorithm().map(|a| a.as_str()),
+                );
+                set_size(span, AWS_KMS_CIPHERTEXT_SIZE, o.ciphertext_blob());
+            }
+            "Decrypt" => {
+                let o = output
+                    .downcast_ref::<DecryptOutput>()
+                    .expect("correct type");
+                set_str(span, AWS_KMS_KEY_ARN, o.key_id());
+                set_str(
+                    span,
+                    AWS_KMS_ENCRYPTION_ALGORITHM,
+                    o.encryption_algorithm().map(|a| a.as_str()),
+                );
+                set_size(span, AWS_KMS_PLAINTEXT_SIZE, o.plaintext());
+            }
+            "GenerateDataKey" => {
+                let o = output
+                    .downcast_ref::<GenerateDataKeyOutput>()
+                    .expect("correct type");
+                set_str(span, AWS_KMS_KEY_ARN, o.key_id());
+                set_size(span, AWS_KMS_CIPHERTEXT_SIZE, o.ciphertext_blob());
+                set_size(span, AWS_KMS_PLAINTEXT_SIZE, o.plaintext());
+            }
+            "Sign" => {
+                let o = output.downcast_ref::<SignOutput>().expect("correct type");
+                set_str(span, AWS_KMS_KEY_ARN, o.key_id());
+                set_str(
+                    span,
+                    AWS_KMS_SIGNING_ALGORITHM,
+                    o.signing_algorithm().map(|a| a.as_str()),
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Sets the key identifier and the number of grant tokens, never the tokens.
+fn set_key(span: &mut impl SpanWrite, key_id: Option<&str>, grant_tokens: &[String]) {
+    set_str(span, AWS_KMS_KEY_ID, key_id);
+    if !grant_tokens.is_empty() {
+        span.set_attribute(AWS_KMS_GRANT_TOKEN_COUNT, grant_tokens.len() as i64);
+    }
+}
+
+/// Sets `key` to the size in bytes of `blob`, never its content.
+fn set_size(span: &mut impl SpanWrite, key: &'static str, blob: Option<&Blob>) {
+    if let Some(blob) = blob {
+        span.set_attribute(key, blob.as_ref().len() as i64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_kms::types::{EncryptionAlgorithmSpec, SigningAlgorithmSpec};
+    use opentelemetry::Value;
+
+    use crate::span_write::{SpanContext, SpanWrite, Status};
+
+    struct TestSpan {
+        attributes: Vec<(&'static str, Value)>,
+    }
+
+    impl TestSpan {
+        fn new() -> Self {
+            Self { attributes: vec![] }
+        }
+
+        fn get(&self, key: &str) -> Option<&Value> {
+            self.attributes
+                .iter()
+                .rev()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v)
+        }
+    }
+
+    impl SpanWrite for TestSpan {
+        fn set_attribute(&mut self, key: &'static str, value: impl Into<Value>) {
+            self.attributes.push((key, value.into()));
+        }
+
+        fn set_status(&mut self, _code: Status) {}
+
+        fn span_context(&self) -> SpanContext {
+            SpanContext::empty_context()
+        }
+    }
+
+    const KEY_ARN: &str =
+        "arn:aws:kms:us-east-1:123456789012:key/1234abcd-12ab-34cd-56ef-1234567890ab";
+
+    // Tests for KMSExtractor — 2 consolidated tests
+
+    #[test]
+    fn extract_encrypt_records_sizes_only() {
+        let extractor = KMSExtractor::new();
+
+        let sdk_input = EncryptInput::builder()
+            .key_id("alias/orders")
+            .plaintext(Blob::new("top-secret"))
+            .encryption_algorithm(EncryptionAlgorithmSpec::SymmetricDefault)
+            .grant_tokens("grant-token-a")
+            .grant_tokens("grant-token-b")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "KMS",
+            "Encrypt",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(span.get(AWS_KMS_KEY_ID), Some(&Value::from("alias/orders")));
+        assert_eq!(span.get(AWS_KMS_GRANT_TOKEN_COUNT), Some(&Value::I64(2)));
+        assert_eq!(span.get(AWS_KMS_PLAINTEXT_SIZE), Some(&Value::I64(10)));
+        assert_eq!(
+            span.get(AWS_KMS_ENCRYPTION_ALGORITHM),
+            Some(&Value::from("SYMMETRIC_DEFAULT"))
+        );
+
+        let sdk_output = EncryptOutput::builder()
+            .key_id(KEY_ARN)
+            .ciphertext_blob(Blob::new(vec![0u8; 184]))
+            .encryption_algorithm(EncryptionAlgorithmSpec::SymmetricDefault)
+            .build();
+        extractor.extract_output(
+            "KMS",
+            "Encrypt",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(span.get(AWS_KMS_KEY_ARN), Some(&Value::from(KEY_ARN)));
+        assert_eq!(span.get(AWS_KMS_CIPHERTEXT_SIZE), Some(&Value::I64(184)));
+        assert!(span.attributes.iter().all(|(_, value)| {
+            let value = value.to_string();
+            !value.contains("top-secret") && !value.contains("grant-token")
+        }));
+    }
+
+    #[test]
+    fn extract_generate_data_key_and_sign() {
+        let extractor = KMSExtractor::new();
+
+        let sdk_output = GenerateDataKeyOutput::builder()
+            .key_id(KEY_ARN)
+            .plaintext(Blob::new(vec![7u8; 32]))
+            .ciphertext_blob(Blob::new(vec![0u8; 184]))
+            .build();
+        let mut span = TestSpan::new();
+        extractor.extract_output(
+            "KMS",
+            "GenerateDataKey",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(span.get(AWS_KMS_PLAINTEXT_SIZE), Some(&Value::I64(32)));
+        assert_eq!(span.get(AWS_KMS_CIPHERTEXT_SIZE), Some(&Value::I64(184)));
+
+        let sdk_input = SignInput::builder()
+            .key_id(KEY_ARN)
+            .message(Blob::new(vec![1u8; 32]))
+            .signing_algorithm(SigningAlgorithmSpec::EcdsaSha256)
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input("KMS", "Sign", &context::Input::erase(sdk_input), &mut span);
+        assert_eq!(
+            span.get(AWS_KMS_SIGNING_ALGORITHM),
+            Some(&Value::from("ECDSA_SHA_256"))
+        );
+        assert_eq!(span.get(AWS_KMS_MESSAGE_SIZE), Some(&Value::I64(32)));
+    }
+}
@@ -0,0 +1,417 @@
+//! Lambda client attribute extraction following the OTel FaaS semantic
+//! conventions.
+//!
+//! This module provides [`LambdaClientExtractor`], which implements
+//! [`super::super::AttributeExtractor`] for the Lambda `Invoke` and
+//! `InvokeWithResponseStream` SDK calls. It is automatically used by
+//! [`super::super::DefaultExtractor`] when the `extract-lambda-client` feature
+//! is enabled.
+//!
+//! ## Extracted attributes
+//!
+//! **Set from input:**
+//! - `faas.invoked_name` — the function name, taken from any of the forms
+//!   accepted by `FunctionName` (name, `name:qualifier`, partial or full ARN)
+//! - `faas.invoked_provider` — always `"aws"`
+//! - `faas.invoked_region` — the region of a function given as a full ARN
+//! - `aws.lambda.invocation_type` — `"RequestResponse"` (the default),
+//!   `"Event"` or `"DryRun"`
+//! - `aws.lambda.qualifier` — the version or alias, from `Qualifier` or from
+//!   the suffix of `FunctionName`
+//!
+//! **Set from the request:**
+//! - `faas.invoked_region` — the region of the Lambda endpoint called, when the
+//!   endpoint host is a standard AWS one (`lambda.<region>.amazonaws.com`,
+//!   `lambda.<region>.api.aws`). It takes precedence over the ARN region.
+//!
+//! **Set from output:**
+//! - `aws.lambda.executed_version` — the version of the function that ran
+//! - `aws.lambda.function_error` — for `Invoke`, the `FunctionError` reported
+//!   when the function itself failed (e.g. `"Unhandled"`)
+//!
+//! Lambda reports a function error with a successful HTTP response, so the SDK
+//! call succeeds. The extractor sets the span status to error when
+//! `FunctionError` is present, so failed invocations are not hidden.
+//!
+//! Payloads and client context are never recorded.
+
+// Lambda client attribute extraction — downcasts Input/Output to concrete
+// aws-sdk-lambda types and extracts invoked function attributes.
+
+use aws_sdk_lambda::operation::{
+    invoke::{InvokeInput, InvokeOutput},
+    invoke_with_response_stream::{InvokeWithResponseStreamInput, InvokeWithResponseStreamOutput},
+};
+use aws_smithy_runtime_api::client::interceptors::context;
+use aws_smithy_runtime_api::http;
+use opentelemetry_semantic_conventions::attribute as semco;
+
+use super::super::{AttributeExtractor, SpanWrite};
+use super::set_str;
+use crate::span_write::Status;
+
+/// Attribute key for the invocation type.
+const AWS_LAMBDA_INVOCATION_TYPE: &str = "aws.lambda.invocation_type";
+/// Attribute key for the version or alias invoked.
+const AWS_LAMBDA_QUALIFIER: &str = "aws.lambda.qualifier";
+/// Attribute key for the version of the function that ran.
+const AWS_LAMBDA_EXECUTED_VERSION: &str = "aws.lambda.executed_version";
+/// Attribute key for the `FunctionError` of a failed invocation.
+const AWS_LAMBDA_FUNCTION_ERROR: &str = "aws.lambda.function_error";
+
+/// Attribute extractor for Lambda client SDK calls.
+///
+/// `LambdaClientExtractor` implements [`AttributeExtractor`] and is
+/// automatically used by [`DefaultExtractor`] when the `extract-lambda-client`
+/// feature is enabled. You only need to construct it directly if you are
+/// composing a custom extraction pipeline.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes.
+///
+/// [`DefaultExtractor`]: crate::interceptor::DefaultExtractor
+#[derive(Debug, Default)]
+pub struct LambdaClientExtractor {
+    _private: (),
+}
+
+impl LambdaClientExtractor {
+    /// Creates a new `LambdaClientExtractor`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::extract::lambda_client::LambdaClientExtractor;
+    ///
+    /// let extractor = LambdaClientExtractor::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+/// Extracts FaaS client OTel attributes from Lambda SDK inputs, requests and
+/// outputs.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes and which operations they apply to.
+impl<SW: SpanWrite> AttributeExtractor<SW> for LambdaClientExtractor {
+    fn extract_input(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        input: &context::Input,
+        span: &mut SW,
+    ) {
+        match operation {
+            "Invoke" => {
+                let i = input.downcast_ref::<InvokeInput>().expect("correct type");
+                set_invoked_function(span, i.function_name(), i.qualifier());
+                set_invocation_type(span, i.invocation_type().map(|t| t.as_str()));
+            }
+            "InvokeWithResponseStream" => {
+                let i = input
+                    .downcast_ref::<InvokeWithResponseStreamInput>()
+                    .expect("correct type");
+                set_invoked_function(span, i.function_name(), i.qualifier());
+                set_invocation_type(span, i.invocation_type().map(|t| t.as_str()));
+            }
+            _ => {}
+        }
+    }
+
+    fn extract_request(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        request: &http::Request,
+        span: &mut SW,
+    ) {
+        if matches!(operation, "Invoke" | "InvokeWithResponseStream") {
+            if let Some(region) = endpoint_region(request.uri()) {
+                span.set_attribute(semco::FAAS_INVOKED_REGION, region.to_owned());
+            }
+        }
+    }
+
+    fn extract_output(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        output: &context::Output,
+        span: &mut SW,
+    ) {
+        match operation {
+            "Invoke" => {
+                let o = output.downcast_ref::<InvokeOutput>().expect("correct type");
+                set_str(span, AWS_LAMBDA_EXECUTED_VERSION, o.executed_version());
+                if let Some(function_error) = o.function_error().filter(|s| !s.is_empty()) {
+                    span.set_attribute(AWS_LAMBDA_FUNCTION_ERROR, function_error.to_owned());
+                    span.set_status(Status::error(format!("function error: {function_error}")));
+                }
+            }
+            "InvokeWithResponseStream" => set_str(
+                span,
+                AWS_LAMBDA_EXECUTED_VERSION,
+                output
+                    .downcast_ref::<InvokeWithResponseStreamOutput>()
+                    .expect("correct type")
+                    .executed_version(),
+            ),
+            _ => {}
+        }
+    }
+}
+
+/// Sets the invoked function name, provider, ARN region and qualifier.
+///
+/// An explicit `Qualifier` takes precedence over one embedded in the function
+/// name; Lambda rejects the call when both are given and differ.
+fn set_invoked_function(
+    span: &mut impl SpanWrite,
+    function_name: Option<&str>,
+    qualifier: Option<&str>,
+) {
+    let Some(function) = function_name.and_then(InvokedFunction::parse) else {
+        return;
+    };
+    span.set_attribute(semco::FAAS_INVOKED_NAME, function.name.to_owned());
+    span.set_attribute(semco::FAAS_INVOKED_PROVIDER, "aws");
+    set_str(span, semco::FAAS_INVOKED_REGION, function.region);
+    set_str(
+        span,
+        AWS_LAMBDA_QUALIFIER,
+        qualifier.filter(|s| !s.is_empty()).or(function.qualifier),
+    );
+}
+
+/// Sets the invocation type, defaulting to `"RequestResponse"` like Lambda.
+fn set_invocation_type(span: &mut impl SpanWrite, invocation_type: Option<&str>) {
+    span.set_attribute(
+        AWS_LAMBDA_INVOCATION_TYPE,
+        invocation_type.unwrap_or("RequestResponse").to_owned(),
+    );
+}
+
+/// The parts of a Lambda `FunctionName`.
+#[derive(Debug, PartialEq)]
+struct InvokedFunction<'a> {
+    name: &'a str,
+    region: Option<&'a str>,
+    qualifier: Option<&'a str>,
+}
+
+impl<'a> InvokedFunction<'a> {
+    /// Parses a function name, `name:qualifier`, partial ARN
+    /// (`account:function:name[:qualifier]`) or full ARN
+    /// (`arn:partition:lambda:region:account:function:name[:qualifier]`).
+    fn parse(function_name: &'a str) -> Option<Self> {
+        let parts: Vec<&str> = function_name.split(':').collect();
+        let (name, region, qualifier) = match parts.as_slice() {
+            [name] => (*name, None, None),
+            [name, qualifier] => (*name, None, Some(*qualifier)),
+            [_, "function", name] => (*name, None, None),
+            [_, "function", name, qualifier] => (*name, None, Some(*qualifier)),
+            ["arn", _, "lambda", region, _, "function", name] => (*name, Some(*region), None),
+            ["arn", _, "lambda", region, _, "function", name, qualifier] => {
+                (*name, Some(*region), Some(*qualifier))
+            }
+            _ => return None,
+        };
+        (!name.is_empty()).then_some(Self {
+            name,
+            region: region.filter(|s| !s.is_empty()),
+            qualifier: qualifier.filter(|s| !s.is_empty()),
+        })
+    }
+}
+
+/// Returns the region of a standard Lambda endpoint URI, e.g. `us-east-1` for
+/// `https://lambda.us-east-1.amazonaws.com/...`.
+fn endpoint_region(uri: &str) -> Option<&str> {
+    let authority = uri.split_once("://").map_or(uri, |(_, rest)| rest);
+    let host = authority
+        .split(['/', '?'])
+        .next()
+        .and_then(|authority| authority.split(':').next())?;
+    let rest = host.strip_prefix("lambda.")?;
+    let (region, domain) = rest.split_once('.')?;
+    let standard = domain == "api.aws"
+        || domain == "amazonaws.com"
+        || domain.starts_with("amazonaws.com.")
+        || domain.ends_with(".api.aws");
+    (standard && !region.is_empty()).then_some(region)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_lambda::types::InvocationType;
+    use aws_smithy_types::body::SdkBody;
+    use opentelemetry::Value;
+
+    use crate::span_write::{SpanContext, SpanWrite, Status};
+
+    struct TestSpan {
+        attributes: Vec<(&'static str, Value)>,
+        status: Option<Status>,
+    }
+
+    impl TestSpan {
+        fn new() -> Self {
+            Self {
+                attributes: vec![],
+                status: None,
+            }
+        }
+
+        fn get(&self, key: &str) -> Option<&Value> {
+            self.attributes
+                .iter()
+                .rev()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v)
+        }
+    }
+
+    impl SpanWrite for TestSpan {
+        fn set_attribute(&mut self, key: &'static str, value: impl Into<Value>) {
+            self.attributes.push((key, value.into()));
+        }
+
+        fn set_status(&mut self, code: Status) {
+            self.status = Some(code);
+        }
+
+        fn span_context(&self) -> SpanContext {
+            SpanContext::empty_context()
+        }
+    }
+
+    // Tests for function name and endpoint parsing — 2 consolidated tests
+
+    #[test]
+    fn parse_function_name_forms() {
+        let function = |name, region, qualifier| {
+            Some(InvokedFunction {
+                name,
+                region,
+                qualifier,
+            })
+        };
+        assert_eq!(
+            InvokedFunction::parse("orders"),
+            function("orders", None, None)
+        );
+        assert_eq!(
+            InvokedFunction::parse("orders:live"),
+            function("orders", None, Some("live"))
+        );
+        assert_eq!(
+            InvokedFunction::parse("123456789012:function:orders"),
+            function("orders", None, None)
+        );
+        assert_eq!(
+            InvokedFunction::parse("arn:aws:lambda:eu-west-1:123456789012:function:orders:3"),
+            function("orders", Some("eu-west-1"), Some("3"))
+        );
+        assert_eq!(InvokedFunction::parse(""), None);
+        assert_eq!(InvokedFunction::parse("a:b:c:d:e"), None);
+    }
+
+    #[test]
+    fn endpoint_region_from_uri() {
+        assert_eq!(
+            endpoint_region("https://lambda.us-east-1.amazonaws.com/2015-03-31/functions"),
+            Some("us-east-1")
+        );
+        assert_eq!(
+            endpoint_region("https://lambda.cn-north-1.amazonaws.com.cn/"),
+            Some("cn-north-1")
+        );
+        assert_eq!(
+            endpoint_region("https://lambda.eu-west-3.api.aws"),
+            Some("eu-west-3")
+        );
+        assert_eq!(endpoint_region("http://localhost:4566/2015-03-31"), None);
+    }
+
+    // Tests for LambdaClientExtractor — 1 consolidated test
+
+    #[test]
+    fn extract_invoke_with_function_error() {
+        let extractor = LambdaClientExtractor::new();
+
+        let sdk_input = InvokeInput::builder()
+            .function_name("arn:aws:lambda:eu-west-1:123456789012:function:orders:live")
+            .invocation_type(InvocationType::Event)
+            .payload(aws_smithy_types::Blob::new("{\"card\":\"4111\"}"))
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "Lambda",
+            "Invoke",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(semco::FAAS_INVOKED_NAME),
+            Some(&Value::from("orders"))
+        );
+        assert_eq!(
+            span.get(semco::FAAS_INVOKED_PROVIDER),
+            Some(&Value::from("aws"))
+        );
+        assert_eq!(
+            span.get(semco::FAAS_INVOKED_REGION),
+            Some(&Value::from("eu-west-1"))
+        );
+        assert_eq!(
+            span.get(AWS_LAMBDA_INVOCATION_TYPE),
+            Some(&Value::from("Event"))
+        );
+        assert_eq!(span.get(AWS_LAMBDA_QUALIFIER), Some(&Value::from("live")));
+
+        let mut request = http::Request::new(SdkBody::empty());
+        request
+            .set_uri(
+                "https://lambda.eu-west-1.amazonaws.com/2015-03-31/functions/orders/invocations",
+            )
+            .unwrap();
+        extractor.extract_request("Lambda", "Invoke", &request, &mut span);
+        assert_eq!(
+            span.get(semco::FAAS_INVOKED_REGION),
+            Some(&Value::from("eu-west-1"))
+        );
+
+        let sdk_output = InvokeOutput::builder()
+            .status_code(200)
+            .executed_version("3")
+            .function_error("Unhandled")
+            .build();
+        extractor.extract_output(
+            "Lambda",
+            "Invoke",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_LAMBDA_EXECUTED_VERSION),
+            Some(&Value::from("3"))
+        );
+        assert_eq!(
+            span.get(AWS_LAMBDA_FUNCTION_ERROR),
+            Some(&Value::from("Unhandled"))
+        );
+        assert_eq!(
+            span.status,
+            Some(Status::error("function error: Unhandled"))
+        );
+        assert!(
+            span.attributes
+                .iter()
+                .all(|(_, value)| !value.as_str().contains("4111"))
+        );
+    }
+}
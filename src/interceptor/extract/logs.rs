@@ -0,0 +1,505 @@
+//! CloudWatch Logs attribute extraction following the OTel AWS semantic
+//! conventions.
+//!
+//! This module provides [`CloudWatchLogsExtractor`], which implements
+//! [`super::super::AttributeExtractor`] for the CloudWatch Logs
+//! `PutLogEvents`, `StartQuery` and `GetQueryResults` SDK calls. It is
+//! automatically used by [`super::super::DefaultExtractor`] when the
+//! `extract-logs` feature is enabled.
+//!
+//! Logs Insights queries run asynchronously: the query ID returned by
+//! `StartQuery` is recorded on the start span and on every `GetQueryResults`
+//! span polling that query, so they can be tied together.
+//!
+//! ## Extracted attributes
+//!
+//! **Set from input:**
+//! - `aws.log.group.names` — for `PutLogEvents`, the log group; for
+//!   `StartQuery`, the log groups queried by name
+//! - `aws.log.group.arns` — for `StartQuery`, the log groups queried by ARN
+//! - `aws.log.stream.names` — for `PutLogEvents`, the log stream
+//! - `aws.logs.event_count` — for `PutLogEvents`, the number of log events
+//! - `aws.logs.batch_bytes` — for `PutLogEvents`, the batch size as counted
+//!   against the service quota: the UTF-8 size of the messages plus 26 bytes
+//!   per event
+//! - `aws.logs.query_language` — for `StartQuery`, the query language, e.g.
+//!   `"CWLI"`
+//! - `aws.logs.query_id` — for `GetQueryResults`, the query polled
+//!
+//! **Set from output:**
+//! - for `PutLogEvents`, when events were rejected:
+//!   - `aws.logs.rejected.too_new_start_index` — the index of the first event
+//!     rejected for being too far in the future
+//!   - `aws.logs.rejected.too_old_end_index` — the index of the last event
+//!     rejected for being too old
+//!   - `aws.logs.rejected.expired_end_index` — the index of the last event
+//!     rejected for being older than the retention period
+//!   - `aws.logs.rejected_entity.error_type` — the reason the entity of the
+//!     request was rejected
+//! - `aws.logs.query_id` — for `StartQuery`, the query ID
+//! - for `GetQueryResults`:
+//!   - `aws.logs.query.status` — the query status, e.g. `"Complete"`
+//!   - `aws.logs.query.records_matched` — the number of log events matched
+//!   - `aws.logs.query.records_scanned` — the number of log events scanned
+//!   - `aws.logs.query.bytes_scanned` — the bytes of log events scanned
+//!
+//! `PutLogEvents` reports rejected events and a rejected entity inside a
+//! successful response. When either was rejected, the span status is set to
+//! error.
+//!
+//! Log messages, query strings and query results are never recorded.
+
+// CloudWatch Logs attribute extraction — downcasts Input/Output to concrete
+// aws-sdk-cloudwatchlogs types and extracts log group, batch and Insights
+// query attributes.
+
+use aws_sdk_cloudwatchlogs::operation::{
+    get_query_results::{GetQueryResultsInput, GetQueryResultsOutput},
+    put_log_events::{PutLogEventsInput, PutLogEventsOutput},
+    start_query::{StartQueryInput, StartQueryOutput},
+};
+use aws_smithy_runtime_api::client::interceptors::context;
+use opentelemetry_semantic_conventions::attribute as semco;
+
+use super::super::{AttributeExtractor, SpanWrite};
+use super::{set_str, set_strings};
+use crate::span_write::Status;
+
+/// Attribute key for the number of log events in a `PutLogEvents` batch.
+const AWS_LOGS_EVENT_COUNT: &str = "aws.logs.event_count";
+/// Attribute key for the size of a `PutLogEvents` batch.
+const AWS_LOGS_BATCH_BYTES: &str = "aws.logs.batch_bytes";
+/// Attribute key for the index of the first event rejected as too new.
+const AWS_LOGS_REJECTED_TOO_NEW_START_INDEX: &str = "aws.logs.rejected.too_new_start_index";
+/// Attribute key for the index of the last event rejected as too old.
+const AWS_LOGS_REJECTED_TOO_OLD_END_INDEX: &str = "aws.logs.rejected.too_old_end_index";
+/// Attribute key for the index of the last event rejected as expired.
+const AWS_LOGS_REJECTED_EXPIRED_END_INDEX: &str = "aws.logs.rejected.expired_end_index";
+/// Attribute key for the reason the request entity was rejected.
+const AWS_LOGS_REJECTED_ENTITY_ERROR_TYPE: &str = "aws.logs.rejected_entity.error_type";
+/// Attribute key for the Logs Insights query language.
+const AWS_LOGS_QUERY_LANGUAGE: &str = "aws.logs.query_language";
+/// Attribute key for the Logs Insights query ID.
+const AWS_LOGS_QUERY_ID: &str = "aws.logs.query_id";
+/// Attribute key for the Logs Insights query status.
+const AWS_LOGS_QUERY_STATUS: &str = "aws.logs.query.status";
+/// Attribute key for the number of log events matched by the query.
+const AWS_LOGS_QUERY_RECORDS_MATCHED: &str = "aws.logs.query.records_matched";
+/// Attribute key for the number of log events scanned by the query.
+const AWS_LOGS_QUERY_RECORDS_SCANNED: &str = "aws.logs.query.records_scanned";
+/// Attribute key for the bytes of log events scanned by the query.
+const AWS_LOGS_QUERY_BYTES_SCANNED: &str = "aws.logs.query.bytes_scanned";
+
+/// Per-event overhead CloudWatch Logs adds when sizing a `PutLogEvents` batch.
+const LOG_EVENT_OVERHEAD_BYTES: usize = 26;
+
+/// Attribute extractor for CloudWatch Logs SDK calls.
+///
+/// `CloudWatchLogsExtractor` implements [`AttributeExtractor`] and is
+/// automatically used by [`DefaultExtractor`] when the `extract-logs` feature
+/// is enabled. You only need to construct it directly if you are composing a
+/// custom extraction pipeline.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes.
+///
+/// [`DefaultExtractor`]: crate::interceptor::DefaultExtractor
+#[derive(Debug, Default)]
+pub struct CloudWatchLogsExtractor {
+    _private: (),
+}
+
+impl CloudWatchLogsExtractor {
+    /// Creates a new `CloudWatchLogsExtractor`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::extract::logs::CloudWatchLogsExtractor;
+    ///
+    /// let extractor = CloudWatchLogsExtractor::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+/// Extracts CloudWatch Logs attributes from SDK inputs and outputs.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes and which operations they apply to.
+impl<SW: SpanWrite> AttributeExtractor<SW> for CloudWatchLogsExtractor {
+    fn extract_input(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        input: &context::Input,
+        span: &mut SW,
+    ) {
+        match operation {
+            "PutLogEvents" => {
+                let i = input
+                    .downcast_ref::<PutLogEventsInput>()
+                    .expect("correct type");
+                set_strings(span, semco::AWS_LOG_GROUP_NAMES, i.log_group_name());
+                set_strings(span, semco::AWS_LOG_STREAM_NAMES, i.log_stream_name());
+                let events = i.log_events();
+                span.set_attribute(AWS_LOGS_EVENT_COUNT, events.len() as i64);
+                let batch_bytes: usize = events
+                    .iter()
+                    .map(|event| event.message().len() + LOG_EVENT_OVERHEAD_BYTES)
+                    .sum();
+                span.set_attribute(AWS_LOGS_BATCH_BYTES, batch_bytes as i64);
+            }
+            "StartQuery" => {
+                let i = input
+                    .downcast_ref::<StartQueryInput>()
+                    .expect("correct type");
+                // Log group identifiers may be either names or ARNs.
+                let (arns, names): (Vec<&str>, Vec<&str>) = i
+                    .log_group_identifiers()
+                    .iter()
+                    .map(String::as_str)
+                    .partition(|identifier| identifier.starts_with("arn:"));
+                set_strings(
+                    span,
+                    semco::AWS_LOG_GROUP_NAMES,
+                    i.log_group_name()
+                        .into_iter()
+                        .chain(i.log_group_names().iter().map(String::as_str))
+                        .chain(names),
+                );
+                set_strings(span, semco::AWS_LOG_GROUP_ARNS, arns);
+                if let Some(language) = i.query_language() {
+                    span.set_attribute(AWS_LOGS_QUERY_LANGUAGE, language.as_str().to_owned());
+                }
+            }
+            "GetQueryResults" => {
+                let i = input
+                    .downcast_ref::<GetQueryResultsInput>()
+                    .expect("correct type");
+                set_str(span, AWS_LOGS_QUERY_ID, i.query_id());
+            }
+            _ => {}
+        }
+    }
+
+    fn extract_output(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        output: &context::Output,
+        span: &mut SW,
+    ) {
+        match operation {
+            "PutLogEvents" => {
+                let o = output
+                    .downcast_ref::<PutLogEventsOutput>()
+                    .expect("correct type");
+                if let Some(rejected) = o.rejected_log_events_info() {
+                    for (key, index) in [
+                        (
+                            AWS_LOGS_REJECTED_TOO_NEW_START_INDEX,
+                            rejected.too_new_log_event_start_index(),
+                        ),
+                        (
+                            AWS_LOGS_REJECTED_TOO_OLD_END_INDEX,
+                            rejected.too_old_log_event_end_index(),
+                        ),
+                        (
+                            AWS_LOGS_REJECTED_EXPIRED_END_INDEX,
+                            rejected.expired_log_event_end_index(),
+                        ),
+                    ] {
+                        if let Some(index) = index {
+                            span.set_attribute(key, i64::from(index));
+                        }
+                    }
+                    span.set_status(Status::error("log events rejected"));
+                }
+                if let Some(rejected) = o.rejected_entity_info() {
+                    let error_type = rejected.error_type().as_str();
+                    span.set_attribute(AWS_LOGS_REJECTED_ENTITY_ERROR_TYPE, error_type.to_owned());
+                    span.set_status(Status::error(format!("entity rejected: {error_type}")));
+                }
+            }
+            "StartQuery" => {
+                let o = output
+                    .downcast_ref::<StartQueryOutput>()
+                    .expect("correct type");
+                set_str(span, AWS_LOGS_QUERY_ID, o.query_id());
+            }
+            "GetQueryResults" => {
+                let o = output
+                    .downcast_ref::<GetQueryResultsOutput>()
+                    .expect("correct type");
+                if let Some(status) = o.status() {
+                    span.set_attribute(AWS_LOGS_QUERY_STATUS, status.as_str().to_owned());
+                }
+                if let Some(statistics) = o.statistics() {
+                    // The service reports these counts as doubles.
+                    span.set_attribute(
+                        AWS_LOGS_QUERY_RECORDS_MATCHED,
+                        statistics.records_matched() as i64,
+                    );
+                    span.set_attribute(
+                        AWS_LOGS_QUERY_RECORDS_SCANNED,
+                        statistics.records_scanned() as i64,
+                    );
+                    span.set_attribute(
+                        AWS_LOGS_QUERY_BYTES_SCANNED,
+                        statistics.bytes_scanned() as i64,
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_cloudwatchlogs::types::{
+        EntityRejectionErrorType, InputLogEvent, QueryLanguage, QueryStatistics, QueryStatus,
+        RejectedEntityInfo, RejectedLogEventsInfo,
+    };
+    use opentelemetry::{Array, StringValue, Value};
+
+    use crate::span_write::{SpanContext, SpanWrite, Status};
+
+    struct TestSpan {
+        attributes: Vec<(&'static str, Value)>,
+        status: Option<Status>,
+    }
+
+    impl TestSpan {
+        fn new() -> Self {
+            Self {
+                attributes: vec![],
+                status: None,
+            }
+        }
+
+        fn get(&self, key: &str) -> Option<&Value> {
+            self.attributes
+                .iter()
+                .rev()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v)
+        }
+    }
+
+    impl SpanWrite for TestSpan {
+        fn set_attribute(&mut self, key: &'static str, value: impl Into<Value>) {
+            self.attributes.push((key, value.into()));
+        }
+
+        fn set_status(&mut self, code: Status) {
+            self.status = Some(code);
+        }
+
+        fn span_context(&self) -> SpanContext {
+            SpanContext::empty_context()
+        }
+    }
+
+    fn strings(values: &[&'static str]) -> Value {
+        Value::Array(Array::String(
+            values.iter().map(|&s| StringValue::from(s)).collect(),
+        ))
+    }
+
+    // Tests for CloudWatchLogsExtractor — 2 consolidated tests
+
+    #[test]
+    fn extract_put_log_events() {
+        let extractor = CloudWatchLogsExtractor::new();
+        let event = |message: &str| {
+            InputLogEvent::builder()
+                .timestamp(1_700_000_000_000)
+                .message(message)
+                .build()
+                .unwrap()
+        };
+        let sdk_input = PutLogEventsInput::builder()
+            .log_group_name("/iot/ingest")
+            .log_stream_name("worker-1")
+            .log_events(event("started"))
+            .log_events(event("user=alice stored 42 records"))
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "CloudWatch Logs",
+            "PutLogEvents",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(semco::AWS_LOG_GROUP_NAMES),
+            Some(&strings(&["/iot/ingest"]))
+        );
+        assert_eq!(
+            span.get(semco::AWS_LOG_STREAM_NAMES),
+            Some(&strings(&["worker-1"]))
+        );
+        assert_eq!(span.get(AWS_LOGS_EVENT_COUNT), Some(&Value::I64(2)));
+        assert_eq!(
+            span.get(AWS_LOGS_BATCH_BYTES),
+            Some(&Value::I64(7 + 28 + 2 * 26))
+        );
+        assert!(
+            span.attributes
+                .iter()
+                .all(|(_, value)| !value.to_string().contains("alice"))
+        );
+
+        let sdk_output = PutLogEventsOutput::builder()
+            .rejected_log_events_info(
+                RejectedLogEventsInfo::builder()
+                    .too_old_log_event_end_index(0)
+                    .build(),
+            )
+            .build();
+        extractor.extract_output(
+            "CloudWatch Logs",
+            "PutLogEvents",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_LOGS_REJECTED_TOO_OLD_END_INDEX),
+            Some(&Value::I64(0))
+        );
+        assert_eq!(span.get(AWS_LOGS_REJECTED_TOO_NEW_START_INDEX), None);
+        assert_eq!(span.status, Some(Status::error("log events rejected")));
+
+        let sdk_output = PutLogEventsOutput::builder()
+            .rejected_entity_info(
+                RejectedEntityInfo::builder()
+                    .error_type(EntityRejectionErrorType::InvalidEntity)
+                    .build()
+                    .unwrap(),
+            )
+            .build();
+        let mut span = TestSpan::new();
+        extractor.extract_output(
+            "CloudWatch Logs",
+            "PutLogEvents",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_LOGS_REJECTED_ENTITY_ERROR_TYPE),
+            Some(&Value::from("InvalidEntity"))
+        );
+        assert_eq!(
+            span.status,
+            Some(Status::error("entity rejected: InvalidEntity"))
+        );
+
+        let mut span = TestSpan::new();
+        extractor.extract_output(
+            "CloudWatch Logs",
+            "PutLogEvents",
+            &context::Output::erase(PutLogEventsOutput::builder().build()),
+            &mut span,
+        );
+        assert!(span.status.is_none());
+    }
+
+    #[test]
+    fn extract_insights_query() {
+        let extractor = CloudWatchLogsExtractor::new();
+        let sdk_input = StartQueryInput::builder()
+            .query_language(QueryLanguage::Cwli)
+            .log_group_identifiers("/iot/ingest")
+            .log_group_identifiers("arn:aws:logs:us-east-1:123456789012:log-group:/iot/query")
+            .start_time(1_700_000_000)
+            .end_time(1_700_003_600)
+            .query_string("fields @message | filter user = 'alice'")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "CloudWatch Logs",
+            "StartQuery",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        extractor.extract_output(
+            "CloudWatch Logs",
+            "StartQuery",
+            &context::Output::erase(StartQueryOutput::builder().query_id("q-1").build()),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(semco::AWS_LOG_GROUP_NAMES),
+            Some(&strings(&["/iot/ingest"]))
+        );
+        assert_eq!(
+            span.get(semco::AWS_LOG_GROUP_ARNS),
+            Some(&strings(&[
+                "arn:aws:logs:us-east-1:123456789012:log-group:/iot/query"
+            ]))
+        );
+        assert_eq!(
+            span.get(AWS_LOGS_QUERY_LANGUAGE),
+            Some(&Value::from("CWLI"))
+        );
+        assert_eq!(span.get(AWS_LOGS_QUERY_ID), Some(&Value::from("q-1")));
+        assert!(
+            span.attributes
+                .iter()
+                .all(|(_, value)| !value.to_string().contains("alice"))
+        );
+
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "CloudWatch Logs",
+            "GetQueryResults",
+            &context::Input::erase(
+                GetQueryResultsInput::builder()
+                    .query_id("q-1")
+                    .build()
+                    .unwrap(),
+            ),
+            &mut span,
+        );
+        extractor.extract_output(
+            "CloudWatch Logs",
+            "GetQueryResults",
+            &context::Output::erase(
+                GetQueryResultsOutput::builder()
+                    .status(QueryStatus::Complete)
+                    .statistics(
+                        QueryStatistics::builder()
+                            .records_matched(12.0)
+                            .records_scanned(3400.0)
+                            .bytes_scanned(512_000.0)
+                            .build(),
+                    )
+                    .build(),
+            ),
+            &mut span,
+        );
+        assert_eq!(span.get(AWS_LOGS_QUERY_ID), Some(&Value::from("q-1")));
+        assert_eq!(
+            span.get(AWS_LOGS_QUERY_STATUS),
+            Some(&Value::from("Complete"))
+        );
+        assert_eq!(
+            span.get(AWS_LOGS_QUERY_RECORDS_MATCHED),
+            Some(&Value::I64(12))
+        );
+        assert_eq!(
+            span.get(AWS_LOGS_QUERY_RECORDS_SCANNED),
+            Some(&Value::I64(3400))
+        );
+        assert_eq!(
+            span.get(AWS_LOGS_QUERY_BYTES_SCANNED),
+            Some(&Value::I64(512_000))
+        );
+    }
+}
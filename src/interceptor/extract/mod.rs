@@ -6,20 +6,178 @@
 //! you do not need to reference them directly unless you want to instantiate an
 //! extractor on its own.
 //!
-//! | Sub-module                  | Feature            | Service   |
-//! |-----------------------------|--------------------|-----------|
-//! | [`dynamodb`]                | `extract-dynamodb` | DynamoDB  |
-//! | [`s3`]                      | `extract-s3`       | S3        |
-//! | [`sqs`]                     | `extract-sqs`      | SQS       |
+//! | Sub-module            | Feature                     | Service               |
+//! |-----------------------|-----------------------------|-----------------------|
+//! | [`athena`]            | `extract-athena`            | Athena                |
+//! | [`bedrock`]           | `extract-bedrock`           | Bedrock Runtime       |
+//! | [`bedrock_agent`]     | `extract-bedrock-agent`     | Bedrock Agent Runtime |
+//! | [`cloudwatch`]        | `extract-cloudwatch`        | CloudWatch            |
+//! | [`dynamodb`]          | `extract-dynamodb`          | DynamoDB              |
+//! | [`eventbridge`]       | `extract-eventbridge`       | EventBridge           |
+//! | [`firehose`]          | `extract-firehose`          | Firehose              |
+//! | [`kinesis`]           | `extract-kinesis`           | Kinesis               |
+//! | [`kms`]               | `extract-kms`               | KMS                   |
+//! | [`lambda_client`]     | `extract-lambda-client`     | Lambda (client)       |
+//! | [`logs`]              | `extract-logs`              | CloudWatch Logs       |
+//! | [`neptunedata`]       | `extract-neptunedata`       | Neptune Data API      |
+//! | [`rdsdata`]           | `extract-rdsdata`           | RDS Data API          |
+//! | [`redshift_data`]     | `extract-redshift-data`     | Redshift Data API     |
+//! | [`s3`]                | `extract-s3`                | S3                    |
+//! | [`sagemaker_runtime`] | `extract-sagemaker-runtime` | SageMaker Runtime     |
+//! | [`secretsmanager`]    | `extract-secretsmanager`    | Secrets Manager       |
+//! | [`sesv2`]             | `extract-sesv2`             | SES (v2)              |
+//! | [`sfn`]               | `extract-sfn`               | Step Functions        |
+//! | [`sns`]               | `extract-sns`               | SNS                   |
+//! | [`sqs`]               | `extract-sqs`               | SQS                   |
+//! | [`ssm`]               | `extract-ssm`               | SSM Parameter Store   |
+//! | [`sts`]               | `extract-sts`               | STS                   |
+//! | [`timestream_query`]  | `extract-timestream-query`  | Timestream Query      |
+//! | [`timestream_write`]  | `extract-timestream-write`  | Timestream Write      |
 
 // Extraction dispatch — Metadata extraction (always available) and
 // feature-gated service-specific modules.
 
+#[cfg(feature = "extract-athena")]
+pub mod athena;
+
+#[cfg(feature = "extract-bedrock")]
+pub mod bedrock;
+
+#[cfg(feature = "extract-bedrock-agent")]
+pub mod bedrock_agent;
+
+#[cfg(feature = "extract-cloudwatch")]
+pub mod cloudwatch;
+
 #[cfg(feature = "extract-dynamodb")]
 pub mod dynamodb;
 
+#[cfg(feature = "extract-eventbridge")]
+pub mod eventbridge;
+
+#[cfg(feature = "extract-firehose")]
+pub mod firehose;
+
+#[cfg(feature = "extract-kinesis")]
+pub mod kinesis;
+
+#[cfg(feature = "extract-kms")]
+pub mod kms;
+
+#[cfg(feature = "extract-lambda-client")]
+pub mod lambda_client;
+
+#[cfg(feature = "extract-logs")]
+pub mod logs;
+
+#[cfg(feature = "extract-neptunedata")]
+pub mod neptunedata;
+
+#[cfg(feature = "extract-rdsdata")]
+pub mod rdsdata;
+
+#[cfg(feature = "extract-redshift-data")]
+pub mod redshift_data;
+
 #[cfg(feature = "extract-s3")]
 pub mod s3;
 
+#[cfg(feature = "extract-sagemaker-runtime")]
+pub mod sagemaker_runtime;
+
+#[cfg(feature = "extract-secretsmanager")]
+pub mod secretsmanager;
+
+#[cfg(feature = "extract-sesv2")]
+pub mod sesv2;
+
+#[cfg(feature = "extract-sfn")]
+pub mod sfn;
+
+#[cfg(feature = "extract-sns")]
+pub mod sns;
+
 #[cfg(feature = "extract-sqs")]
 pub mod sqs;
+
+#[cfg(feature = "extract-ssm")]
+pub mod ssm;
+
+#[cfg(feature = "extract-sts")]
+pub mod sts;
+
+#[cfg(feature = "extract-timestream-query")]
+pub mod timestream_query;
+
+#[cfg(feature = "extract-timestream-write")]
+pub mod timestream_write;
+
+#[cfg(any(feature = "extract-sqs", feature = "extract-sns"))]
+mod propagation;
+
+#[cfg(any(
+    feature = "extract-athena",
+    feature = "extract-dynamodb",
+    feature = "extract-neptunedata",
+    feature = "extract-rdsdata",
+    feature = "extract-redshift-data",
+    feature = "extract-timestream-query"
+))]
+mod sanitize;
+
+#[cfg(any(
+    feature = "extract-bedrock-agent",
+    feature = "extract-kinesis",
+    feature = "extract-sfn"
+))]
+mod hash;
+
+/// Sets `key` to a non-empty string value.
+#[cfg(any(
+    feature = "extract-athena",
+    feature = "extract-bedrock-agent",
+    feature = "extract-kms",
+    feature = "extract-lambda-client",
+    feature = "extract-logs",
+    feature = "extract-neptunedata",
+    feature = "extract-rdsdata",
+    feature = "extract-redshift-data",
+    feature = "extract-s3",
+    feature = "extract-sagemaker-runtime",
+    feature = "extract-secretsmanager",
+    feature = "extract-sesv2",
+    feature = "extract-sfn",
+    feature = "extract-sts",
+    feature = "extract-timestream-write"
+))]
+pub(super) fn set_str(span: &mut impl super::SpanWrite, key: &'static str, value: Option<&str>) {
+    if let Some(value) = value.filter(|s| !s.is_empty()) {
+        span.set_attribute(key, value.to_owned());
+    }
+}
+
+/// Sets `key` to a string array of the non-empty `values`, unless there are
+/// none.
+#[cfg(any(
+    feature = "extract-bedrock",
+    feature = "extract-logs",
+    feature = "extract-secretsmanager",
+    feature = "extract-ssm"
+))]
+pub(super) fn set_strings(
+    span: &mut impl super::SpanWrite,
+    key: &'static str,
+    values: impl IntoIterator<Item = impl AsRef<str>>,
+) {
+    let values: Vec<opentelemetry::StringValue> = values
+        .into_iter()
+        .filter(|s| !s.as_ref().is_empty())
+        .map(|s| opentelemetry::StringValue::from(s.as_ref().to_owned()))
+        .collect();
+    if !values.is_empty() {
+        span.set_attribute(
+            key,
+            opentelemetry::Value::Array(opentelemetry::Array::String(values)),
+        );
+    }
+}
@@ -0,0 +1,451 @@
+//! Neptune Data API attribute extraction following the OTel database semantic
+//! conventions.
+//!
+//! This module provides [`NeptuneDataExtractor`], which implements
+//! [`super::super::AttributeExtractor`] for the Neptune Data API openCypher
+//! and Gremlin SDK calls: `ExecuteOpenCypherQuery`,
+//! `ExecuteOpenCypherExplainQuery`, `GetOpenCypherQueryStatus`,
+//! `CancelOpenCypherQuery`, `ExecuteGremlinQuery`,
+//! `ExecuteGremlinExplainQuery`, `ExecuteGremlinProfileQuery`,
+//! `GetGremlinQueryStatus` and `CancelGremlinQuery`. It is automatically used
+//! by [`super::super::DefaultExtractor`] when the `extract-neptunedata`
+//! feature is enabled.
+//!
+//! ## Extracted attributes
+//!
+//! **Set from input:**
+//! - `db.system.name` — always `"aws.neptune"`
+//! - `aws.neptune.query.language` — `"opencypher"` or `"gremlin"`
+//! - `db.query.text` — for the execute, explain and profile operations, the
+//!   query with string and numeric literals replaced by `?`
+//! - `aws.neptune.explain_mode` — for `ExecuteOpenCypherExplainQuery`, the
+//!   explain mode, e.g. `"dynamic"`
+//! - `aws.neptune.query.id` — for the status and cancel operations, the query
+//!   ID
+//!
+//! **Set from output:**
+//! - `aws.neptune.query.id` — for `ExecuteGremlinQuery`, the ID Neptune
+//!   assigned to the query
+//! - for `GetOpenCypherQueryStatus` and `GetGremlinQueryStatus`,
+//!   `db.query.text` as above, and:
+//!   - `aws.neptune.query.elapsed_ms` — the time the query has been running,
+//!     in milliseconds
+//!   - `aws.neptune.query.waited_ms` — the time the query waited in the queue,
+//!     in milliseconds
+//!   - `aws.neptune.query.cancelled` — whether the query was cancelled
+//!
+//! Query parameters and results are never recorded. Neptune does not return
+//! the ID of an openCypher query from `ExecuteOpenCypherQuery`, so only the
+//! status and cancel spans carry one.
+
+// Neptune Data API attribute extraction — downcasts Input/Output to concrete
+// aws-sdk-neptunedata types and extracts graph query attributes.
+
+use aws_sdk_neptunedata::operation::{
+    cancel_gremlin_query::CancelGremlinQueryInput,
+    cancel_open_cypher_query::CancelOpenCypherQueryInput,
+    execute_gremlin_explain_query::ExecuteGremlinExplainQueryInput,
+    execute_gremlin_profile_query::ExecuteGremlinProfileQueryInput,
+    execute_gremlin_query::{ExecuteGremlinQueryInput, ExecuteGremlinQueryOutput},
+    execute_open_cypher_explain_query::ExecuteOpenCypherExplainQueryInput,
+    execute_open_cypher_query::ExecuteOpenCypherQueryInput,
+    get_gremlin_query_status::{GetGremlinQueryStatusInput, GetGremlinQueryStatusOutput},
+    get_open_cypher_query_status::{GetOpenCypherQueryStatusInput, GetOpenCypherQueryStatusOutput},
+};
+use aws_sdk_neptunedata::types::QueryEvalStats;
+use aws_smithy_runtime_api::client::interceptors::context;
+use opentelemetry_semantic_conventions::attribute as semco;
+
+use super::super::{AttributeExtractor, SpanWrite};
+use super::sanitize::sanitize_literals;
+use super::set_str;
+
+/// `db.system.name` value for Neptune.
+const DB_SYSTEM_NAME_AWS_NEPTUNE: &str = "aws.neptune";
+
+/// Attribute key for the graph query language.
+const AWS_NEPTUNE_QUERY_LANGUAGE: &str = "aws.neptune.query.language";
+/// Attribute key for the Neptune query ID.
+const AWS_NEPTUNE_QUERY_ID: &str = "aws.neptune.query.id";
+/// Attribute key for the openCypher explain mode.
+const AWS_NEPTUNE_EXPLAIN_MODE: &str = "aws.neptune.explain_mode";
+/// Attribute key for the time the query has been running, in milliseconds.
+const AWS_NEPTUNE_QUERY_ELAPSED_MS: &str = "aws.neptune.query.elapsed_ms";
+/// Attribute key for the time the query waited in the queue, in milliseconds.
+const AWS_NEPTUNE_QUERY_WAITED_MS: &str = "aws.neptune.query.waited_ms";
+/// Attribute key for whether the query was cancelled.
+const AWS_NEPTUNE_QUERY_CANCELLED: &str = "aws.neptune.query.cancelled";
+
+/// A graph query language supported by the Neptune Data API.
+#[derive(Debug, Clone, Copy)]
+enum QueryLanguage {
+    OpenCypher,
+    Gremlin,
+}
+
+impl QueryLanguage {
+    /// The `aws.neptune.query.language` value.
+    fn as_str(self) -> &'static str {
+        match self {
+            QueryLanguage::OpenCypher => "opencypher",
+            QueryLanguage::Gremlin => "gremlin",
+        }
+    }
+
+    /// Replaces the literals of `query` with `?`. openCypher quotes
+    /// identifiers with backticks; both languages accept single- and
+    /// double-quoted strings.
+    fn sanitize(self, query: &str) -> String {
+        match self {
+            QueryLanguage::OpenCypher => {
+                sanitize_literals(query, &['\'', '"'], &['`'], true, false)
+            }
+            QueryLanguage::Gremlin => sanitize_literals(query, &['\'', '"'], &[], true, false),
+        }
+    }
+}
+
+/// Attribute extractor for Neptune Data API SDK calls.
+///
+/// `NeptuneDataExtractor` implements [`AttributeExtractor`] and is
+/// automatically used by [`DefaultExtractor`] when the `extract-neptunedata`
+/// feature is enabled. You only need to construct it directly if you are
+/// composing a custom extraction pipeline.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes.
+///
+/// [`DefaultExtractor`]: crate::interceptor::DefaultExtractor
+#[derive(Debug, Default)]
+pub struct NeptuneDataExtractor {
+    _private: (),
+}
+
+impl NeptuneDataExtractor {
+    /// Creates a new `NeptuneDataExtractor`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::extract::neptunedata::NeptuneDataExtractor;
+    ///
+    /// let extractor = NeptuneDataExtractor::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+/// Extracts database OTel attributes from Neptune Data API SDK inputs and
+/// outputs.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes and which operations they apply to.
+impl<SW: SpanWrite> AttributeExtractor<SW> for NeptuneDataExtractor {
+    fn extract_input(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        input: &context::Input,
+        span: &mut SW,
+    ) {
+        use QueryLanguage::{Gremlin, OpenCypher};
+
+        match operation {
+            "ExecuteOpenCypherQuery" => {
+                let i = input
+                    .downcast_ref::<ExecuteOpenCypherQueryInput>()
+                    .expect("correct type");
+                set_query(span, OpenCypher, i.open_cypher_query());
+            }
+            "ExecuteOpenCypherExplainQuery" => {
+                let i = input
+                    .downcast_ref::<ExecuteOpenCypherExplainQueryInput>()
+                    .expect("correct type");
+                set_query(span, OpenCypher, i.open_cypher_query());
+                set_str(
+                    span,
+                    AWS_NEPTUNE_EXPLAIN_MODE,
+                    i.explain_mode().map(|mode| mode.as_str()),
+                );
+            }
+            "GetOpenCypherQueryStatus" => {
+                let i = input
+                    .downcast_ref::<GetOpenCypherQueryStatusInput>()
+                    .expect("correct type");
+                set_query(span, OpenCypher, None);
+                set_str(span, AWS_NEPTUNE_QUERY_ID, i.query_id());
+            }
+            "CancelOpenCypherQuery" => {
+                let i = input
+                    .downcast_ref::<CancelOpenCypherQueryInput>()
+                    .expect("correct type");
+                set_query(span, OpenCypher, None);
+                set_str(span, AWS_NEPTUNE_QUERY_ID, i.query_id());
+            }
+            "ExecuteGremlinQuery" => {
+                let i = input
+                    .downcast_ref::<ExecuteGremlinQueryInput>()
+                    .expect("correct type");
+                set_query(span, Gremlin, i.gremlin_query());
+            }
+            "ExecuteGremlinExplainQuery" => {
+                let i = input
+                    .downcast_ref::<ExecuteGremlinExplainQueryInput>()
+                    .expect("correct type");
+                set_query(span, Gremlin, i.gremlin_query());
+            }
+            "ExecuteGremlinProfileQuery" => {
+                let i = input
+                    .downcast_ref::<ExecuteGremlinProfileQueryInput>()
+                    .expect("correct type");
+                set_query(span, Gremlin, i.gremlin_query());
+            }
+            "GetGremlinQueryStatus" => {
+                let i = input
+                    .downcast_ref::<GetGremlinQueryStatusInput>()
+                    .expect("correct type");
+                set_query(span, Gremlin, None);
+                set_str(span, AWS_NEPTUNE_QUERY_ID, i.query_id());
+            }
+            "CancelGremlinQuery" => {
+                let i = input
+                    .downcast_ref::<CancelGremlinQueryInput>()
+                    .expect("correct type");
+                set_query(span, Gremlin, None);
+                set_str(span, AWS_NEPTUNE_QUERY_ID, i.query_id());
+            }
+            _ => {}
+        }
+    }
+
+    fn extract_output(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        output: &context::Output,
+        span: &mut SW,
+    ) {
+        match operation {
+            "ExecuteGremlinQuery" => {
+                let o = output
+                    .downcast_ref::<ExecuteGremlinQueryOutput>()
+                    .expect("correct type");
+                set_str(span, AWS_NEPTUNE_QUERY_ID, o.request_id());
+            }
+            "GetOpenCypherQueryStatus" => {
+                let o = output
+                    .downcast_ref::<GetOpenCypherQueryStatusOutput>()
+                    .expect("correct type");
+                set_query_text(span, QueryLanguage::OpenCypher, o.query_string());
+                set_eval_stats(span, o.query_eval_stats());
+            }
+            "GetGremlinQueryStatus" => {
+                let o = output
+                    .downcast_ref::<GetGremlinQueryStatusOutput>()
+                    .expect("correct type");
+                set_query_text(span, QueryLanguage::Gremlin, o.query_string());
+                set_eval_stats(span, o.query_eval_stats());
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Sets the database system, the query language and, when given, the
+/// sanitized query text.
+fn set_query(span: &mut impl SpanWrite, language: QueryLanguage, query: Option<&str>) {
+    span.set_attribute(
+        crate::interceptor::DB_SYSTEM_NAME,
+        DB_SYSTEM_NAME_AWS_NEPTUNE,
+    );
+    span.set_attribute(AWS_NEPTUNE_QUERY_LANGUAGE, language.as_str());
+    set_query_text(span, language, query);
+}
+
+/// Sets `db.query.text` from a non-empty query, with literals redacted.
+fn set_query_text(span: &mut impl SpanWrite, language: QueryLanguage, query: Option<&str>) {
+    if let Some(query) = query.filter(|s| !s.is_empty()) {
+        span.set_attribute(semco::DB_QUERY_TEXT, language.sanitize(query));
+    }
+}
+
+/// Sets the evaluation statistics of a running query.
+fn set_eval_stats(span: &mut impl SpanWrite, stats: Option<&QueryEvalStats>) {
+    let Some(stats) = stats else {
+        return;
+    };
+    if let Some(elapsed) = stats.elapsed() {
+        span.set_attribute(AWS_NEPTUNE_QUERY_ELAPSED_MS, i64::from(elapsed));
+    }
+    if let Some(waited) = stats.waited() {
+        span.set_attribute(AWS_NEPTUNE_QUERY_WAITED_MS, i64::from(waited));
+    }
+    if let Some(cancelled) = stats.cancelled() {
+        span.set_attribute(AWS_NEPTUNE_QUERY_CANCELLED, cancelled);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_neptunedata::types::OpenCypherExplainMode;
+    use opentelemetry::Value;
+
+    use crate::span_write::{SpanContext, SpanWrite, Status};
+
+    struct TestSpan {
+        attributes: Vec<(&'static str, Value)>,
+    }
+
+    impl TestSpan {
+        fn new() -> Self {
+            Self { attributes: vec![] }
+        }
+
+        fn get(&self, key: &str) -> Option<&Value> {
+            self.attributes
+                .iter()
+                .rev()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v)
+        }
+    }
+
+    impl SpanWrite for TestSpan {
+        fn set_attribute(&mut self, key: &'static str, value: impl Into<Value>) {
+            self.attributes.push((key, value.into()));
+        }
+
+        fn set_status(&mut self, _code: Status) {}
+
+        fn span_context(&self) -> SpanContext {
+            SpanContext::empty_context()
+        }
+    }
+
+    // Tests for NeptuneDataExtractor — 2 consolidated tests
+
+    #[test]
+    fn extract_open_cypher_explain_query() {
+        let sdk_input = ExecuteOpenCypherExplainQueryInput::builder()
+            .open_cypher_query(
+                "MATCH (p:`Person`) WHERE p.email = 'a@example.com' RETURN p LIMIT 5",
+            )
+            .parameters(r#"{"email":"a@example.com"}"#)
+            .explain_mode(OpenCypherExplainMode::Dynamic)
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        NeptuneDataExtractor::new().extract_input(
+            "neptunedata",
+            "ExecuteOpenCypherExplainQuery",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(crate::interceptor::DB_SYSTEM_NAME),
+            Some(&Value::from("aws.neptune"))
+        );
+        assert_eq!(
+            span.get(AWS_NEPTUNE_QUERY_LANGUAGE),
+            Some(&Value::from("opencypher"))
+        );
+        assert_eq!(
+            span.get(semco::DB_QUERY_TEXT),
+            Some(&Value::from(
+                "MATCH (p:`Person`) WHERE p.email = ? RETURN p LIMIT ?"
+            ))
+        );
+        assert_eq!(
+            span.get(AWS_NEPTUNE_EXPLAIN_MODE),
+            Some(&Value::from("dynamic"))
+        );
+        assert!(
+            span.attributes
+                .iter()
+                .all(|(_, value)| !value.to_string().contains("example.com"))
+        );
+    }
+
+    #[test]
+    fn extract_gremlin_query_and_status() {
+        let extractor = NeptuneDataExtractor::new();
+
+        let sdk_input = ExecuteGremlinQueryInput::builder()
+            .gremlin_query("g.V().has(\"name\", 'alice').out('knows').limit(10)")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "neptunedata",
+            "ExecuteGremlinQuery",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_NEPTUNE_QUERY_LANGUAGE),
+            Some(&Value::from("gremlin"))
+        );
+        assert_eq!(
+            span.get(semco::DB_QUERY_TEXT),
+            Some(&Value::from("g.V().has(?, ?).out(?).limit(?)"))
+        );
+        extractor.extract_output(
+            "neptunedata",
+            "ExecuteGremlinQuery",
+            &context::Output::erase(
+                ExecuteGremlinQueryOutput::builder()
+                    .request_id("q-123")
+                    .build(),
+            ),
+            &mut span,
+        );
+        assert_eq!(span.get(AWS_NEPTUNE_QUERY_ID), Some(&Value::from("q-123")));
+
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "neptunedata",
+            "GetGremlinQueryStatus",
+            &context::Input::erase(
+                GetGremlinQueryStatusInput::builder()
+                    .query_id("q-123")
+                    .build()
+                    .unwrap(),
+            ),
+            &mut span,
+        );
+        extractor.extract_output(
+            "neptunedata",
+            "GetGremlinQueryStatus",
+            &context::Output::erase(
+                GetGremlinQueryStatusOutput::builder()
+                    .query_id("q-123")
+                    .query_string("g.V().has('name', 'alice')")
+                    .query_eval_stats(
+                        QueryEvalStats::builder()
+                            .elapsed(1200)
+                            .waited(15)
+                            .cancelled(false)
+                            .build(),
+                    )
+                    .build(),
+            ),
+            &mut span,
+        );
+        assert_eq!(span.get(AWS_NEPTUNE_QUERY_ID), Some(&Value::from("q-123")));
+        assert_eq!(
+            span.get(semco::DB_QUERY_TEXT),
+            Some(&Value::from("g.V().has(?, ?)"))
+        );
+        assert_eq!(
+            span.get(AWS_NEPTUNE_QUERY_ELAPSED_MS),
+            Some(&Value::I64(1200))
+        );
+        assert_eq!(span.get(AWS_NEPTUNE_QUERY_WAITED_MS), Some(&Value::I64(15)));
+        assert_eq!(
+            span.get(AWS_NEPTUNE_QUERY_CANCELLED),
+            Some(&Value::Bool(false))
+        );
+    }
+}
@@ -0,0 +1,102 @@
+//! W3C Trace Context helpers shared by the messaging extractors.
+//!
+//! Producers carry the span context in `traceparent` and `tracestate` message
+//! attributes, the same keys the W3C Trace Context specification defines for
+//! HTTP headers. The SQS extractor reads them back on receive, so a message
+//! published through SNS and delivered to SQS links back to its producer.
+
+use std::collections::HashMap;
+
+#[cfg(feature = "extract-sns")]
+use opentelemetry::Context;
+use opentelemetry::{
+    propagation::TextMapPropagator,
+    trace::{SpanContext, TraceContextExt},
+};
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+
+/// W3C Trace Context message attribute carrying the producer span context.
+pub(super) const TRACEPARENT: &str = "traceparent";
+/// W3C Trace Context message attribute carrying vendor-specific trace state.
+pub(super) const TRACESTATE: &str = "tracestate";
+
+/// Parses a `traceparent` (and optional `tracestate`) value into a remote
+/// [`SpanContext`], which is invalid if the value cannot be parsed.
+#[cfg(feature = "extract-sqs")]
+pub(super) fn extract_trace_context(traceparent: &str, tracestate: Option<&str>) -> SpanContext {
+    let mut carrier = HashMap::from([(TRACEPARENT.to_owned(), traceparent.to_owned())]);
+    if let Some(tracestate) = tracestate {
+        carrier.insert(TRACESTATE.to_owned(), tracestate.to_owned());
+    }
+    TraceContextPropagator::new()
+        .extract(&carrier)
+        .span()
+        .span_context()
+        .clone()
+}
+
+/// Serializes `span_context` into `(name, value)` pairs for the `traceparent`
+/// and, when the trace state is not empty, `tracestate` message attributes.
+///
+/// Returns an empty list for an invalid span context.
+#[cfg(feature = "extract-sns")]
+pub(super) fn inject_trace_context(span_context: &SpanContext) -> Vec<(&'static str, String)> {
+    let mut carrier = HashMap::new();
+    TraceContextPropagator::new().inject_context(
+        &Context::new().with_remote_span_context(span_context.clone()),
+        &mut carrier,
+    );
+    [TRACEPARENT, TRACESTATE]
+        .into_iter()
+        .filter_map(|name| {
+            carrier
+                .remove(name)
+                .filter(|value| !value.is_empty())
+                .map(|value| (name, value))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(all(feature = "extract-sqs", feature = "extract-sns"))]
+    fn trace_context_round_trip() {
+        use opentelemetry::trace::{SpanId, TraceFlags, TraceId, TraceState};
+
+        let span_context = SpanContext::new(
+            TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736").unwrap(),
+            SpanId::from_hex("00f067aa0ba902b7").unwrap(),
+            TraceFlags::SAMPLED,
+            false,
+            TraceState::from_key_value([("vendor", "value")]).unwrap(),
+        );
+        let injected = inject_trace_context(&span_context);
+        assert_eq!(
+            injected,
+            vec![
+                (
+                    TRACEPARENT,
+                    "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01".to_owned()
+                ),
+                (TRACESTATE, "vendor=value".to_owned()),
+            ]
+        );
+
+        let extracted = extract_trace_context(&injected[0].1, Some(&injected[1].1));
+        assert_eq!(extracted.trace_id(), span_context.trace_id());
+        assert_eq!(extracted.span_id(), span_context.span_id());
+        assert!(extracted.is_remote());
+        assert_eq!(extracted.trace_state().header(), "vendor=value");
+    }
+
+    #[test]
+    fn invalid_trace_context() {
+        #[cfg(feature = "extract-sns")]
+        assert!(inject_trace_context(&SpanContext::empty_context()).is_empty());
+        #[cfg(feature = "extract-sqs")]
+        assert!(!extract_trace_context("not-a-traceparent", None).is_valid());
+    }
+}
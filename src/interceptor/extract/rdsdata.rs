@@ -0,0 +1,574 @@
+//! RDS Data API attribute extraction following the OTel database semantic
+//! conventions.
+//!
+//! This module provides [`RDSDataExtractor`], which implements
+//! [`super::super::AttributeExtractor`] for the RDS Data API
+//! `ExecuteStatement`, `BatchExecuteStatement`, `BeginTransaction`,
+//! `CommitTransaction` and `RollbackTransaction` SDK calls. It is
+//! automatically used by [`super::super::DefaultExtractor`] when the
+//! `extract-rdsdata` feature is enabled.
+//!
+//! ## Extracted attributes
+//!
+//! **Set from input:**
+//! - `db.system.name` — `"postgresql"` or `"mysql"`, from
+//!   [`RDSDataExtractor::with_db_system_name`] or inferred from the SQL
+//!   dialect, and `"other_sql"` otherwise
+//! - `db.namespace` — the database and schema, joined with `|` when both are
+//!   given
+//! - `db.query.text` — the SQL statement, with literals replaced by `?`;
+//!   `:name` parameters are left unexpanded
+//! - `db.operation.batch.size` — for `BatchExecuteStatement`, the number of
+//!   parameter sets
+//! - `aws.rdsdata.resource_arn` — the ARN of the Aurora cluster
+//! - `aws.rdsdata.transaction_id` — the transaction the call belongs to, when
+//!   given
+//!
+//! **Set from output:**
+//! - `aws.rdsdata.transaction_id` — for `BeginTransaction`, the transaction
+//!   started
+//! - `aws.rdsdata.number_of_records_updated` — for `ExecuteStatement`, the
+//!   number of records updated
+//! - `db.response.returned_rows` — for `ExecuteStatement`, the number of
+//!   records returned, unless they are formatted as JSON
+//! - `aws.rdsdata.transaction_status` — for `CommitTransaction` and
+//!   `RollbackTransaction`, the status of the transaction
+//!
+//! The dialect is inferred from markers only one of the engines accepts:
+//! backquoted identifiers for MySQL and `::` casts for PostgreSQL. Parameter
+//! values and returned records are never recorded.
+
+// RDS Data API attribute extraction — downcasts Input/Output to concrete
+// aws-sdk-rdsdata types and extracts database, query and transaction
+// attributes.
+
+use aws_sdk_rdsdata::operation::{
+    batch_execute_statement::BatchExecuteStatementInput,
+    begin_transaction::{BeginTransactionInput, BeginTransactionOutput},
+    commit_transaction::{CommitTransactionInput, CommitTransactionOutput},
+    execute_statement::{ExecuteStatementInput, ExecuteStatementOutput},
+    rollback_transaction::{RollbackTransactionInput, RollbackTransactionOutput},
+};
+use aws_smithy_runtime_api::client::interceptors::context;
+use opentelemetry::Value;
+use opentelemetry_semantic_conventions::attribute as semco;
+
+use super::super::{AttributeExtractor, SpanWrite};
+use super::sanitize::sanitize_literals;
+use super::set_str;
+
+/// `db.system.name` value for PostgreSQL.
+const DB_SYSTEM_NAME_POSTGRESQL: &str = "postgresql";
+/// `db.system.name` value for MySQL.
+const DB_SYSTEM_NAME_MYSQL: &str = "mysql";
+/// `db.system.name` value for a SQL database whose engine is unknown.
+const DB_SYSTEM_NAME_OTHER_SQL: &str = "other_sql";
+
+/// Attribute key for the ARN of the Aurora cluster.
+const AWS_RDSDATA_RESOURCE_ARN: &str = "aws.rdsdata.resource_arn";
+/// Attribute key for the transaction ID.
+const AWS_RDSDATA_TRANSACTION_ID: &str = "aws.rdsdata.transaction_id";
+/// Attribute key for the number of records updated by a statement.
+const AWS_RDSDATA_NUMBER_OF_RECORDS_UPDATED: &str = "aws.rdsdata.number_of_records_updated";
+/// Attribute key for the status of a committed or rolled back transaction.
+const AWS_RDSDATA_TRANSACTION_STATUS: &str = "aws.rdsdata.transaction_status";
+
+/// Attribute extractor for RDS Data API SDK calls.
+///
+/// `RDSDataExtractor` implements [`AttributeExtractor`] and is automatically
+/// used by [`DefaultExtractor`] when the `extract-rdsdata` feature is enabled.
+/// Construct it directly to set the database engine of your cluster, then hand
+/// it to [`DefaultExtractor::set_rdsdata_extractor`].
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes.
+///
+/// [`DefaultExtractor`]: crate::interceptor::DefaultExtractor
+/// [`DefaultExtractor::set_rdsdata_extractor`]: crate::interceptor::DefaultExtractor::set_rdsdata_extractor
+#[derive(Debug, Default)]
+pub struct RDSDataExtractor {
+    db_system_name: Option<String>,
+}
+
+impl RDSDataExtractor {
+    /// Creates a new `RDSDataExtractor` that infers the database engine from
+    /// the SQL dialect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::extract::rdsdata::RDSDataExtractor;
+    ///
+    /// let extractor = RDSDataExtractor::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `db.system.name` recorded for every call, e.g. `"postgresql"`
+    /// or `"mysql"`, instead of inferring it from the SQL dialect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::{
+    ///     DefaultInterceptor, extract::rdsdata::RDSDataExtractor,
+    /// };
+    ///
+    /// let mut interceptor = DefaultInterceptor::new();
+    /// interceptor
+    ///     .extractor
+    ///     .set_rdsdata_extractor(RDSDataExtractor::new().with_db_system_name("postgresql"));
+    /// ```
+    pub fn with_db_system_name(mut self, db_system_name: impl Into<String>) -> Self {
+        self.db_system_name = Some(db_system_name.into());
+        self
+    }
+}
+
+/// Extracts database OTel attributes from RDS Data API SDK inputs and outputs.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes and which operations they apply to.
+impl<SW: SpanWrite> AttributeExtractor<SW> for RDSDataExtractor {
+    fn extract_input(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        input: &context::Input,
+        span: &mut SW,
+    ) {
+        match operation {
+            "ExecuteStatement" => {
+                let i = input
+                    .downcast_ref::<ExecuteStatementInput>()
+                    .expect("correct type");
+                self.set_statement(span, i.sql());
+                set_database(span, i.resource_arn(), i.database(), i.schema());
+                set_str(span, AWS_RDSDATA_TRANSACTION_ID, i.transaction_id());
+            }
+            "BatchExecuteStatement" => {
+                let i = input
+                    .downcast_ref::<BatchExecuteStatementInput>()
+                    .expect("correct type");
+                self.set_statement(span, i.sql());
+                set_database(span, i.resource_arn(), i.database(), i.schema());
+                set_str(span, AWS_RDSDATA_TRANSACTION_ID, i.transaction_id());
+                span.set_attribute(
+                    semco::DB_OPERATION_BATCH_SIZE,
+                    Value::I64(i.parameter_sets().len() as i64),
+                );
+            }
+            "BeginTransaction" => {
+                let i = input
+                    .downcast_ref::<BeginTransactionInput>()
+                    .expect("correct type");
+                self.set_statement(span, None);
+                set_database(span, i.resource_arn(), i.database(), i.schema());
+            }
+            "CommitTransaction" => {
+                let i = input
+                    .downcast_ref::<CommitTransactionInput>()
+                    .expect("correct type");
+                self.set_statement(span, None);
+                set_database(span, i.resource_arn(), None, None);
+                set_str(span, AWS_RDSDATA_TRANSACTION_ID, i.transaction_id());
+            }
+            "RollbackTransaction" => {
+                let i = input
+                    .downcast_ref::<RollbackTransactionInput>()
+                    .expect("correct type");
+                self.set_statement(span, None);
+                set_database(span, i.resource_arn(), None, None);
+                set_str(span, AWS_RDSDATA_TRANSACTION_ID, i.transaction_id());
+            }
+            _ => {}
+        }
+    }
+
+    fn extract_output(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        output: &context::Output,
+        span: &mut SW,
+    ) {
+        match operation {
+            "ExecuteStatement" => {
+                let o = output
+                    .downcast_ref::<ExecuteStatementOutput>()
+                    .expect("correct type");
+                span.set_attribute(
+                    AWS_RDSDATA_NUMBER_OF_RECORDS_UPDATED,
+                    o.number_of_records_updated(),
+                );
+                if o.formatted_records().is_none() {
+                    span.set_attribute(semco::DB_RESPONSE_RETURNED_ROWS, o.records().len() as i64);
+                }
+            }
+            "BeginTransaction" => {
+                let o = output
+                    .downcast_ref::<BeginTransactionOutput>()
+                    .expect("correct type");
+                set_str(span, AWS_RDSDATA_TRANSACTION_ID, o.transaction_id());
+            }
+            "CommitTransaction" => {
+                let o = output
+                    .downcast_ref::<CommitTransactionOutput>()
+                    .expect("correct type");
+                set_str(span, AWS_RDSDATA_TRANSACTION_STATUS, o.transaction_status());
+            }
+            "RollbackTransaction" => {
+                let o = output
+                    .downcast_ref::<RollbackTransactionOutput>()
+                    .expect("correct type");
+                set_str(span, AWS_RDSDATA_TRANSACTION_STATUS, o.transaction_status());
+            }
+            _ => {}
+        }
+    }
+}
+
+impl RDSDataExtractor {
+    /// Sets `db.system.name` and the sanitized `db.query.text` of a call.
+    ///
+    /// The configured engine wins; otherwise it is inferred from `sql`. The
+    /// literals are redacted according to the engine's quoting rules. When the
+    /// engine is unknown, double quotes are treated as string delimiters and
+    /// PostgreSQL escape and dollar-quoted strings are redacted too.
+    fn set_statement(&self, span: &mut impl SpanWrite, sql: Option<&str>) {
+        let db_system_name = match &self.db_system_name {
+            Some(db_system_name) => db_system_name.clone(),
+            None => sql
+                .and_then(infer_db_system_name)
+                .unwrap_or(DB_SYSTEM_NAME_OTHER_SQL)
+                .to_owned(),
+        };
+        if let Some(sql) = sql.filter(|s| !s.is_empty()) {
+            let query_text = if db_system_name == DB_SYSTEM_NAME_POSTGRESQL {
+                sanitize_literals(sql, &['\''], &['"'], false, true)
+            } else {
+                sanitize_literals(
+                    sql,
+                    &['\'', '"'],
+                    &['`'],
+                    true,
+                    db_system_name != DB_SYSTEM_NAME_MYSQL,
+                )
+            };
+            span.set_attribute(semco::DB_QUERY_TEXT, query_text);
+        }
+        span.set_attribute(crate::interceptor::DB_SYSTEM_NAME, db_system_name);
+    }
+}
+
+/// Infers the database engine from SQL syntax only one engine accepts:
+/// backquoted identifiers for MySQL, `::` casts for PostgreSQL.
+fn infer_db_system_name(sql: &str) -> Option<&'static str> {
+    // Drop string literals first so their content cannot be mistaken for syntax
+    let code = sanitize_literals(sql, &['\''], &[], true, true);
+    if code.contains('`') {
+        Some(DB_SYSTEM_NAME_MYSQL)
+    } else if code.contains("::") {
+        Some(DB_SYSTEM_NAME_POSTGRESQL)
+    } else {
+        None
+    }
+}
+
+/// Sets the cluster ARN and the `db.namespace` built from the database and
+/// schema.
+fn set_database(
+    span: &mut impl SpanWrite,
+    resource_arn: Option<&str>,
+    database: Option<&str>,
+    schema: Option<&str>,
+) {
+    set_str(span, AWS_RDSDATA_RESOURCE_ARN, resource_arn);
+    let namespace: Vec<&str> = [database, schema]
+        .into_iter()
+        .flatten()
+        .filter(|s| !s.is_empty())
+        .collect();
+    if !namespace.is_empty() {
+        span.set_attribute(semco::DB_NAMESPACE, namespace.join("|"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_rdsdata::types::{Field, SqlParameter};
+
+    use crate::span_write::{SpanContext, SpanWrite, Status};
+
+    struct TestSpan {
+        attributes: Vec<(&'static str, Value)>,
+    }
+
+    impl TestSpan {
+        fn new() -> Self {
+            Self { attributes: vec![] }
+        }
+
+        fn get(&self, key: &str) -> Option<&Value> {
+            self.attributes
+                .iter()
+                .rev()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v)
+        }
+    }
+
+    impl SpanWrite for TestSpan {
+        fn set_attribute(&mut self, key: &'static str, value: impl Into<Value>) {
+            self.attributes.push((key, value.into()));
+        }
+
+        fn set_status(&mut self, _code: Status) {}
+
+        fn span_context(&self) -> SpanContext {
+            SpanContext::empty_context()
+        }
+    }
+
+    const CLUSTER_ARN: &str = "arn:aws:rds:us-east-1:123456789012:cluster:orders";
+
+    // Tests for RDSDataExtractor — 4 consolidated tests
+
+    #[test]
+    fn extract_execute_statement() {
+        let extractor = RDSDataExtractor::new();
+
+        let sdk_input = ExecuteStatementInput::builder()
+            .resource_arn(CLUSTER_ARN)
+            .secret_arn("arn:aws:secretsmanager:us-east-1:123456789012:secret:db")
+            .database("orders")
+            .schema("public")
+            .sql("SELECT id FROM orders WHERE status = 'paid' AND total::numeric > 100 AND customer = :customer")
+            .parameters(
+                SqlParameter::builder()
+                    .name("customer")
+                    .value(Field::StringValue("alice".into()))
+                    .build(),
+            )
+            .transaction_id("tx-1")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "RDS Data",
+            "ExecuteStatement",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(crate::interceptor::DB_SYSTEM_NAME),
+            Some(&Value::from("postgresql"))
+        );
+        assert_eq!(
+            span.get(semco::DB_NAMESPACE),
+            Some(&Value::from("orders|public"))
+        );
+        assert_eq!(
+            span.get(semco::DB_QUERY_TEXT),
+            Some(&Value::from(
+                "SELECT id FROM orders WHERE status = ? AND total::numeric > ? AND customer = :customer"
+            ))
+        );
+        assert_eq!(
+            span.get(AWS_RDSDATA_RESOURCE_ARN),
+            Some(&Value::from(CLUSTER_ARN))
+        );
+        assert_eq!(
+            span.get(AWS_RDSDATA_TRANSACTION_ID),
+            Some(&Value::from("tx-1"))
+        );
+        assert!(
+            span.attributes
+                .iter()
+                .all(|(_, value)| !value.to_string().contains("alice"))
+        );
+
+        let sdk_output = ExecuteStatementOutput::builder()
+            .records(vec![Field::LongValue(1)])
+            .records(vec![Field::LongValue(2)])
+            .number_of_records_updated(0)
+            .build();
+        extractor.extract_output(
+            "RDS Data",
+            "ExecuteStatement",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_RDSDATA_NUMBER_OF_RECORDS_UPDATED),
+            Some(&Value::I64(0))
+        );
+        assert_eq!(
+            span.get(semco::DB_RESPONSE_RETURNED_ROWS),
+            Some(&Value::I64(2))
+        );
+    }
+
+    #[test]
+    fn extract_batch_and_transaction() {
+        let extractor = RDSDataExtractor::new().with_db_system_name("mysql");
+
+        let sdk_input = BatchExecuteStatementInput::builder()
+            .resource_arn(CLUSTER_ARN)
+            .database("orders")
+            .sql(r#"INSERT INTO items (sku, note) VALUES (:sku, "gift")"#)
+            .parameter_sets(vec![])
+            .parameter_sets(vec![])
+            .parameter_sets(vec![])
+            .transaction_id("tx-2")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "RDS Data",
+            "BatchExecuteStatement",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(crate::interceptor::DB_SYSTEM_NAME),
+            Some(&Value::from("mysql"))
+        );
+        assert_eq!(span.get(semco::DB_NAMESPACE), Some(&Value::from("orders")));
+        assert_eq!(
+            span.get(semco::DB_QUERY_TEXT),
+            Some(&Value::from(
+                "INSERT INTO items (sku, note) VALUES (:sku, ?)"
+            ))
+        );
+        assert_eq!(
+            span.get(semco::DB_OPERATION_BATCH_SIZE),
+            Some(&Value::I64(3))
+        );
+
+        let mut span = TestSpan::new();
+        extractor.extract_output(
+            "RDS Data",
+            "BeginTransaction",
+            &context::Output::erase(
+                BeginTransactionOutput::builder()
+                    .transaction_id("tx-2")
+                    .build(),
+            ),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_RDSDATA_TRANSACTION_ID),
+            Some(&Value::from("tx-2"))
+        );
+
+        let mut span = TestSpan::new();
+        extractor.extract_output(
+            "RDS Data",
+            "CommitTransaction",
+            &context::Output::erase(
+                CommitTransactionOutput::builder()
+                    .transaction_status("Transaction Committed")
+                    .build(),
+            ),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_RDSDATA_TRANSACTION_STATUS),
+            Some(&Value::from("Transaction Committed"))
+        );
+    }
+
+    #[test]
+    fn extract_execute_statement_redacts_dollar_quotes() {
+        let sql = "UPDATE users SET token = $$s3cr3t$$, note = $n$it's s3cr3t$n$ WHERE id = :id";
+        for extractor in [
+            RDSDataExtractor::new().with_db_system_name("postgresql"),
+            RDSDataExtractor::new(),
+        ] {
+            let sdk_input = ExecuteStatementInput::builder()
+                .resource_arn(CLUSTER_ARN)
+                .sql(sql)
+                .build()
+                .unwrap();
+            let mut span = TestSpan::new();
+            extractor.extract_input(
+                "RDS Data",
+                "ExecuteStatement",
+                &context::Input::erase(sdk_input),
+                &mut span,
+            );
+            assert_eq!(
+                span.get(semco::DB_QUERY_TEXT),
+                Some(&Value::from(
+                    "UPDATE users SET token = ?, note = ? WHERE id = :id"
+                ))
+            );
+            assert!(
+                span.attributes
+                    .iter()
+                    .all(|(_, value)| !value.to_string().contains("s3cr3t"))
+            );
+        }
+    }
+
+    #[test]
+    fn extract_execute_statement_redacts_engine_literals() {
+        for (db_system_name, sql, query_text) in [
+            (
+                "postgresql",
+                r"UPDATE users SET note = E'O\'Reilly s3cr3t' WHERE id = :id",
+                "UPDATE users SET note = ? WHERE id = :id",
+            ),
+            (
+                "mysql",
+                "UPDATE users SET token = 0x73336372337421 WHERE id = :id",
+                "UPDATE users SET token = ? WHERE id = :id",
+            ),
+        ] {
+            let sdk_input = ExecuteStatementInput::builder()
+                .resource_arn(CLUSTER_ARN)
+                .sql(sql)
+                .build()
+                .unwrap();
+            let mut span = TestSpan::new();
+            RDSDataExtractor::new()
+                .with_db_system_name(db_system_name)
+                .extract_input(
+                    "RDS Data",
+                    "ExecuteStatement",
+                    &context::Input::erase(sdk_input),
+                    &mut span,
+                );
+            assert_eq!(
+                span.get(semco::DB_QUERY_TEXT),
+                Some(&Value::from(query_text))
+            );
+            assert!(
+                span.attributes
+                    .iter()
+                    .all(|(_, value)| !value.to_string().contains("s3cr3t"))
+            );
+        }
+    }
+
+    #[test]
+    fn infer_db_system_name_from_dialect() {
+        assert_eq!(
+            infer_db_system_name("SELECT `id` FROM `orders`"),
+            Some("mysql")
+        );
+        assert_eq!(
+            infer_db_system_name("SELECT now()::date"),
+            Some("postgresql")
+        );
+        // Markers inside string literals are ignored
+        assert_eq!(
+            infer_db_system_name("SELECT id FROM t WHERE note = 'a::b `c`'"),
+            None
+        );
+        assert_eq!(infer_db_system_name("SELECT 1"), None);
+    }
+}
@@ -0,0 +1,464 @@
+//! Redshift Data API attribute extraction following the OTel database
+//! semantic conventions.
+//!
+//! This module provides [`RedshiftDataExtractor`], which implements
+//! [`super::super::AttributeExtractor`] for the Redshift Data API
+//! `ExecuteStatement`, `BatchExecuteStatement`, `DescribeStatement`,
+//! `GetStatementResult` and `CancelStatement` SDK calls. It is automatically
+//! used by [`super::super::DefaultExtractor`] when the `extract-redshift-data`
+//! feature is enabled.
+//!
+//! Statements run asynchronously: the statement ID returned on submission is
+//! recorded on the submit span and on every span polling or reading that
+//! statement, so they can be tied together.
+//!
+//! ## Extracted attributes
+//!
+//! **Set from input:**
+//! - `db.system.name` — always `"aws.redshift"`
+//! - `db.query.text` — for `ExecuteStatement` and `BatchExecuteStatement`, the
+//!   SQL with literals replaced by `?`, statements of a batch joined with `"; "`
+//! - `db.namespace` — the database, for `ExecuteStatement` and
+//!   `BatchExecuteStatement`
+//! - `db.operation.batch.size` — for `BatchExecuteStatement`, the number of
+//!   statements
+//! - `aws.redshift_data.workgroup_name` — the Redshift Serverless workgroup,
+//!   when given
+//! - `aws.redshift_data.cluster_identifier` — the provisioned cluster, when
+//!   given
+//! - `aws.redshift_data.statement_id` — for `DescribeStatement`,
+//!   `GetStatementResult` and `CancelStatement`, the statement polled
+//!
+//! **Set from output:**
+//! - `aws.redshift_data.statement_id` — for `ExecuteStatement` and
+//!   `BatchExecuteStatement`, the statement submitted
+//! - for `DescribeStatement`, `db.query.text`, `db.namespace`, the workgroup
+//!   or cluster as above, and:
+//!   - `aws.redshift_data.status` — the statement status, e.g. `"FINISHED"`
+//!   - `aws.redshift_data.execution_time_ms` — the time the statement ran, in
+//!     milliseconds
+//!   - `aws.redshift_data.result_rows` — the number of rows returned or
+//!     affected
+//!   - `aws.redshift_data.result_size` — the size of the results in bytes
+//! - `db.response.returned_rows` — for `GetStatementResult`, the total number
+//!   of rows in the result
+//!
+//! Redshift does not report the bytes scanned by a statement. Parameter values
+//! and result records are never recorded.
+
+// Redshift Data API attribute extraction — downcasts Input/Output to concrete
+// aws-sdk-redshiftdata types and extracts statement, query and database
+// attributes.
+
+use aws_sdk_redshiftdata::operation::{
+    batch_execute_statement::{BatchExecuteStatementInput, BatchExecuteStatementOutput},
+    cancel_statement::CancelStatementInput,
+    describe_statement::{DescribeStatementInput, DescribeStatementOutput},
+    execute_statement::{ExecuteStatementInput, ExecuteStatementOutput},
+    get_statement_result::{GetStatementResultInput, GetStatementResultOutput},
+};
+use aws_smithy_runtime_api::client::interceptors::context;
+use opentelemetry::Value;
+use opentelemetry_semantic_conventions::attribute as semco;
+
+use super::super::{AttributeExtractor, SpanWrite};
+use super::sanitize::sanitize_literals;
+use super::set_str;
+
+/// `db.system.name` value for Redshift.
+const DB_SYSTEM_NAME_AWS_REDSHIFT: &str = "aws.redshift";
+
+/// Attribute key for the Redshift Serverless workgroup name.
+const AWS_REDSHIFT_DATA_WORKGROUP_NAME: &str = "aws.redshift_data.workgroup_name";
+/// Attribute key for the provisioned cluster identifier.
+const AWS_REDSHIFT_DATA_CLUSTER_IDENTIFIER: &str = "aws.redshift_data.cluster_identifier";
+/// Attribute key for the statement ID.
+const AWS_REDSHIFT_DATA_STATEMENT_ID: &str = "aws.redshift_data.statement_id";
+/// Attribute key for the statement status.
+const AWS_REDSHIFT_DATA_STATUS: &str = "aws.redshift_data.status";
+/// Attribute key for the time the statement ran, in milliseconds.
+const AWS_REDSHIFT_DATA_EXECUTION_TIME_MS: &str = "aws.redshift_data.execution_time_ms";
+/// Attribute key for the number of rows returned or affected by the statement.
+const AWS_REDSHIFT_DATA_RESULT_ROWS: &str = "aws.redshift_data.result_rows";
+/// Attribute key for the size of the statement results in bytes.
+const AWS_REDSHIFT_DATA_RESULT_SIZE: &str = "aws.redshift_data.result_size";
+
+/// Attribute extractor for Redshift Data API SDK calls.
+///
+/// `RedshiftDataExtractor` implements [`AttributeExtractor`] and is
+/// automatically used by [`DefaultExtractor`] when the `extract-redshift-data`
+/// feature is enabled. You only need to construct it directly if you are
+/// composing a custom extraction pipeline.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes.
+///
+/// [`DefaultExtractor`]: crate::interceptor::DefaultExtractor
+#[derive(Debug, Default)]
+pub struct RedshiftDataExtractor {
+    _private: (),
+}
+
+impl RedshiftDataExtractor {
+    /// Creates a new `RedshiftDataExtractor`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::extract::redshift_data::RedshiftDataExtractor;
+    ///
+    /// let extractor = RedshiftDataExtractor::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+/// Extracts database OTel attributes from Redshift Data API SDK inputs and
+/// outputs.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes and which operations they apply to.
+impl<SW: SpanWrite> AttributeExtractor<SW> for RedshiftDataExtractor {
+    fn extract_input(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        input: &context::Input,
+        span: &mut SW,
+    ) {
+        match operation {
+            "ExecuteStatement" => {
+                let i = input
+                    .downcast_ref::<ExecuteStatementInput>()
+                    .expect("correct type");
+                span.set_attribute(
+                    crate::interceptor::DB_SYSTEM_NAME,
+                    DB_SYSTEM_NAME_AWS_REDSHIFT,
+                );
+                set_query_text(span, i.sql());
+                set_target(
+                    span,
+                    i.database(),
+                    i.workgroup_name(),
+                    i.cluster_identifier(),
+                );
+            }
+            "BatchExecuteStatement" => {
+                let i = input
+                    .downcast_ref::<BatchExecuteStatementInput>()
+                    .expect("correct type");
+                span.set_attribute(
+                    crate::interceptor::DB_SYSTEM_NAME,
+                    DB_SYSTEM_NAME_AWS_REDSHIFT,
+                );
+                set_query_text(span, i.sqls().iter().map(String::as_str));
+                span.set_attribute(
+                    semco::DB_OPERATION_BATCH_SIZE,
+                    Value::I64(i.sqls().len() as i64),
+                );
+                set_target(
+                    span,
+                    i.database(),
+                    i.workgroup_name(),
+                    i.cluster_identifier(),
+                );
+            }
+            "DescribeStatement" => {
+                let i = input
+                    .downcast_ref::<DescribeStatementInput>()
+                    .expect("correct type");
+                span.set_attribute(
+                    crate::interceptor::DB_SYSTEM_NAME,
+                    DB_SYSTEM_NAME_AWS_REDSHIFT,
+                );
+                set_str(span, AWS_REDSHIFT_DATA_STATEMENT_ID, i.id());
+            }
+            "GetStatementResult" => {
+                let i = input
+                    .downcast_ref::<GetStatementResultInput>()
+                    .expect("correct type");
+                span.set_attribute(
+                    crate::interceptor::DB_SYSTEM_NAME,
+                    DB_SYSTEM_NAME_AWS_REDSHIFT,
+                );
+                set_str(span, AWS_REDSHIFT_DATA_STATEMENT_ID, i.id());
+            }
+            "CancelStatement" => {
+                let i = input
+                    .downcast_ref::<CancelStatementInput>()
+                    .expect("correct type");
+                span.set_attribute(
+                    crate::interceptor::DB_SYSTEM_NAME,
+                    DB_SYSTEM_NAME_AWS_REDSHIFT,
+                );
+                set_str(span, AWS_REDSHIFT_DATA_STATEMENT_ID, i.id());
+            }
+            _ => {}
+        }
+    }
+
+    fn extract_output(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        output: &context::Output,
+        span: &mut SW,
+    ) {
+        match operation {
+            "ExecuteStatement" => {
+                let o = output
+                    .downcast_ref::<ExecuteStatementOutput>()
+                    .expect("correct type");
+                set_str(span, AWS_REDSHIFT_DATA_STATEMENT_ID, o.id());
+            }
+            "BatchExecuteStatement" => {
+                let o = output
+                    .downcast_ref::<BatchExecuteStatementOutput>()
+                    .expect("correct type");
+                set_str(span, AWS_REDSHIFT_DATA_STATEMENT_ID, o.id());
+            }
+            "DescribeStatement" => {
+                let o = output
+                    .downcast_ref::<DescribeStatementOutput>()
+                    .expect("correct type");
+                if o.sub_statements().is_empty() {
+                    set_query_text(span, o.query_string());
+                } else {
+                    set_query_text(
+                        span,
+                        o.sub_statements()
+                            .iter()
+                            .filter_map(|statement| statement.query_string()),
+                    );
+                }
+                set_target(
+                    span,
+                    o.database(),
+                    o.workgroup_name(),
+                    o.cluster_identifier(),
+                );
+                set_str(
+                    span,
+                    AWS_REDSHIFT_DATA_STATUS,
+                    o.status().map(|status| status.as_str()),
+                );
+                span.set_attribute(
+                    AWS_REDSHIFT_DATA_EXECUTION_TIME_MS,
+                    o.duration() / 1_000_000,
+                );
+                // -1 stands for an unknown value
+                if o.result_rows() >= 0 {
+                    span.set_attribute(AWS_REDSHIFT_DATA_RESULT_ROWS, o.result_rows());
+                }
+                if o.result_size() >= 0 {
+                    span.set_attribute(AWS_REDSHIFT_DATA_RESULT_SIZE, o.result_size());
+                }
+            }
+            "GetStatementResult" => {
+                let o = output
+                    .downcast_ref::<GetStatementResultOutput>()
+                    .expect("correct type");
+                span.set_attribute(semco::DB_RESPONSE_RETURNED_ROWS, o.total_num_rows());
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Sets `db.query.text` from SQL statements, with literals redacted and the
+/// statements joined with `"; "`. Redshift string literals accept backslash
+/// escapes, so `\'` does not close one.
+fn set_query_text<'a>(span: &mut impl SpanWrite, statements: impl IntoIterator<Item = &'a str>) {
+    let query_texts: Vec<String> = statements
+        .into_iter()
+        .filter(|s| !s.is_empty())
+        .map(|statement| sanitize_literals(statement, &['\''], &['"'], true, true))
+        .collect();
+    if !query_texts.is_empty() {
+        span.set_attribute(semco::DB_QUERY_TEXT, query_texts.join("; "));
+    }
+}
+
+/// Sets the database, workgroup and cluster a statement runs against.
+fn set_target(
+    span: &mut impl SpanWrite,
+    database: Option<&str>,
+    workgroup_name: Option<&str>,
+    cluster_identifier: Option<&str>,
+) {
+    set_str(span, semco::DB_NAMESPACE, database);
+    set_str(span, AWS_REDSHIFT_DATA_WORKGROUP_NAME, workgroup_name);
+    set_str(
+        span,
+        AWS_REDSHIFT_DATA_CLUSTER_IDENTIFIER,
+        cluster_identifier,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_redshiftdata::types::{StatusString, SubStatementData};
+
+    use crate::span_write::{SpanContext, SpanWrite, Status};
+
+    struct TestSpan {
+        attributes: Vec<(&'static str, Value)>,
+    }
+
+    impl TestSpan {
+        fn new() -> Self {
+            Self { attributes: vec![] }
+        }
+
+        fn get(&self, key: &str) -> Option<&Value> {
+            self.attributes
+                .iter()
+                .rev()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v)
+        }
+    }
+
+    impl SpanWrite for TestSpan {
+        fn set_attribute(&mut self, key: &'static str, value: impl Into<Value>) {
+            self.attributes.push((key, value.into()));
+        }
+
+        fn set_status(&mut self, _code: Status) {}
+
+        fn span_context(&self) -> SpanContext {
+            SpanContext::empty_context()
+        }
+    }
+
+    const STATEMENT_ID: &str = "d9b6c0c9-0747-4bf4-b142-e8883122f766";
+
+    // Tests for RedshiftDataExtractor — 2 consolidated tests
+
+    #[test]
+    fn extract_execute_statement() {
+        let extractor = RedshiftDataExtractor::new();
+
+        let sdk_input = ExecuteStatementInput::builder()
+            .sql(r#"SELECT * FROM "sales" WHERE region = $$emea$$ AND name = 'O\'Reilly secret' AND year = 2024"#)
+            .database("dev")
+            .workgroup_name("analytics")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "Redshift Data",
+            "ExecuteStatement",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(crate::interceptor::DB_SYSTEM_NAME),
+            Some(&Value::from("aws.redshift"))
+        );
+        assert_eq!(
+            span.get(semco::DB_QUERY_TEXT),
+            Some(&Value::from(
+                "SELECT * FROM \"sales\" WHERE region = ? AND name = ? AND year = ?"
+            ))
+        );
+        assert!(
+            span.attributes
+                .iter()
+                .all(|(_, value)| !value.to_string().contains("secret"))
+        );
+        assert_eq!(span.get(semco::DB_NAMESPACE), Some(&Value::from("dev")));
+        assert_eq!(
+            span.get(AWS_REDSHIFT_DATA_WORKGROUP_NAME),
+            Some(&Value::from("analytics"))
+        );
+        assert_eq!(span.get(AWS_REDSHIFT_DATA_CLUSTER_IDENTIFIER), None);
+
+        extractor.extract_output(
+            "Redshift Data",
+            "ExecuteStatement",
+            &context::Output::erase(ExecuteStatementOutput::builder().id(STATEMENT_ID).build()),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_REDSHIFT_DATA_STATEMENT_ID),
+            Some(&Value::from(STATEMENT_ID))
+        );
+    }
+
+    #[test]
+    fn extract_describe_batch_statement() {
+        let extractor = RedshiftDataExtractor::new();
+
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "Redshift Data",
+            "DescribeStatement",
+            &context::Input::erase(
+                DescribeStatementInput::builder()
+                    .id(STATEMENT_ID)
+                    .build()
+                    .unwrap(),
+            ),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_REDSHIFT_DATA_STATEMENT_ID),
+            Some(&Value::from(STATEMENT_ID))
+        );
+
+        let sdk_output = DescribeStatementOutput::builder()
+            .id(STATEMENT_ID)
+            .database("dev")
+            .cluster_identifier("warehouse")
+            .status(StatusString::Finished)
+            .duration(1_520_000_000)
+            .result_rows(-1)
+            .result_size(-1)
+            .sub_statements(
+                SubStatementData::builder()
+                    .id(format!("{STATEMENT_ID}:1"))
+                    .query_string("DELETE FROM staging WHERE day < '2024-01-01'")
+                    .build()
+                    .unwrap(),
+            )
+            .sub_statements(
+                SubStatementData::builder()
+                    .id(format!("{STATEMENT_ID}:2"))
+                    .query_string("INSERT INTO staging SELECT * FROM raw")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        extractor.extract_output(
+            "Redshift Data",
+            "DescribeStatement",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(semco::DB_QUERY_TEXT),
+            Some(&Value::from(
+                "DELETE FROM staging WHERE day < ?; INSERT INTO staging SELECT * FROM raw"
+            ))
+        );
+        assert_eq!(span.get(semco::DB_NAMESPACE), Some(&Value::from("dev")));
+        assert_eq!(
+            span.get(AWS_REDSHIFT_DATA_CLUSTER_IDENTIFIER),
+            Some(&Value::from("warehouse"))
+        );
+        assert_eq!(
+            span.get(AWS_REDSHIFT_DATA_STATUS),
+            Some(&Value::from("FINISHED"))
+        );
+        assert_eq!(
+            span.get(AWS_REDSHIFT_DATA_EXECUTION_TIME_MS),
+            Some(&Value::I64(1520))
+        );
+        assert_eq!(span.get(AWS_REDSHIFT_DATA_RESULT_ROWS), None);
+        assert_eq!(span.get(AWS_REDSHIFT_DATA_RESULT_SIZE), None);
+    }
+}
@@ -17,29 +17,162 @@
 //! - `aws.s3.upload_id` — set for multipart upload operations
 //! - `aws.s3.part_number` — set for `GetObject`, `HeadObject`, `UploadPart`,
 //!   `UploadPartCopy`
+//! - `aws.s3.delete` — set for `DeleteObjects`, the `Delete` request container
+//!   serialized as `Objects=[{Key=string,VersionId=string},…],Quiet=boolean`.
+//!   At most 100 objects are serialized; longer lists end with `…`
+//!
+//! **`DeleteObjects` counts** (no semconv equivalent):
+//! - `aws.s3.delete_request_count` — number of objects in the request
+//! - `aws.s3.deleted_count`, `aws.s3.delete_error_count` — from the output.
+//!   The deleted count is only set when the response lists deleted keys: a
+//!   `Quiet` request gets none back, so an empty list does not mean nothing
+//!   was deleted
+//! - `aws.s3.delete_error_codes` — the distinct error codes of the failed keys
+//!
+//! `DeleteObjects` reports per-key failures inside a successful response. When
+//! at least one key failed, the span status is set to error.
+//!
+//! **Object metadata** (no semconv equivalent):
+//! - `aws.s3.range` — the requested byte range of `GetObject`
+//! - `aws.s3.content_length` — from the `PutObject` and `UploadPart` inputs, and
+//!   from the `GetObject` and `HeadObject` outputs
+//! - `aws.s3.version_id` — from the `GetObject`, `HeadObject`, `PutObject`,
+//!   `CopyObject` and `CompleteMultipartUpload` outputs
+//! - `aws.s3.etag` — from the `GetObject`, `HeadObject`, `PutObject`,
+//!   `CopyObject`, `UploadPart` and `CompleteMultipartUpload` outputs
+//! - `aws.s3.storage_class` — from the `PutObject` input, and from the
+//!   `GetObject` and `HeadObject` outputs
+//! - `aws.s3.server_side_encryption`, `aws.s3.sse_kms_key_id` — from the
+//!   outputs of the same operations as `aws.s3.etag`
+//! - `aws.s3.checksum_algorithm` — the algorithm of the checksum returned by
+//!   the `GetObject`, `HeadObject`, `PutObject`, `UploadPart` and
+//!   `CompleteMultipartUpload` outputs (`CRC32`, `CRC32C`, `CRC64NVME`, `SHA1`
+//!   or `SHA256`)
+//!
+//! ## Multipart upload correlation
+//!
+//! The spans of a multipart upload only share the `aws.s3.upload_id` attribute.
+//! [`S3Extractor`] remembers the span context of each `CreateMultipartUpload`
+//! span, keyed by the returned upload ID, so that the later `UploadPart`,
+//! `UploadPartCopy`, `CompleteMultipartUpload` and `AbortMultipartUpload` spans
+//! of the same upload get a span link back to it. The `CompleteMultipartUpload`
+//! span also records:
+//!
+//! - `aws.s3.multipart_part_count` — number of parts being assembled
+//! - `aws.s3.multipart_total_bytes` — sum of the part sizes, set only when the
+//!   size of every part was seen by this extractor
+//!
+//! Uploads are forgotten once a completion or abort succeeds; a failed one
+//! keeps the upload tracked so that a retry is still linked. At most 1024
+//! uploads are tracked at a time; beyond that the oldest one is evicted, so an
+//! upload abandoned without being aborted does not leak memory. Correlation only works
+//! within a process that uses the same [`S3Extractor`] for the whole upload.
 
 // S3 attribute extraction — downcasts Input/Output to concrete
 // aws-sdk-s3 types and extracts bucket name, key, etc.
 
 use aws_sdk_s3::operation::{
     abort_multipart_upload::AbortMultipartUploadInput,
-    complete_multipart_upload::CompleteMultipartUploadInput, copy_object::CopyObjectInput,
-    create_bucket::CreateBucketInput, create_multipart_upload::CreateMultipartUploadInput,
-    delete_bucket::DeleteBucketInput, delete_object::DeleteObjectInput,
-    delete_objects::DeleteObjectsInput, get_bucket_location::GetBucketLocationInput,
-    get_bucket_policy::GetBucketPolicyInput, get_object::GetObjectInput,
-    head_bucket::HeadBucketInput, head_object::HeadObjectInput, list_objects::ListObjectsInput,
-    list_objects_v2::ListObjectsV2Input, list_parts::ListPartsInput,
+    complete_multipart_upload::{CompleteMultipartUploadInput, CompleteMultipartUploadOutput},
+    copy_object::{CopyObjectInput, CopyObjectOutput},
+    create_bucket::CreateBucketInput,
+    create_multipart_upload::{CreateMultipartUploadInput, CreateMultipartUploadOutput},
+    delete_bucket::DeleteBucketInput,
+    delete_object::DeleteObjectInput,
+    delete_objects::{DeleteObjectsInput, DeleteObjectsOutput},
+    get_bucket_location::GetBucketLocationInput,
+    get_bucket_policy::GetBucketPolicyInput,
+    get_object::{GetObjectInput, GetObjectOutput},
+    head_bucket::HeadBucketInput,
+    head_object::{HeadObjectInput, HeadObjectOutput},
+    list_objects::ListObjectsInput,
+    list_objects_v2::ListObjectsV2Input,
+    list_parts::ListPartsInput,
     put_bucket_lifecycle_configuration::PutBucketLifecycleConfigurationInput,
-    put_object::PutObjectInput, restore_object::RestoreObjectInput,
-    select_object_content::SelectObjectContentInput, upload_part::UploadPartInput,
+    put_object::{PutObjectInput, PutObjectOutput},
+    restore_object::RestoreObjectInput,
+    select_object_content::SelectObjectContentInput,
+    upload_part::{UploadPartInput, UploadPartOutput},
     upload_part_copy::UploadPartCopyInput,
 };
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::fmt::Write;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use aws_sdk_s3::primitives::ByteStream;
+use aws_sdk_s3::types::{CompletedMultipartUpload, Delete, ServerSideEncryption, StorageClass};
 use aws_smithy_runtime_api::client::interceptors::context;
-use opentelemetry::Value;
+use opentelemetry::{Array, StringValue, Value};
 use opentelemetry_semantic_conventions::attribute as semco;
 
 use super::super::{AttributeExtractor, SpanWrite};
+use super::set_str;
+use crate::span_write::{SpanContext, Status};
+use opentelemetry::trace::SpanId;
+
+/// Maximum number of objects serialized into the `aws.s3.delete` attribute.
+///
+/// A `DeleteObjects` request can carry up to 1000 keys; serializing all of them
+/// would produce attribute values that exporters and backends truncate anyway.
+const MAX_DELETE_OBJECTS: usize = 100;
+
+/// Attribute key for the number of objects in a `DeleteObjects` request.
+const AWS_S3_DELETE_REQUEST_COUNT: &str = "aws.s3.delete_request_count";
+/// Attribute key for the number of objects reported deleted by `DeleteObjects`.
+const AWS_S3_DELETED_COUNT: &str = "aws.s3.deleted_count";
+/// Attribute key for the number of keys `DeleteObjects` failed to delete.
+const AWS_S3_DELETE_ERROR_COUNT: &str = "aws.s3.delete_error_count";
+/// Attribute key for the distinct error codes reported by `DeleteObjects`.
+const AWS_S3_DELETE_ERROR_CODES: &str = "aws.s3.delete_error_codes";
+
+/// Attribute key for the byte range requested by `GetObject`.
+const AWS_S3_RANGE: &str = "aws.s3.range";
+/// Attribute key for the size of the object or part body, in bytes.
+const AWS_S3_CONTENT_LENGTH: &str = "aws.s3.content_length";
+/// Attribute key for the object version ID.
+const AWS_S3_VERSION_ID: &str = "aws.s3.version_id";
+/// Attribute key for the object or part entity tag.
+const AWS_S3_ETAG: &str = "aws.s3.etag";
+/// Attribute key for the object storage class.
+const AWS_S3_STORAGE_CLASS: &str = "aws.s3.storage_class";
+/// Attribute key for the server-side encryption mode (e.g. `aws:kms`).
+const AWS_S3_SERVER_SIDE_ENCRYPTION: &str = "aws.s3.server_side_encryption";
+/// Attribute key for the KMS key used for server-side encryption.
+const AWS_S3_SSE_KMS_KEY_ID: &str = "aws.s3.sse_kms_key_id";
+/// Attribute key for the algorithm of the checksum returned by S3.
+const AWS_S3_CHECKSUM_ALGORITHM: &str = "aws.s3.checksum_algorithm";
+
+/// Attribute key for the number of parts assembled by `CompleteMultipartUpload`.
+const AWS_S3_MULTIPART_PART_COUNT: &str = "aws.s3.multipart_part_count";
+/// Attribute key for the total size of the parts assembled by `CompleteMultipartUpload`.
+const AWS_S3_MULTIPART_TOTAL_BYTES: &str = "aws.s3.multipart_total_bytes";
+
+/// Maximum number of in-flight multipart uploads tracked by [`S3Extractor`].
+const MAX_TRACKED_UPLOADS: usize = 1024;
+
+/// Evaluates to the name of the first checksum present on an S3 output, as an
+/// `Option<&'static str>`.
+///
+/// Every S3 output that carries checksums exposes the same `checksum_*`
+/// accessors but they share no trait, hence the macro.
+macro_rules! checksum_algorithm {
+    ($output:expr) => {{
+        let output = $output;
+        if output.checksum_crc32().is_some() {
+            Some("CRC32")
+        } else if output.checksum_crc32_c().is_some() {
+            Some("CRC32C")
+        } else if output.checksum_crc64_nvme().is_some() {
+            Some("CRC64NVME")
+        } else if output.checksum_sha1().is_some() {
+            Some("SHA1")
+        } else if output.checksum_sha256().is_some() {
+            Some("SHA256")
+        } else {
+            None
+        }
+    }};
+}
 
 /// Attribute extractor for S3 SDK calls.
 ///
@@ -53,7 +186,7 @@ use super::super::{AttributeExtractor, SpanWrite};
 /// [`DefaultExtractor`]: crate::interceptor::DefaultExtractor
 #[derive(Debug, Default)]
 pub struct S3Extractor {
-    _private: (),
+    multipart_uploads: Mutex<MultipartUploads>,
 }
 
 impl S3Extractor {
@@ -67,11 +200,186 @@ impl S3Extractor {
     /// let extractor = S3Extractor::new();
     /// ```
     pub fn new() -> Self {
-        Self { _private: () }
+        Self::default()
+    }
+
+    /// Locks the multipart upload map, recovering it if a previous holder panicked.
+    fn multipart_uploads(&self) -> MutexGuard<'_, MultipartUploads> {
+        self.multipart_uploads
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Links `span` to the `CreateMultipartUpload` span of `upload_id`, if known.
+    fn link_to_upload(&self, span: &mut impl SpanWrite, upload_id: Option<&str>) {
+        let span_context = upload_id.and_then(|upload_id| {
+            self.multipart_uploads()
+                .get_mut(upload_id)
+                .map(|upload| upload.span_context.clone())
+        });
+        if let Some(span_context) = span_context {
+            span.add_link(span_context, vec![]);
+        }
+    }
+
+    /// Links the completion or abort `span` to the `CreateMultipartUpload` span
+    /// of `upload_id` and remembers which upload `span` finishes. Returns the
+    /// part count and total size for a completion listing `completed` parts,
+    /// if the upload is tracked.
+    ///
+    /// The upload stays tracked until [`Self::finish_upload`] is called once
+    /// the call succeeded, so that a failed completion can be retried.
+    fn begin_finish_upload(
+        &self,
+        span: &mut impl SpanWrite,
+        upload_id: Option<&str>,
+        completed: Option<&CompletedMultipartUpload>,
+    ) -> Option<(usize, Option<i64>)> {
+        let upload_id = upload_id?;
+        let finishing_span = span.span_context();
+        let (span_context, totals) = {
+            let mut uploads = self.multipart_uploads();
+            let upload = uploads.get_mut(upload_id)?;
+            let tracked = (upload.span_context.clone(), upload.totals(completed));
+            if finishing_span.is_valid() {
+                uploads.begin_finish(finishing_span.span_id(), upload_id);
+            }
+            tracked
+        };
+        span.add_link(span_context, vec![]);
+        Some(totals)
+    }
+
+    /// Stops tracking the upload finished by `span`, after the call succeeded.
+    fn finish_upload(&self, span: &impl SpanWrite) {
+        let span_id = span.span_context().span_id();
+        let mut uploads = self.multipart_uploads();
+        if let Some(upload_id) = uploads.finishing.remove(&span_id) {
+            uploads.remove(&upload_id);
+        }
+    }
+
+    /// Forgets which upload `span` finishes, after the call failed; the upload
+    /// itself stays tracked.
+    fn abandon_finish_upload(&self, span: &impl SpanWrite) {
+        let span_id = span.span_context().span_id();
+        self.multipart_uploads().finishing.remove(&span_id);
+    }
+
+    /// Records the size of a part of `upload_id`, if the upload is tracked.
+    fn record_part(&self, upload_id: Option<&str>, part_number: Option<i32>, size: Option<i64>) {
+        if let (Some(upload_id), Some(part_number)) = (upload_id, part_number) {
+            if let Some(upload) = self.multipart_uploads().get_mut(upload_id) {
+                upload.part_sizes.insert(part_number, size);
+            }
+        }
+    }
+}
+
+/// Bounded map from multipart upload ID to the state tracked for that upload.
+///
+/// `order` holds the upload IDs from oldest to newest and is used to evict the
+/// oldest upload once [`MAX_TRACKED_UPLOADS`] is reached. Removing an upload
+/// leaves its ID in `order`; stale IDs are skipped on eviction and dropped
+/// when `order` grows to twice the limit, so no operation scans `order` more
+/// than once per [`MAX_TRACKED_UPLOADS`] insertions.
+///
+/// `finishing` maps the span of each in-flight `CompleteMultipartUpload` or
+/// `AbortMultipartUpload` call to the upload it finishes.
+#[derive(Debug, Default)]
+struct MultipartUploads {
+    uploads: HashMap<String, MultipartUpload>,
+    order: VecDeque<String>,
+    finishing: HashMap<SpanId, String>,
+}
+
+/// State tracked for one multipart upload.
+#[derive(Debug)]
+struct MultipartUpload {
+    /// Span context of the `CreateMultipartUpload` span.
+    span_context: SpanContext,
+    /// Size of each uploaded part, by part number; `None` when unknown. A part
+    /// uploaded again replaces the previous entry, like it does in S3.
+    part_sizes: BTreeMap<i32, Option<i64>>,
+}
+
+impl MultipartUploads {
+    /// Starts tracking `upload_id`, evicting the oldest upload if the map is full.
+    fn insert(&mut self, upload_id: String, span_context: SpanContext) {
+        if self.uploads.contains_key(&upload_id) {
+            return;
+        }
+        if self.uploads.len() >= MAX_TRACKED_UPLOADS {
+            while let Some(oldest) = self.order.pop_front() {
+                if self.uploads.remove(&oldest).is_some() {
+                    break;
+                }
+            }
+        }
+        if self.order.len() >= 2 * MAX_TRACKED_UPLOADS {
+            let uploads = &self.uploads;
+            self.order.retain(|tracked| uploads.contains_key(tracked));
+        }
+        self.order.push_back(upload_id.clone());
+        self.uploads.insert(
+            upload_id,
+            MultipartUpload {
+                span_context,
+                part_sizes: BTreeMap::new(),
+            },
+        );
+    }
+
+    fn get_mut(&mut self, upload_id: &str) -> Option<&mut MultipartUpload> {
+        self.uploads.get_mut(upload_id)
+    }
+
+    /// Stops tracking `upload_id` and returns its state, if it was tracked.
+    fn remove(&mut self, upload_id: &str) -> Option<MultipartUpload> {
+        self.uploads.remove(upload_id)
+    }
+
+    /// Records that the call of `span_id` finishes `upload_id`.
+    ///
+    /// Calls failing without a service error never report back, so the map is
+    /// cleared when full; the uploads it referred to are then only evicted.
+    fn begin_finish(&mut self, span_id: SpanId, upload_id: &str) {
+        if self.finishing.len() >= MAX_TRACKED_UPLOADS {
+            self.finishing.clear();
+        }
+        self.finishing.insert(span_id, upload_id.to_owned());
+    }
+}
+
+impl MultipartUpload {
+    /// Returns the number of parts and, when every part size is known, their
+    /// total size for a completion listing `completed` parts.
+    ///
+    /// When the completion request lists no parts, all tracked parts count.
+    fn totals(&self, completed: Option<&CompletedMultipartUpload>) -> (usize, Option<i64>) {
+        let listed = completed
+            .map(|completed| completed.parts())
+            .filter(|parts| !parts.is_empty());
+        match listed {
+            Some(parts) => {
+                let total_bytes = parts
+                    .iter()
+                    .map(|part| {
+                        part.part_number()
+                            .and_then(|number| self.part_sizes.get(&number).copied().flatten())
+                    })
+                    .sum::<Option<i64>>();
+                (parts.len(), total_bytes)
+            }
+            None => (
+                self.part_sizes.len(),
+                self.part_sizes.values().copied().sum::<Option<i64>>(),
+            ),
+        }
     }
 }
 
-/// Extracts S3-specific OTel attributes from SDK inputs.
+/// Extracts S3-specific OTel attributes from SDK inputs and outputs.
 ///
 /// See the [module-level documentation](self) for the full list of extracted
 /// attributes and which operations they apply to.
@@ -91,6 +399,7 @@ impl<SW: SpanWrite> AttributeExtractor<SW> for S3Extractor {
                 set_bucket(span, i.bucket());
                 set_key(span, i.key());
                 set_part_number(span, i.part_number());
+                set_str(span, AWS_S3_RANGE, i.range());
             }
             "PutObject" => {
                 let i = input
@@ -98,6 +407,8 @@ impl<SW: SpanWrite> AttributeExtractor<SW> for S3Extractor {
                     .expect("correct type");
                 set_bucket(span, i.bucket());
                 set_key(span, i.key());
+                set_content_length(span, body_length(i.content_length(), i.body()));
+                set_storage_class(span, i.storage_class());
             }
             "DeleteObject" => {
                 let i = input
@@ -107,15 +418,11 @@ impl<SW: SpanWrite> AttributeExtractor<SW> for S3Extractor {
                 set_key(span, i.key());
             }
             "DeleteObjects" => {
-                // TODO: The OTel semconv defines `aws.s3.delete` for the delete request
-                // container (the `--delete` parameter). The SDK exposes this as a structured
-                // `Delete` type, not a string. Serialising it to the expected string format
-                // (e.g. "Objects=[{Key=string,VersionId=string}],Quiet=boolean") is non-trivial
-                // and low-value. Revisit if users request it.
                 let i = input
                     .downcast_ref::<DeleteObjectsInput>()
                     .expect("correct type");
                 set_bucket(span, i.bucket());
+                set_delete(span, i.delete());
             }
             "HeadObject" => {
                 let i = input
@@ -147,6 +454,14 @@ impl<SW: SpanWrite> AttributeExtractor<SW> for S3Extractor {
                 set_bucket(span, i.bucket());
                 set_key(span, i.key());
                 set_upload_id(span, i.upload_id());
+                if let Some((part_count, total_bytes)) =
+                    self.begin_finish_upload(span, i.upload_id(), i.multipart_upload())
+                {
+                    span.set_attribute(AWS_S3_MULTIPART_PART_COUNT, Value::I64(part_count as i64));
+                    if let Some(total_bytes) = total_bytes {
+                        span.set_attribute(AWS_S3_MULTIPART_TOTAL_BYTES, Value::I64(total_bytes));
+                    }
+                }
             }
             "AbortMultipartUpload" => {
                 let i = input
@@ -155,6 +470,7 @@ impl<SW: SpanWrite> AttributeExtractor<SW> for S3Extractor {
                 set_bucket(span, i.bucket());
                 set_key(span, i.key());
                 set_upload_id(span, i.upload_id());
+                self.begin_finish_upload(span, i.upload_id(), None);
             }
             "UploadPart" => {
                 let i = input
@@ -164,6 +480,10 @@ impl<SW: SpanWrite> AttributeExtractor<SW> for S3Extractor {
                 set_key(span, i.key());
                 set_upload_id(span, i.upload_id());
                 set_part_number(span, i.part_number());
+                let content_length = body_length(i.content_length(), i.body());
+                set_content_length(span, content_length);
+                self.link_to_upload(span, i.upload_id());
+                self.record_part(i.upload_id(), i.part_number(), content_length);
             }
             "UploadPartCopy" => {
                 let i = input
@@ -174,6 +494,9 @@ impl<SW: SpanWrite> AttributeExtractor<SW> for S3Extractor {
                 set_copy_source(span, i.copy_source());
                 set_upload_id(span, i.upload_id());
                 set_part_number(span, i.part_number());
+                self.link_to_upload(span, i.upload_id());
+                // The size of a copied part is not known from the request
+                self.record_part(i.upload_id(), i.part_number(), None);
             }
             "ListParts" => {
                 let i = input
@@ -249,6 +572,143 @@ impl<SW: SpanWrite> AttributeExtractor<SW> for S3Extractor {
             _ => {}
         };
     }
+
+    fn extract_output(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        output: &context::Output,
+        span: &mut SW,
+    ) {
+        match operation {
+            "GetObject" => {
+                let o = output
+                    .downcast_ref::<GetObjectOutput>()
+                    .expect("correct type");
+                set_content_length(span, o.content_length());
+                set_str(span, AWS_S3_VERSION_ID, o.version_id());
+                set_str(span, AWS_S3_ETAG, o.e_tag());
+                set_storage_class(span, o.storage_class());
+                set_encryption(span, o.server_side_encryption(), o.ssekms_key_id());
+                set_str(span, AWS_S3_CHECKSUM_ALGORITHM, checksum_algorithm!(o));
+            }
+            "HeadObject" => {
+                let o = output
+                    .downcast_ref::<HeadObjectOutput>()
+                    .expect("correct type");
+                set_content_length(span, o.content_length());
+                set_str(span, AWS_S3_VERSION_ID, o.version_id());
+                set_str(span, AWS_S3_ETAG, o.e_tag());
+                set_storage_class(span, o.storage_class());
+                set_encryption(span, o.server_side_encryption(), o.ssekms_key_id());
+                set_str(span, AWS_S3_CHECKSUM_ALGORITHM, checksum_algorithm!(o));
+            }
+            "PutObject" => {
+                let o = output
+                    .downcast_ref::<PutObjectOutput>()
+                    .expect("correct type");
+                set_str(span, AWS_S3_VERSION_ID, o.version_id());
+                set_str(span, AWS_S3_ETAG, o.e_tag());
+                set_encryption(span, o.server_side_encryption(), o.ssekms_key_id());
+                set_str(span, AWS_S3_CHECKSUM_ALGORITHM, checksum_algorithm!(o));
+            }
+            "CopyObject" => {
+                let o = output
+                    .downcast_ref::<CopyObjectOutput>()
+                    .expect("correct type");
+                set_str(span, AWS_S3_VERSION_ID, o.version_id());
+                set_str(
+                    span,
+                    AWS_S3_ETAG,
+                    o.copy_object_result().and_then(|r| r.e_tag()),
+                );
+                set_encryption(span, o.server_side_encryption(), o.ssekms_key_id());
+            }
+            "UploadPart" => {
+                let o = output
+                    .downcast_ref::<UploadPartOutput>()
+                    .expect("correct type");
+                set_str(span, AWS_S3_ETAG, o.e_tag());
+                set_encryption(span, o.server_side_encryption(), o.ssekms_key_id());
+                set_str(span, AWS_S3_CHECKSUM_ALGORITHM, checksum_algorithm!(o));
+            }
+            "CompleteMultipartUpload" => {
+                let o = output
+                    .downcast_ref::<CompleteMultipartUploadOutput>()
+                    .expect("correct type");
+                set_str(span, AWS_S3_VERSION_ID, o.version_id());
+                set_str(span, AWS_S3_ETAG, o.e_tag());
+                set_encryption(span, o.server_side_encryption(), o.ssekms_key_id());
+                set_str(span, AWS_S3_CHECKSUM_ALGORITHM, checksum_algorithm!(o));
+                self.finish_upload(span);
+            }
+            "AbortMultipartUpload" => self.finish_upload(span),
+            "CreateMultipartUpload" => {
+                let o = output
+                    .downcast_ref::<CreateMultipartUploadOutput>()
+                    .expect("correct type");
+                let span_context = span.span_context();
+                if let (Some(upload_id), true) = (o.upload_id(), span_context.is_valid()) {
+                    self.multipart_uploads()
+                        .insert(upload_id.to_owned(), span_context);
+                }
+            }
+            "DeleteObjects" => extract_delete_objects_output(output, span),
+            // Do nothing for other operations
+            _ => {}
+        }
+    }
+
+    fn extract_error(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        _error: &context::Error,
+        span: &mut SW,
+    ) {
+        if matches!(
+            operation,
+            "CompleteMultipartUpload" | "AbortMultipartUpload"
+        ) {
+            self.abandon_finish_upload(span);
+        }
+    }
+}
+
+/// Extracts output attributes for the `DeleteObjects` operation and sets an
+/// error status when some keys could not be deleted.
+fn extract_delete_objects_output(output: &context::Output, span: &mut impl SpanWrite) {
+    let o = output
+        .downcast_ref::<DeleteObjectsOutput>()
+        .expect("correct type");
+    // Quiet-mode responses only list the failed keys
+    if !o.deleted().is_empty() {
+        span.set_attribute(AWS_S3_DELETED_COUNT, Value::I64(o.deleted().len() as i64));
+    }
+    span.set_attribute(
+        AWS_S3_DELETE_ERROR_COUNT,
+        Value::I64(o.errors().len() as i64),
+    );
+    if !o.errors().is_empty() {
+        let codes = o
+            .errors()
+            .iter()
+            .filter_map(|error| error.code())
+            .collect::<BTreeSet<_>>();
+        span.set_attribute(
+            AWS_S3_DELETE_ERROR_CODES,
+            Value::Array(Array::String(
+                codes
+                    .iter()
+                    .map(|code| StringValue::from(code.to_string()))
+                    .collect(),
+            )),
+        );
+        span.set_status(Status::error(format!(
+            "{} object(s) could not be deleted",
+            o.errors().len()
+        )));
+    }
 }
 
 /// Sets the `aws.s3.bucket` attribute if present.
@@ -279,6 +739,85 @@ fn set_upload_id(span: &mut impl SpanWrite, upload_id: Option<&str>) {
     }
 }
 
+/// Sets the `aws.s3.delete` and `aws.s3.delete_request_count` attributes if present.
+fn set_delete(span: &mut impl SpanWrite, delete: Option<&Delete>) {
+    if let Some(delete) = delete {
+        span.set_attribute(
+            AWS_S3_DELETE_REQUEST_COUNT,
+            Value::I64(delete.objects().len() as i64),
+        );
+        span.set_attribute(semco::AWS_S3_DELETE, serialize_delete(delete));
+    }
+}
+
+/// Serializes a `Delete` container into the semconv `aws.s3.delete` format, e.g.
+/// `Objects=[{Key=a.txt,VersionId=1},{Key=b.txt}],Quiet=false`.
+///
+/// Only the first [`MAX_DELETE_OBJECTS`] objects are serialized; the list ends
+/// with `…` when some were left out. `Quiet` is omitted when not set.
+fn serialize_delete(delete: &Delete) -> String {
+    let mut serialized = String::from("Objects=[");
+    for (i, object) in delete.objects().iter().enumerate() {
+        if i > 0 {
+            serialized.push(',');
+        }
+        if i == MAX_DELETE_OBJECTS {
+            serialized.push('…');
+            break;
+        }
+        let _ = write!(serialized, "{{Key={}", object.key());
+        if let Some(version_id) = object.version_id() {
+            let _ = write!(serialized, ",VersionId={version_id}");
+        }
+        serialized.push('}');
+    }
+    serialized.push(']');
+    if let Some(quiet) = delete.quiet() {
+        let _ = write!(serialized, ",Quiet={quiet}");
+    }
+    serialized
+}
+
+/// Returns the body size of an upload request: the explicit `Content-Length`,
+/// or else the body length when the stream knows it exactly.
+fn body_length(content_length: Option<i64>, body: &ByteStream) -> Option<i64> {
+    content_length.or_else(|| match body.size_hint() {
+        (lower, Some(upper)) if lower == upper => i64::try_from(lower).ok(),
+        _ => None,
+    })
+}
+
+/// Sets the `aws.s3.content_length` attribute if present.
+fn set_content_length(span: &mut impl SpanWrite, content_length: Option<i64>) {
+    if let Some(content_length) = content_length {
+        span.set_attribute(AWS_S3_CONTENT_LENGTH, Value::I64(content_length));
+    }
+}
+
+/// Sets the `aws.s3.storage_class` attribute if present.
+fn set_storage_class(span: &mut impl SpanWrite, storage_class: Option<&StorageClass>) {
+    set_str(
+        span,
+        AWS_S3_STORAGE_CLASS,
+        storage_class.map(|s| s.as_str()),
+    );
+}
+
+/// Sets the `aws.s3.server_side_encryption` and `aws.s3.sse_kms_key_id`
+/// attributes if present.
+fn set_encryption(
+    span: &mut impl SpanWrite,
+    server_side_encryption: Option<&ServerSideEncryption>,
+    kms_key_id: Option<&str>,
+) {
+    set_str(
+        span,
+        AWS_S3_SERVER_SIDE_ENCRYPTION,
+        server_side_encryption.map(|s| s.as_str()),
+    );
+    set_str(span, AWS_S3_SSE_KMS_KEY_ID, kms_key_id);
+}
+
 /// Sets the `aws.s3.part_number` attribute if present.
 fn set_part_number(span: &mut impl SpanWrite, part_number: Option<i32>) {
     if let Some(part_number) = part_number {
@@ -297,13 +836,15 @@ mod tests {
     use opentelemetry::Value;
     use opentelemetry_semantic_conventions::attribute as semco;
 
-    use crate::span_write::{SpanWrite, Status};
+    use crate::span_write::{KeyValue, SpanWrite, Status};
 
     // ── TestSpan helper ──────────────────────────────────────────────────────
 
     struct TestSpan {
         attributes: Vec<(&'static str, Value)>,
         status: Option<Status>,
+        links: Vec<SpanContext>,
+        span_context: SpanContext,
     }
 
     impl TestSpan {
@@ -311,9 +852,25 @@ mod tests {
             Self {
                 attributes: vec![],
                 status: None,
+                links: vec![],
+                span_context: SpanContext::empty_context(),
             }
         }
 
+        fn with_span_id(span_id: u64) -> Self {
+            use opentelemetry::trace::{SpanId, TraceFlags, TraceId, TraceState};
+
+            let mut span = Self::new();
+            span.span_context = SpanContext::new(
+                TraceId::from(1u128),
+                SpanId::from(span_id),
+                TraceFlags::SAMPLED,
+                false,
+                TraceState::default(),
+            );
+            span
+        }
+
         fn get(&self, key: &str) -> Option<&Value> {
             self.attributes
                 .iter()
@@ -331,6 +888,14 @@ mod tests {
         fn set_status(&mut self, code: Status) {
             self.status = Some(code);
         }
+
+        fn add_link(&mut self, span_context: SpanContext, _attributes: Vec<KeyValue>) {
+            self.links.push(span_context);
+        }
+
+        fn span_context(&self) -> SpanContext {
+            self.span_context.clone()
+        }
     }
 
     // ── set_bucket ───────────────────────────────────────────────────────────
@@ -425,6 +990,279 @@ mod tests {
         assert!(span.get(semco::AWS_S3_PART_NUMBER).is_none());
     }
 
+    // ── serialize_delete ─────────────────────────────────────────────────────
+
+    #[test]
+    fn serialize_delete_objects_and_quiet() {
+        use aws_sdk_s3::types::ObjectIdentifier;
+
+        let delete = Delete::builder()
+            .objects(
+                ObjectIdentifier::builder()
+                    .key("a.txt")
+                    .version_id("v1")
+                    .build()
+                    .unwrap(),
+            )
+            .objects(ObjectIdentifier::builder().key("b.txt").build().unwrap())
+            .quiet(true)
+            .build()
+            .unwrap();
+        assert_eq!(
+            serialize_delete(&delete),
+            "Objects=[{Key=a.txt,VersionId=v1},{Key=b.txt}],Quiet=true"
+        );
+
+        let empty = Delete::builder().set_objects(Some(vec![])).build().unwrap();
+        assert_eq!(serialize_delete(&empty), "Objects=[]");
+    }
+
+    #[test]
+    fn serialize_delete_caps_object_list() {
+        use aws_sdk_s3::types::ObjectIdentifier;
+
+        let objects = (0..MAX_DELETE_OBJECTS + 5)
+            .map(|i| {
+                ObjectIdentifier::builder()
+                    .key(format!("k{i}"))
+                    .build()
+                    .unwrap()
+            })
+            .collect();
+        let delete = Delete::builder()
+            .set_objects(Some(objects))
+            .build()
+            .unwrap();
+        let serialized = serialize_delete(&delete);
+
+        assert_eq!(serialized.matches("{Key=").count(), MAX_DELETE_OBJECTS);
+        assert!(serialized.ends_with(&format!("{{Key=k{}}},…]", MAX_DELETE_OBJECTS - 1)));
+
+        let mut span = TestSpan::new();
+        set_delete(&mut span, Some(&delete));
+        assert_eq!(
+            span.get(AWS_S3_DELETE_REQUEST_COUNT),
+            Some(&Value::I64((MAX_DELETE_OBJECTS + 5) as i64))
+        );
+        assert_eq!(
+            span.get(semco::AWS_S3_DELETE),
+            Some(&Value::from(serialized))
+        );
+    }
+
+    // ── extract_delete_objects_output ────────────────────────────────────────
+
+    #[test]
+    fn extract_delete_objects_output_partial_failure() {
+        use aws_sdk_s3::types::{DeletedObject, Error};
+
+        let sdk_output = DeleteObjectsOutput::builder()
+            .deleted(DeletedObject::builder().key("a.txt").build())
+            .errors(Error::builder().key("b.txt").code("AccessDenied").build())
+            .errors(Error::builder().key("c.txt").code("AccessDenied").build())
+            .errors(Error::builder().key("d.txt").code("InternalError").build())
+            .build();
+        let output = context::Output::erase(sdk_output);
+        let mut span = TestSpan::new();
+        S3Extractor::new().extract_output("S3", "DeleteObjects", &output, &mut span);
+
+        assert_eq!(span.get(AWS_S3_DELETED_COUNT), Some(&Value::I64(1)));
+        assert_eq!(span.get(AWS_S3_DELETE_ERROR_COUNT), Some(&Value::I64(3)));
+        assert_eq!(
+            span.get(AWS_S3_DELETE_ERROR_CODES),
+            Some(&Value::Array(Array::String(vec![
+                "AccessDenied".into(),
+                "InternalError".into()
+            ])))
+        );
+        assert_eq!(
+            span.status,
+            Some(Status::error("3 object(s) could not be deleted"))
+        );
+    }
+
+    #[test]
+    fn extract_delete_objects_output_all_deleted() {
+        use aws_sdk_s3::types::DeletedObject;
+
+        let sdk_output = DeleteObjectsOutput::builder()
+            .deleted(DeletedObject::builder().key("a.txt").build())
+            .deleted(DeletedObject::builder().key("b.txt").build())
+            .build();
+        let output = context::Output::erase(sdk_output);
+        let mut span = TestSpan::new();
+        S3Extractor::new().extract_output("S3", "DeleteObjects", &output, &mut span);
+
+        assert_eq!(span.get(AWS_S3_DELETED_COUNT), Some(&Value::I64(2)));
+        assert_eq!(span.get(AWS_S3_DELETE_ERROR_COUNT), Some(&Value::I64(0)));
+        assert!(span.get(AWS_S3_DELETE_ERROR_CODES).is_none());
+        assert!(span.status.is_none());
+    }
+
+    #[test]
+    fn extract_delete_objects_output_quiet() {
+        use aws_sdk_s3::types::Error;
+
+        // Quiet mode, every key deleted: the response lists nothing
+        let output = context::Output::erase(DeleteObjectsOutput::builder().build());
+        let mut span = TestSpan::new();
+        S3Extractor::new().extract_output("S3", "DeleteObjects", &output, &mut span);
+
+        assert!(span.get(AWS_S3_DELETED_COUNT).is_none());
+        assert_eq!(span.get(AWS_S3_DELETE_ERROR_COUNT), Some(&Value::I64(0)));
+        assert!(span.status.is_none());
+
+        // Quiet mode with a failure: only the failed key is listed
+        let sdk_output = DeleteObjectsOutput::builder()
+            .errors(Error::builder().key("b.txt").code("AccessDenied").build())
+            .build();
+        let output = context::Output::erase(sdk_output);
+        let mut span = TestSpan::new();
+        S3Extractor::new().extract_output("S3", "DeleteObjects", &output, &mut span);
+
+        assert!(span.get(AWS_S3_DELETED_COUNT).is_none());
+        assert_eq!(span.get(AWS_S3_DELETE_ERROR_COUNT), Some(&Value::I64(1)));
+        assert_eq!(
+            span.status,
+            Some(Status::error("1 object(s) could not be deleted"))
+        );
+    }
+
+    // ── multipart upload correlation ─────────────────────────────────────────
+
+    #[test]
+    fn multipart_upload_spans_link_to_creating_span() {
+        use aws_sdk_s3::types::CompletedPart;
+
+        let extractor = S3Extractor::new();
+
+        let mut create_span = TestSpan::with_span_id(42);
+        let output = context::Output::erase(
+            CreateMultipartUploadOutput::builder()
+                .upload_id("upload-1")
+                .build(),
+        );
+        extractor.extract_output("S3", "CreateMultipartUpload", &output, &mut create_span);
+
+        // Part 1 size from the body, part 2 from the explicit content length,
+        // part 2 uploaded twice: the latest size wins
+        let parts = [
+            (1, None, ByteStream::from_static(b"hello")),
+            (2, Some(3), ByteStream::default()),
+            (2, Some(7), ByteStream::default()),
+        ];
+        for (part_number, content_length, body) in parts {
+            let input = context::Input::erase(
+                UploadPartInput::builder()
+                    .bucket("b")
+                    .key("k")
+                    .upload_id("upload-1")
+                    .part_number(part_number)
+                    .set_content_length(content_length)
+                    .body(body)
+                    .build()
+                    .unwrap(),
+            );
+            let mut span = TestSpan::new();
+            extractor.extract_input("S3", "UploadPart", &input, &mut span);
+            assert_eq!(span.links, vec![create_span.span_context.clone()]);
+        }
+
+        let input = context::Input::erase(
+            CompleteMultipartUploadInput::builder()
+                .bucket("b")
+                .key("k")
+                .upload_id("upload-1")
+                .multipart_upload(
+                    CompletedMultipartUpload::builder()
+                        .parts(CompletedPart::builder().part_number(1).build())
+                        .parts(CompletedPart::builder().part_number(2).build())
+                        .build(),
+                )
+                .build()
+                .unwrap(),
+        );
+        // A failed completion keeps the upload tracked for the retry
+        let mut failed_span = TestSpan::with_span_id(2);
+        extractor.extract_input("S3", "CompleteMultipartUpload", &input, &mut failed_span);
+        let error = context::Error::erase(std::io::Error::other("InternalError"));
+        extractor.extract_error("S3", "CompleteMultipartUpload", &error, &mut failed_span);
+        assert_eq!(failed_span.links, vec![create_span.span_context.clone()]);
+        assert!(extractor.multipart_uploads().finishing.is_empty());
+
+        let mut complete_span = TestSpan::with_span_id(3);
+        extractor.extract_input("S3", "CompleteMultipartUpload", &input, &mut complete_span);
+        assert_eq!(complete_span.links, vec![create_span.span_context.clone()]);
+        assert_eq!(
+            complete_span.get(AWS_S3_MULTIPART_PART_COUNT),
+            Some(&Value::I64(2))
+        );
+        assert_eq!(
+            complete_span.get(AWS_S3_MULTIPART_TOTAL_BYTES),
+            Some(&Value::I64(12))
+        );
+
+        // The upload is forgotten once the completion succeeds
+        let output = context::Output::erase(CompleteMultipartUploadOutput::builder().build());
+        extractor.extract_output("S3", "CompleteMultipartUpload", &output, &mut complete_span);
+        let input = context::Input::erase(
+            AbortMultipartUploadInput::builder()
+                .bucket("b")
+                .key("k")
+                .upload_id("upload-1")
+                .build()
+                .unwrap(),
+        );
+        let mut abort_span = TestSpan::new();
+        extractor.extract_input("S3", "AbortMultipartUpload", &input, &mut abort_span);
+        assert!(abort_span.links.is_empty());
+
+        // Uploads created under an invalid span context are not tracked
+        let output = context::Output::erase(
+            CreateMultipartUploadOutput::builder()
+                .upload_id("upload-2")
+                .build(),
+        );
+        extractor.extract_output("S3", "CreateMultipartUpload", &output, &mut TestSpan::new());
+        assert!(extractor.multipart_uploads().get_mut("upload-2").is_none());
+    }
+
+    #[test]
+    fn multipart_uploads_bounded_and_totals() {
+        let mut uploads = MultipartUploads::default();
+        for i in 0..=MAX_TRACKED_UPLOADS {
+            uploads.insert(format!("upload-{i}"), SpanContext::empty_context());
+        }
+        assert_eq!(uploads.uploads.len(), MAX_TRACKED_UPLOADS);
+        assert!(uploads.get_mut("upload-0").is_none());
+        assert!(uploads.get_mut("upload-1").is_some());
+
+        // Removed uploads are skipped on eviction, and their stale IDs are
+        // eventually dropped from `order`
+        assert!(uploads.remove("upload-1").is_some());
+        assert!(uploads.remove("upload-1").is_none());
+        uploads.insert("upload-a".to_owned(), SpanContext::empty_context());
+        uploads.insert("upload-b".to_owned(), SpanContext::empty_context());
+        assert!(uploads.get_mut("upload-2").is_none());
+        assert!(uploads.get_mut("upload-3").is_some());
+        for i in 0..4 * MAX_TRACKED_UPLOADS {
+            uploads.insert(format!("done-{i}"), SpanContext::empty_context());
+            uploads.remove(&format!("done-{i}"));
+        }
+        assert!(uploads.order.len() <= 2 * MAX_TRACKED_UPLOADS);
+        assert!(uploads.get_mut("upload-b").is_some());
+
+        // A part of unknown size (e.g. UploadPartCopy) leaves the total unknown
+        let upload = uploads
+            .get_mut(&format!("upload-{MAX_TRACKED_UPLOADS}"))
+            .unwrap();
+        upload.part_sizes.insert(1, Some(10));
+        upload.part_sizes.insert(2, None);
+        assert_eq!(upload.totals(None), (2, None));
+        upload.part_sizes.insert(2, Some(5));
+        assert_eq!(upload.totals(None), (2, Some(15)));
+    }
+
     // ── S3Extractor::extract_input dispatch ──────────────────────────────────
 
     #[test]
@@ -462,6 +1300,187 @@ mod tests {
         let mut span2 = TestSpan::new();
         extractor.extract_input("S3", "GetObject", &input_no_part, &mut span2);
         assert!(span2.get(semco::AWS_S3_PART_NUMBER).is_none());
+        assert!(span2.get(AWS_S3_RANGE).is_none());
+    }
+
+    #[test]
+    fn extract_input_object_sizes_and_range() {
+        let extractor = S3Extractor::new();
+
+        let sdk_input = GetObjectInput::builder()
+            .bucket("b")
+            .key("k")
+            .range("bytes=0-1023")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "S3",
+            "GetObject",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_S3_RANGE),
+            Some(&Value::String("bytes=0-1023".into()))
+        );
+
+        let sdk_input = PutObjectInput::builder()
+            .bucket("b")
+            .key("k")
+            .content_length(2048)
+            .storage_class(StorageClass::StandardIa)
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "S3",
+            "PutObject",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(span.get(AWS_S3_CONTENT_LENGTH), Some(&Value::I64(2048)));
+        assert_eq!(
+            span.get(AWS_S3_STORAGE_CLASS),
+            Some(&Value::String("STANDARD_IA".into()))
+        );
+
+        let sdk_input = UploadPartInput::builder()
+            .bucket("b")
+            .key("k")
+            .upload_id("u")
+            .part_number(2)
+            .content_length(5_242_880)
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "S3",
+            "UploadPart",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_S3_CONTENT_LENGTH),
+            Some(&Value::I64(5_242_880))
+        );
+
+        // Without an explicit Content-Length, the size comes from the body
+        let sdk_input = PutObjectInput::builder()
+            .bucket("b")
+            .key("k")
+            .body(ByteStream::from_static(b"hello"))
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "S3",
+            "PutObject",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(span.get(AWS_S3_CONTENT_LENGTH), Some(&Value::I64(5)));
+    }
+
+    // ── S3Extractor::extract_output dispatch ─────────────────────────────────
+
+    #[test]
+    fn extract_output_get_object_metadata() {
+        let sdk_output = GetObjectOutput::builder()
+            .content_length(1024)
+            .version_id("v2")
+            .e_tag("\"9b2cf535f27731c974343645a3985328\"")
+            .storage_class(StorageClass::Standard)
+            .server_side_encryption(ServerSideEncryption::AwsKms)
+            .ssekms_key_id("arn:aws:kms:eu-west-1:123456789012:key/abcd")
+            .checksum_crc32_c("yZRlqg==")
+            .build();
+        let output = context::Output::erase(sdk_output);
+        let mut span = TestSpan::new();
+        S3Extractor::new().extract_output("S3", "GetObject", &output, &mut span);
+
+        assert_eq!(span.get(AWS_S3_CONTENT_LENGTH), Some(&Value::I64(1024)));
+        assert_eq!(
+            span.get(AWS_S3_VERSION_ID),
+            Some(&Value::String("v2".into()))
+        );
+        assert_eq!(
+            span.get(AWS_S3_ETAG),
+            Some(&Value::String(
+                "\"9b2cf535f27731c974343645a3985328\"".into()
+            ))
+        );
+        assert_eq!(
+            span.get(AWS_S3_STORAGE_CLASS),
+            Some(&Value::String("STANDARD".into()))
+        );
+        assert_eq!(
+            span.get(AWS_S3_SERVER_SIDE_ENCRYPTION),
+            Some(&Value::String("aws:kms".into()))
+        );
+        assert_eq!(
+            span.get(AWS_S3_SSE_KMS_KEY_ID),
+            Some(&Value::String(
+                "arn:aws:kms:eu-west-1:123456789012:key/abcd".into()
+            ))
+        );
+        assert_eq!(
+            span.get(AWS_S3_CHECKSUM_ALGORITHM),
+            Some(&Value::String("CRC32C".into()))
+        );
+    }
+
+    #[test]
+    fn extract_output_put_and_copy_object_metadata() {
+        use aws_sdk_s3::types::CopyObjectResult;
+
+        let sdk_output = PutObjectOutput::builder()
+            .e_tag("\"abc\"")
+            .server_side_encryption(ServerSideEncryption::Aes256)
+            .checksum_sha256("n4bQgYhMfWWaL+qgxVrQFaO/TxsrC4Is0V1sFbDwCgg=")
+            .build();
+        let mut span = TestSpan::new();
+        S3Extractor::new().extract_output(
+            "S3",
+            "PutObject",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_S3_ETAG),
+            Some(&Value::String("\"abc\"".into()))
+        );
+        assert_eq!(
+            span.get(AWS_S3_SERVER_SIDE_ENCRYPTION),
+            Some(&Value::String("AES256".into()))
+        );
+        assert_eq!(
+            span.get(AWS_S3_CHECKSUM_ALGORITHM),
+            Some(&Value::String("SHA256".into()))
+        );
+        assert!(span.get(AWS_S3_VERSION_ID).is_none());
+        assert!(span.get(AWS_S3_SSE_KMS_KEY_ID).is_none());
+
+        let sdk_output = CopyObjectOutput::builder()
+            .version_id("v3")
+            .copy_object_result(CopyObjectResult::builder().e_tag("\"def\"").build())
+            .build();
+        let mut span = TestSpan::new();
+        S3Extractor::new().extract_output(
+            "S3",
+            "CopyObject",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_S3_VERSION_ID),
+            Some(&Value::String("v3".into()))
+        );
+        assert_eq!(
+            span.get(AWS_S3_ETAG),
+            Some(&Value::String("\"def\"".into()))
+        );
+        assert!(span.get(AWS_S3_CHECKSUM_ALGORITHM).is_none());
     }
 
     #[test]
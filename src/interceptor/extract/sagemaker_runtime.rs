@@ -0,0 +1,385 @@
+//! SageMaker Runtime attribute extraction.
+//!
+//! This module provides [`SageMakerRuntimeExtractor`], which implements
+//! [`super::super::AttributeExtractor`] for the SageMaker Runtime
+//! `InvokeEndpoint`, `InvokeEndpointAsync` and
+//! `InvokeEndpointWithResponseStream` SDK calls. It is automatically used by
+//! [`super::super::DefaultExtractor`] when the `extract-sagemaker-runtime`
+//! feature is enabled.
+//!
+//! ## Extracted attributes
+//!
+//! **Set from input:**
+//! - `aws.sagemaker.endpoint_name` — the endpoint invoked
+//! - `aws.sagemaker.target_model` — for `InvokeEndpoint`, the model of a
+//!   multi-model endpoint, when given
+//! - `aws.sagemaker.target_variant` — for `InvokeEndpoint` and
+//!   `InvokeEndpointWithResponseStream`, the production variant requested,
+//!   when given
+//! - `aws.sagemaker.target_container_hostname` — for `InvokeEndpoint` and
+//!   `InvokeEndpointWithResponseStream`, the container of a multi-container
+//!   endpoint, when given
+//! - `aws.sagemaker.inference_component_name` — for `InvokeEndpoint` and
+//!   `InvokeEndpointWithResponseStream`, the inference component, when given
+//! - `aws.sagemaker.content_type` — the MIME type of the payload
+//! - `aws.sagemaker.request_size` — for `InvokeEndpoint` and
+//!   `InvokeEndpointWithResponseStream`, the payload size in bytes
+//! - `aws.sagemaker.input_location` — for `InvokeEndpointAsync`, the S3 URI of
+//!   the payload
+//!
+//! **Set from input or output:**
+//! - `aws.sagemaker.inference_id` — the inference ID, given by the caller or,
+//!   for `InvokeEndpointAsync`, returned by the service
+//!
+//! **Set from output:**
+//! - `aws.sagemaker.invoked_production_variant` — for `InvokeEndpoint` and
+//!   `InvokeEndpointWithResponseStream`, the production variant that served
+//!   the request
+//! - `aws.sagemaker.response_size` — for `InvokeEndpoint`, the response size
+//!   in bytes
+//! - `aws.sagemaker.output_location` — for `InvokeEndpointAsync`, the S3 URI
+//!   the response will be written to
+//!
+//! Payloads are never recorded, only their sizes.
+
+// SageMaker Runtime attribute extraction — downcasts Input/Output to concrete
+// aws-sdk-sagemakerruntime types and extracts endpoint, variant and payload
+// size attributes.
+
+use aws_sdk_sagemakerruntime::operation::{
+    invoke_endpoint::{InvokeEndpointInput, InvokeEndpointOutput},
+    invoke_endpoint_async::{InvokeEndpointAsyncInput, InvokeEndpointAsyncOutput},
+    invoke_endpoint_with_response_stream::{
+        InvokeEndpointWithResponseStreamInput, InvokeEndpointWithResponseStreamOutput,
+    },
+};
+use aws_smithy_runtime_api::client::interceptors::context;
+use aws_smithy_types::Blob;
+
+use super::super::{AttributeExtractor, SpanWrite};
+use super::set_str;
+
+/// Attribute key for the endpoint name.
+const AWS_SAGEMAKER_ENDPOINT_NAME: &str = "aws.sagemaker.endpoint_name";
+/// Attribute key for the model targeted on a multi-model endpoint.
+const AWS_SAGEMAKER_TARGET_MODEL: &str = "aws.sagemaker.target_model";
+/// Attribute key for the production variant requested.
+const AWS_SAGEMAKER_TARGET_VARIANT: &str = "aws.sagemaker.target_variant";
+/// Attribute key for the container targeted on a multi-container endpoint.
+const AWS_SAGEMAKER_TARGET_CONTAINER_HOSTNAME: &str = "aws.sagemaker.target_container_hostname";
+/// Attribute key for the inference component name.
+const AWS_SAGEMAKER_INFERENCE_COMPONENT_NAME: &str = "aws.sagemaker.inference_component_name";
+/// Attribute key for the MIME type of the payload.
+const AWS_SAGEMAKER_CONTENT_TYPE: &str = "aws.sagemaker.content_type";
+/// Attribute key for the payload size in bytes.
+const AWS_SAGEMAKER_REQUEST_SIZE: &str = "aws.sagemaker.request_size";
+/// Attribute key for the S3 URI of an asynchronous payload.
+const AWS_SAGEMAKER_INPUT_LOCATION: &str = "aws.sagemaker.input_location";
+/// Attribute key for the inference ID.
+const AWS_SAGEMAKER_INFERENCE_ID: &str = "aws.sagemaker.inference_id";
+/// Attribute key for the production variant that served the request.
+const AWS_SAGEMAKER_INVOKED_PRODUCTION_VARIANT: &str = "aws.sagemaker.invoked_production_variant";
+/// Attribute key for the response size in bytes.
+const AWS_SAGEMAKER_RESPONSE_SIZE: &str = "aws.sagemaker.response_size";
+/// Attribute key for the S3 URI of an asynchronous response.
+const AWS_SAGEMAKER_OUTPUT_LOCATION: &str = "aws.sagemaker.output_location";
+
+/// Attribute extractor for SageMaker Runtime SDK calls.
+///
+/// `SageMakerRuntimeExtractor` implements [`AttributeExtractor`] and is
+/// automatically used by [`DefaultExtractor`] when the
+/// `extract-sagemaker-runtime` feature is enabled. You only need to construct
+/// it directly if you are composing a custom extraction pipeline.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes.
+///
+/// [`DefaultExtractor`]: crate::interceptor::DefaultExtractor
+#[derive(Debug, Default)]
+pub struct SageMakerRuntimeExtractor {
+    _private: (),
+}
+
+impl SageMakerRuntimeExtractor {
+    /// Creates a new `SageMakerRuntimeExtractor`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::extract::sagemaker_runtime::SageMakerRuntimeExtractor;
+    ///
+    /// let extractor = SageMakerRuntimeExtractor::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+/// Extracts SageMaker Runtime OTel attributes from SDK inputs and outputs.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes and which operations they apply to.
+impl<SW: SpanWrite> AttributeExtractor<SW> for SageMakerRuntimeExtractor {
+    fn extract_input(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        input: &context::Input,
+        span: &mut SW,
+    ) {
+        match operation {
+            "InvokeEndpoint" => {
+                let i = input
+                    .downcast_ref::<InvokeEndpointInput>()
+                    .expect("correct type");
+                set_str(span, AWS_SAGEMAKER_ENDPOINT_NAME, i.endpoint_name());
+                set_str(span, AWS_SAGEMAKER_TARGET_MODEL, i.target_model());
+                set_target(
+                    span,
+                    i.target_variant(),
+                    i.target_container_hostname(),
+                    i.inference_component_name(),
+                );
+                set_payload(span, i.content_type(), i.body());
+                set_str(span, AWS_SAGEMAKER_INFERENCE_ID, i.inference_id());
+            }
+            "InvokeEndpointAsync" => {
+                let i = input
+                    .downcast_ref::<InvokeEndpointAsyncInput>()
+                    .expect("correct type");
+                set_str(span, AWS_SAGEMAKER_ENDPOINT_NAME, i.endpoint_name());
+                set_str(span, AWS_SAGEMAKER_CONTENT_TYPE, i.content_type());
+                set_str(span, AWS_SAGEMAKER_INPUT_LOCATION, i.input_location());
+                set_str(span, AWS_SAGEMAKER_INFERENCE_ID, i.inference_id());
+            }
+            "InvokeEndpointWithResponseStream" => {
+                let i = input
+                    .downcast_ref::<InvokeEndpointWithResponseStreamInput>()
+                    .expect("correct type");
+                set_str(span, AWS_SAGEMAKER_ENDPOINT_NAME, i.endpoint_name());
+                set_target(
+                    span,
+                    i.target_variant(),
+                    i.target_container_hostname(),
+                    i.inference_component_name(),
+                );
+                set_payload(span, i.content_type(), i.body());
+                set_str(span, AWS_SAGEMAKER_INFERENCE_ID, i.inference_id());
+            }
+            _ => {}
+        }
+    }
+
+    fn extract_output(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        output: &context::Output,
+        span: &mut SW,
+    ) {
+        match operation {
+            "InvokeEndpoint" => {
+                let o = output
+                    .downcast_ref::<InvokeEndpointOutput>()
+                    .expect("correct type");
+                set_str(
+                    span,
+                    AWS_SAGEMAKER_INVOKED_PRODUCTION_VARIANT,
+                    o.invoked_production_variant(),
+                );
+                if let Some(body) = o.body() {
+                    span.set_attribute(AWS_SAGEMAKER_RESPONSE_SIZE, body.as_ref().len() as i64);
+                }
+            }
+            "InvokeEndpointAsync" => {
+                let o = output
+                    .downcast_ref::<InvokeEndpointAsyncOutput>()
+                    .expect("correct type");
+                set_str(span, AWS_SAGEMAKER_INFERENCE_ID, o.inference_id());
+                set_str(span, AWS_SAGEMAKER_OUTPUT_LOCATION, o.output_location());
+            }
+            "InvokeEndpointWithResponseStream" => {
+                let o = output
+                    .downcast_ref::<InvokeEndpointWithResponseStreamOutput>()
+                    .expect("correct type");
+                set_str(
+                    span,
+                    AWS_SAGEMAKER_INVOKED_PRODUCTION_VARIANT,
+                    o.invoked_production_variant(),
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Sets the variant, container and inference component targeted by a
+/// synchronous invocation.
+fn set_target(
+    span: &mut impl SpanWrite,
+    target_variant: Option<&str>,
+    target_container_hostname: Option<&str>,
+    inference_component_name: Option<&str>,
+) {
+    set_str(span, AWS_SAGEMAKER_TARGET_VARIANT, target_variant);
+    set_str(
+        span,
+        AWS_SAGEMAKER_TARGET_CONTAINER_HOSTNAME,
+        target_container_hostname,
+    );
+    set_str(
+        span,
+        AWS_SAGEMAKER_INFERENCE_COMPONENT_NAME,
+        inference_component_name,
+    );
+}
+
+/// Sets the content type and size of an inline payload.
+fn set_payload(span: &mut impl SpanWrite, content_type: Option<&str>, body: Option<&Blob>) {
+    set_str(span, AWS_SAGEMAKER_CONTENT_TYPE, content_type);
+    if let Some(body) = body {
+        span.set_attribute(AWS_SAGEMAKER_REQUEST_SIZE, body.as_ref().len() as i64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::Value;
+
+    use crate::span_write::{SpanContext, SpanWrite, Status};
+
+    struct TestSpan {
+        attributes: Vec<(&'static str, Value)>,
+    }
+
+    impl TestSpan {
+        fn new() -> Self {
+            Self { attributes: vec![] }
+        }
+
+        fn get(&self, key: &str) -> Option<&Value> {
+            self.attributes
+                .iter()
+                .rev()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v)
+        }
+    }
+
+    impl SpanWrite for TestSpan {
+        fn set_attribute(&mut self, key: &'static str, value: impl Into<Value>) {
+            self.attributes.push((key, value.into()));
+        }
+
+        fn set_status(&mut self, _code: Status) {}
+
+        fn span_context(&self) -> SpanContext {
+            SpanContext::empty_context()
+        }
+    }
+
+    // Tests for SageMakerRuntimeExtractor — 2 consolidated tests
+
+    #[test]
+    fn extract_invoke_endpoint() {
+        let extractor = SageMakerRuntimeExtractor::new();
+
+        let sdk_input = InvokeEndpointInput::builder()
+            .endpoint_name("recommender")
+            .target_model("model-a.tar.gz")
+            .target_variant("variant-b")
+            .content_type("application/json")
+            .body(Blob::new(r#"{"user":"u-1"}"#))
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "SageMaker Runtime",
+            "InvokeEndpoint",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_SAGEMAKER_ENDPOINT_NAME),
+            Some(&Value::from("recommender"))
+        );
+        assert_eq!(
+            span.get(AWS_SAGEMAKER_TARGET_MODEL),
+            Some(&Value::from("model-a.tar.gz"))
+        );
+        assert_eq!(
+            span.get(AWS_SAGEMAKER_TARGET_VARIANT),
+            Some(&Value::from("variant-b"))
+        );
+        assert_eq!(span.get(AWS_SAGEMAKER_TARGET_CONTAINER_HOSTNAME), None);
+        assert_eq!(
+            span.get(AWS_SAGEMAKER_CONTENT_TYPE),
+            Some(&Value::from("application/json"))
+        );
+        assert_eq!(span.get(AWS_SAGEMAKER_REQUEST_SIZE), Some(&Value::I64(14)));
+
+        let sdk_output = InvokeEndpointOutput::builder()
+            .body(Blob::new(r#"{"items":[1,2,3]}"#))
+            .invoked_production_variant("variant-b")
+            .build();
+        extractor.extract_output(
+            "SageMaker Runtime",
+            "InvokeEndpoint",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_SAGEMAKER_INVOKED_PRODUCTION_VARIANT),
+            Some(&Value::from("variant-b"))
+        );
+        assert_eq!(span.get(AWS_SAGEMAKER_RESPONSE_SIZE), Some(&Value::I64(17)));
+    }
+
+    #[test]
+    fn extract_invoke_endpoint_async() {
+        let extractor = SageMakerRuntimeExtractor::new();
+
+        let sdk_input = InvokeEndpointAsyncInput::builder()
+            .endpoint_name("batch-scorer")
+            .input_location("s3://bucket/in/payload.json")
+            .content_type("application/json")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "SageMaker Runtime",
+            "InvokeEndpointAsync",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_SAGEMAKER_ENDPOINT_NAME),
+            Some(&Value::from("batch-scorer"))
+        );
+        assert_eq!(
+            span.get(AWS_SAGEMAKER_INPUT_LOCATION),
+            Some(&Value::from("s3://bucket/in/payload.json"))
+        );
+        assert_eq!(span.get(AWS_SAGEMAKER_INFERENCE_ID), None);
+
+        let sdk_output = InvokeEndpointAsyncOutput::builder()
+            .inference_id("inf-123")
+            .output_location("s3://bucket/out/inf-123.out")
+            .build();
+        extractor.extract_output(
+            "SageMaker Runtime",
+            "InvokeEndpointAsync",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_SAGEMAKER_INFERENCE_ID),
+            Some(&Value::from("inf-123"))
+        );
+        assert_eq!(
+            span.get(AWS_SAGEMAKER_OUTPUT_LOCATION),
+            Some(&Value::from("s3://bucket/out/inf-123.out"))
+        );
+    }
+}
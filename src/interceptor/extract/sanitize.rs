@@ -0,0 +1,267 @@
+//! Query-text sanitization shared by the database-like extractors.
+//!
+//! The OTel database semconv only allows `db.query.text` to be collected by
+//! default when literal values have been redacted. [`sanitize_literals`]
+//! replaces every string and numeric literal with a `?` placeholder while
+//! keeping identifiers, keywords and existing placeholders untouched.
+
+/// Replaces string and numeric literals in `text` with `?`.
+///
+/// - Characters in `string_quotes` open a string literal. The whole literal,
+///   quotes included, is replaced by a single `?`. A doubled quote (`''`)
+///   inside the literal does not terminate it, nor does a backslash-escaped
+///   quote when `backslash_escapes` is `true` (MySQL, Cypher, Gremlin). Leave
+///   it `false` for dialects where a backslash is an ordinary character
+///   (standard SQL, PartiQL), so that `'C:\'` ends at its closing quote.
+/// - When `postgres_strings` is `true` (PostgreSQL, Redshift), two more string
+///   forms are redacted: `E'...'` escape strings, in which a backslash always
+///   escapes, and dollar-quoted strings opened by a `$$` or `$tag$` delimiter
+///   and running up to the same delimiter. Positional parameters such as `$1`
+///   are not delimiters and are kept.
+/// - Characters in `identifier_quotes` open a quoted identifier, which is
+///   copied verbatim (e.g. `"my-table"` in PartiQL or `` `label` `` in Cypher).
+/// - Numeric literals (`42`, `3.14`, `1e-5`, and the `0x1F` and `0b101`
+///   forms of MySQL) that are not part of an identifier are replaced by `?`.
+///
+/// An unterminated literal is redacted up to the end of the text.
+pub(super) fn sanitize_literals(
+    text: &str,
+    string_quotes: &[char],
+    identifier_quotes: &[char],
+    backslash_escapes: bool,
+    postgres_strings: bool,
+) -> String {
+    let mut sanitized = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    // Whether the previous character can be part of an identifier, in which
+    // case a digit is not the start of a numeric literal (e.g. `table2`).
+    let mut in_word = false;
+
+    while let Some(c) = chars.next() {
+        if string_quotes.contains(&c) {
+            skip_quoted(&mut chars, c, backslash_escapes);
+            sanitized.push('?');
+            in_word = false;
+        } else if postgres_strings
+            && matches!(c, 'E' | 'e')
+            && !in_word
+            && chars.peek() == Some(&'\'')
+        {
+            chars.next();
+            skip_quoted(&mut chars, '\'', true);
+            sanitized.push('?');
+            in_word = false;
+        } else if postgres_strings && c == '$' && !in_word && skip_dollar_quoted(&mut chars) {
+            sanitized.push('?');
+            in_word = false;
+        } else if identifier_quotes.contains(&c) {
+            sanitized.push(c);
+            sanitized.push_str(&skip_quoted(&mut chars, c, false));
+            in_word = false;
+        } else if c.is_ascii_digit() && !in_word {
+            skip_number(&mut chars, c);
+            sanitized.push('?');
+            in_word = false;
+        } else {
+            sanitized.push(c);
+            in_word = c.is_alphanumeric() || c == '_' || c == '$';
+        }
+    }
+
+    sanitized
+}
+
+/// Consumes a quoted section whose opening `quote` has already been read, and
+/// returns the consumed characters (closing quote included).
+///
+/// When `backslash_escapes` is `true`, a backslash escapes the next character.
+/// A doubled `quote` is always treated as an escaped quote.
+fn skip_quoted(
+    chars: &mut std::iter::Peekable<std::str::Chars<'_>>,
+    quote: char,
+    backslash_escapes: bool,
+) -> String {
+    let mut consumed = String::new();
+    while let Some(c) = chars.next() {
+        consumed.push(c);
+        if backslash_escapes && c == '\\' {
+            if let Some(escaped) = chars.next() {
+                consumed.push(escaped);
+            }
+        } else if c == quote {
+            if chars.peek() == Some(&quote) {
+                consumed.push(quote);
+                chars.next();
+            } else {
+                break;
+            }
+        }
+    }
+    consumed
+}
+
+/// Consumes a dollar-quoted section whose opening `$` has already been read,
+/// closing delimiter included, and returns `true`. Returns `false` and
+/// consumes nothing when the `$` does not open a `$$` or `$tag$` delimiter.
+fn skip_dollar_quoted(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> bool {
+    let mut lookahead = chars.clone();
+    let mut delimiter = String::from("$");
+    loop {
+        match lookahead.next() {
+            Some('$') => break,
+            Some(c)
+                if c.is_alphabetic() || c == '_' || (c.is_ascii_digit() && delimiter.len() > 1) =>
+            {
+                delimiter.push(c);
+            }
+            _ => return false,
+        }
+    }
+    delimiter.push('$');
+    *chars = lookahead;
+
+    let mut body = String::new();
+    for c in chars.by_ref() {
+        body.push(c);
+        if body.ends_with(&delimiter) {
+            break;
+        }
+    }
+    true
+}
+
+/// Consumes the remainder of a numeric literal whose `first` digit has already
+/// been read: digits, an optional fractional part and an optional exponent, or
+/// the hex or binary digits of a `0x`/`0b` literal.
+fn skip_number(chars: &mut std::iter::Peekable<std::str::Chars<'_>>, first: char) {
+    if first == '0' {
+        let mut lookahead = chars.clone();
+        if matches!(lookahead.next(), Some('x' | 'X' | 'b' | 'B'))
+            && lookahead.peek().is_some_and(char::is_ascii_hexdigit)
+        {
+            while lookahead.next_if(char::is_ascii_hexdigit).is_some() {}
+            *chars = lookahead;
+            return;
+        }
+    }
+    let mut previous = first;
+    while let Some(&c) = chars.peek() {
+        let is_exponent_sign = (c == '+' || c == '-') && matches!(previous, 'e' | 'E');
+        if c.is_ascii_digit() || c == '.' || c == 'e' || c == 'E' || is_exponent_sign {
+            previous = c;
+            chars.next();
+        } else {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanitize_literals_redacts_strings_and_numbers() {
+        // PartiQL: single-quoted strings, double-quoted identifiers
+        assert_eq!(
+            sanitize_literals(
+                r#"SELECT * FROM "orders-2024" WHERE pk = 'it''s' AND n > 3.5e-2 AND c = ?"#,
+                &['\''],
+                &['"'],
+                false,
+                false,
+            ),
+            r#"SELECT * FROM "orders-2024" WHERE pk = ? AND n > ? AND c = ?"#
+        );
+
+        // Cypher: both quote styles are strings, backticks are identifiers
+        assert_eq!(
+            sanitize_literals(
+                r#"MATCH (n:`Person 1`) WHERE n.name = "a\"b" OR n.alias = 'x' RETURN n LIMIT 10"#,
+                &['\'', '"'],
+                &['`'],
+                true,
+                false,
+            ),
+            "MATCH (n:`Person 1`) WHERE n.name = ? OR n.alias = ? RETURN n LIMIT ?"
+        );
+
+        // Without backslash escapes, a trailing backslash ends nothing
+        assert_eq!(
+            sanitize_literals(
+                r"SELECT * FROM files WHERE path = 'C:\' AND size > 10",
+                &['\''],
+                &['"'],
+                false,
+                false,
+            ),
+            "SELECT * FROM files WHERE path = ? AND size > ?"
+        );
+    }
+
+    #[test]
+    fn sanitize_literals_keeps_identifiers_and_handles_unterminated() {
+        // Digits that are part of an identifier are kept
+        assert_eq!(
+            sanitize_literals(
+                "SELECT col1 FROM table_2 WHERE x = $1",
+                &['\''],
+                &['"'],
+                false,
+                false
+            ),
+            "SELECT col1 FROM table_2 WHERE x = $1"
+        );
+
+        // Hex and binary literals are redacted whole, `0x` alone is not one
+        assert_eq!(
+            sanitize_literals(
+                "SELECT * FROM t WHERE k = 0x1F2E OR f = 0B101 OR n = 0x",
+                &['\'', '"'],
+                &['`'],
+                true,
+                false
+            ),
+            "SELECT * FROM t WHERE k = ? OR f = ? OR n = ?x"
+        );
+
+        // Unterminated string literal is redacted up to the end
+        assert_eq!(
+            sanitize_literals("WHERE pk = 'oops", &['\''], &['"'], false, false),
+            "WHERE pk = ?"
+        );
+    }
+
+    #[test]
+    fn sanitize_literals_redacts_postgres_strings() {
+        let sanitized = sanitize_literals(
+            "SELECT $$top secret$$, $body$it's $$ nested$body$, $1, a$b$ FROM t WHERE x = $tag$oops",
+            &['\''],
+            &['"'],
+            false,
+            true,
+        );
+        assert_eq!(sanitized, "SELECT ?, ?, $1, a$b$ FROM t WHERE x = ?");
+        assert!(!sanitized.contains("secret"));
+
+        // Escape strings end at their closing quote, not at `\'`
+        let sanitized = sanitize_literals(
+            r"SELECT * FROM t WHERE a = E'O\'Reilly secret' AND b = e'\\' AND type = 'x'",
+            &['\''],
+            &['"'],
+            false,
+            true,
+        );
+        assert_eq!(
+            sanitized,
+            "SELECT * FROM t WHERE a = ? AND b = ? AND type = ?"
+        );
+        assert!(!sanitized.contains("secret"));
+
+        // Without PostgreSQL strings, `$` is an ordinary identifier character
+        assert_eq!(
+            sanitize_literals("SELECT $$x$$", &['\''], &['"'], false, false),
+            "SELECT $$x$$"
+        );
+    }
+}
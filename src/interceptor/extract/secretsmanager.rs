@@ -0,0 +1,458 @@
+//! Secrets Manager attribute extraction.
+//!
+//! This module provides [`SecretsManagerExtractor`], which implements
+//! [`super::super::AttributeExtractor`] for the Secrets Manager
+//! `GetSecretValue` and `BatchGetSecretValue` SDK calls. It is automatically
+//! used by [`super::super::DefaultExtractor`] when the `extract-secretsmanager`
+//! feature is enabled.
+//!
+//! ## Extracted attributes
+//!
+//! **Set from `GetSecretValue`:**
+//! - `aws.secretsmanager.secret_id` — the secret name or ARN requested
+//! - `aws.secretsmanager.version_stage`, `aws.secretsmanager.version_id` — the
+//!   version requested, when given
+//! - `aws.secretsmanager.secret_arn` — from the output, the ARN of the secret
+//! - `aws.secretsmanager.version_id`, `aws.secretsmanager.version_stages` —
+//!   from the output, the version returned and its staging labels
+//!
+//! **Set from `BatchGetSecretValue`:**
+//! - `aws.secretsmanager.secret_ids` — the secret names or ARNs requested, when
+//!   the call lists them rather than using filters
+//! - `aws.secretsmanager.returned_count` — from the output, the number of
+//!   secret values returned
+//! - `aws.secretsmanager.error_count`, `aws.secretsmanager.error_codes` — from
+//!   the output, the number of secrets that could not be retrieved and the
+//!   distinct error codes
+//!
+//! `BatchGetSecretValue` reports per-secret failures inside a successful
+//! response. When at least one secret could not be retrieved, the span status
+//! is set to error.
+//!
+//! ## Secret values
+//!
+//! Secret values are never recorded. Outputs are only read through the
+//! private `metadata` views, which destructure the identifier and version
+//! fields of the SDK output and never name a secret value field; the
+//! extractor itself never holds an SDK output. The crate's `clippy.toml`
+//! additionally lists the secret value accessors under `disallowed-methods`
+//! as a backstop.
+
+// Secrets Manager attribute extraction — downcasts Input/Output to concrete
+// aws-sdk-secretsmanager types and extracts secret identifiers and versions.
+
+use std::collections::BTreeSet;
+
+use aws_sdk_secretsmanager::operation::{
+    batch_get_secret_value::BatchGetSecretValueInput, get_secret_value::GetSecretValueInput,
+};
+use aws_smithy_runtime_api::client::interceptors::context;
+use opentelemetry::{Array, StringValue, Value};
+
+use super::super::{AttributeExtractor, SpanWrite};
+use super::{set_str, set_strings};
+use crate::span_write::Status;
+
+/// Attribute key for the secret name or ARN requested by `GetSecretValue`.
+const AWS_SECRETSMANAGER_SECRET_ID: &str = "aws.secretsmanager.secret_id";
+/// Attribute key for the secret names or ARNs requested by `BatchGetSecretValue`.
+const AWS_SECRETSMANAGER_SECRET_IDS: &str = "aws.secretsmanager.secret_ids";
+/// Attribute key for the ARN of the secret returned.
+const AWS_SECRETSMANAGER_SECRET_ARN: &str = "aws.secretsmanager.secret_arn";
+/// Attribute key for the version stage requested.
+const AWS_SECRETSMANAGER_VERSION_STAGE: &str = "aws.secretsmanager.version_stage";
+/// Attribute key for the secret version ID.
+const AWS_SECRETSMANAGER_VERSION_ID: &str = "aws.secretsmanager.version_id";
+/// Attribute key for the staging labels of the secret version returned.
+const AWS_SECRETSMANAGER_VERSION_STAGES: &str = "aws.secretsmanager.version_stages";
+/// Attribute key for the number of secret values returned by `BatchGetSecretValue`.
+const AWS_SECRETSMANAGER_RETURNED_COUNT: &str = "aws.secretsmanager.returned_count";
+/// Attribute key for the number of secrets `BatchGetSecretValue` failed to retrieve.
+const AWS_SECRETSMANAGER_ERROR_COUNT: &str = "aws.secretsmanager.error_count";
+/// Attribute key for the distinct error codes of `BatchGetSecretValue`.
+const AWS_SECRETSMANAGER_ERROR_CODES: &str = "aws.secretsmanager.error_codes";
+
+/// Attribute extractor for Secrets Manager SDK calls.
+///
+/// `SecretsManagerExtractor` implements [`AttributeExtractor`] and is
+/// automatically used by [`DefaultExtractor`] when the `extract-secretsmanager`
+/// feature is enabled. You only need to construct it directly if you are
+/// composing a custom extraction pipeline.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes.
+///
+/// [`DefaultExtractor`]: crate::interceptor::DefaultExtractor
+#[derive(Debug, Default)]
+pub struct SecretsManagerExtractor {
+    _private: (),
+}
+
+impl SecretsManagerExtractor {
+    /// Creates a new `SecretsManagerExtractor`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::extract::secretsmanager::SecretsManagerExtractor;
+    ///
+    /// let extractor = SecretsManagerExtractor::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+/// Extracts Secrets Manager OTel attributes from SDK inputs and outputs.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes and which operations they apply to.
+impl<SW: SpanWrite> AttributeExtractor<SW> for SecretsManagerExtractor {
+    fn extract_input(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        input: &context::Input,
+        span: &mut SW,
+    ) {
+        match operation {
+            "GetSecretValue" => {
+                let i = input
+                    .downcast_ref::<GetSecretValueInput>()
+                    .expect("correct type");
+                set_str(span, AWS_SECRETSMANAGER_SECRET_ID, i.secret_id());
+                set_str(span, AWS_SECRETSMANAGER_VERSION_STAGE, i.version_stage());
+                set_str(span, AWS_SECRETSMANAGER_VERSION_ID, i.version_id());
+            }
+            "BatchGetSecretValue" => {
+                let i = input
+                    .downcast_ref::<BatchGetSecretValueInput>()
+                    .expect("correct type");
+                set_strings(span, AWS_SECRETSMANAGER_SECRET_IDS, i.secret_id_list());
+            }
+            _ => {}
+        }
+    }
+
+    fn extract_output(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        output: &context::Output,
+        span: &mut SW,
+    ) {
+        match operation {
+            "GetSecretValue" => {
+                let version = metadata::SecretVersion::of(output);
+                set_str(span, AWS_SECRETSMANAGER_SECRET_ARN, version.arn);
+                set_str(span, AWS_SECRETSMANAGER_VERSION_ID, version.version_id);
+                set_strings(
+                    span,
+                    AWS_SECRETSMANAGER_VERSION_STAGES,
+                    version.version_stages,
+                );
+            }
+            "BatchGetSecretValue" => {
+                set_batch_outcome(span, &metadata::BatchOutcome::of(output));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Sets the returned and failed counts of `BatchGetSecretValue`, the distinct
+/// error codes, and an error status when at least one secret failed.
+fn set_batch_outcome(span: &mut impl SpanWrite, outcome: &metadata::BatchOutcome<'_>) {
+    let returned = outcome.returned;
+    let failed = outcome.errors.len();
+    span.set_attribute(AWS_SECRETSMANAGER_RETURNED_COUNT, returned as i64);
+    span.set_attribute(AWS_SECRETSMANAGER_ERROR_COUNT, failed as i64);
+    if failed == 0 {
+        return;
+    }
+    let codes = outcome
+        .errors
+        .iter()
+        .filter_map(|error| error.error_code())
+        .collect::<BTreeSet<_>>();
+    span.set_attribute(
+        AWS_SECRETSMANAGER_ERROR_CODES,
+        Value::Array(Array::String(
+            codes
+                .into_iter()
+                .map(|code| StringValue::from(code.to_owned()))
+                .collect(),
+        )),
+    );
+    span.set_status(Status::error(format!(
+        "{} of {} secrets failed",
+        failed,
+        returned + failed
+    )));
+}
+
+/// Metadata views over the Secrets Manager outputs.
+///
+/// This is the only code that reads an SDK output. Each view destructures the
+/// fields it needs and nothing else, so the secret value fields are never
+/// named.
+mod metadata {
+    use aws_sdk_secretsmanager::{
+        operation::{
+            batch_get_secret_value::BatchGetSecretValueOutput,
+            get_secret_value::GetSecretValueOutput,
+        },
+        types::ApiErrorType,
+    };
+    use aws_smithy_runtime_api::client::interceptors::context;
+
+    /// Identifiers and version of the secret returned by `GetSecretValue`.
+    pub(super) struct SecretVersion<'a> {
+        pub(super) arn: Option<&'a str>,
+        pub(super) version_id: Option<&'a str>,
+        pub(super) version_stages: &'a [String],
+    }
+
+    impl<'a> SecretVersion<'a> {
+        pub(super) fn of(output: &'a context::Output) -> Self {
+            let GetSecretValueOutput {
+                arn,
+                version_id,
+                version_stages,
+                ..
+            } = output
+                .downcast_ref::<GetSecretValueOutput>()
+                .expect("correct type");
+            Self {
+                arn: arn.as_deref(),
+                version_id: version_id.as_deref(),
+                version_stages: version_stages.as_deref().unwrap_or_default(),
+            }
+        }
+    }
+
+    /// Returned count and errors of `BatchGetSecretValue`.
+    pub(super) struct BatchOutcome<'a> {
+        pub(super) returned: usize,
+        pub(super) errors: &'a [ApiErrorType],
+    }
+
+    impl<'a> BatchOutcome<'a> {
+        pub(super) fn of(output: &'a context::Output) -> Self {
+            let BatchGetSecretValueOutput {
+                secret_values,
+                errors,
+                ..
+            } = output
+                .downcast_ref::<BatchGetSecretValueOutput>()
+                .expect("correct type");
+            Self {
+                returned: secret_values.as_ref().map_or(0, Vec::len),
+                errors: errors.as_deref().unwrap_or_default(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_secretsmanager::{
+        operation::{
+            batch_get_secret_value::BatchGetSecretValueOutput,
+            get_secret_value::{GetSecretValueError, GetSecretValueOutput},
+        },
+        types::{ApiErrorType, SecretValueEntry, error::ResourceNotFoundException},
+    };
+    use opentelemetry::Value;
+
+    use crate::span_write::{SpanContext, SpanWrite, Status};
+
+    struct TestSpan {
+        attributes: Vec<(&'static str, Value)>,
+        status: Option<Status>,
+    }
+
+    impl TestSpan {
+        fn new() -> Self {
+            Self {
+                attributes: vec![],
+                status: None,
+            }
+        }
+
+        fn get(&self, key: &str) -> Option<&Value> {
+            self.attributes
+                .iter()
+                .rev()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v)
+        }
+
+        fn contains(&self, needle: &str) -> bool {
+            self.attributes
+                .iter()
+                .any(|(_, value)| value.to_string().contains(needle))
+        }
+    }
+
+    impl SpanWrite for TestSpan {
+        fn set_attribute(&mut self, key: &'static str, value: impl Into<Value>) {
+            self.attributes.push((key, value.into()));
+        }
+
+        fn set_status(&mut self, code: Status) {
+            self.status = Some(code);
+        }
+
+        fn span_context(&self) -> SpanContext {
+            SpanContext::empty_context()
+        }
+    }
+
+    const SECRET_ARN: &str = "arn:aws:secretsmanager:us-east-1:123456789012:secret:db-AbCdEf";
+    const SECRET_VALUE: &str = "hunter2-do-not-record";
+
+    // Tests for SecretsManagerExtractor — 3 consolidated tests
+
+    #[test]
+    fn extract_get_secret_value() {
+        let extractor = SecretsManagerExtractor::new();
+
+        let sdk_input = GetSecretValueInput::builder()
+            .secret_id("db")
+            .version_stage("AWSCURRENT")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "Secrets Manager",
+            "GetSecretValue",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_SECRETSMANAGER_SECRET_ID),
+            Some(&Value::from("db"))
+        );
+        assert_eq!(
+            span.get(AWS_SECRETSMANAGER_VERSION_STAGE),
+            Some(&Value::from("AWSCURRENT"))
+        );
+        assert!(!span.contains(SECRET_VALUE));
+
+        let sdk_output = GetSecretValueOutput::builder()
+            .arn(SECRET_ARN)
+            .name("db")
+            .version_id("v-1")
+            .version_stages("AWSCURRENT")
+            .secret_string(SECRET_VALUE)
+            .secret_binary(aws_smithy_types::Blob::new(SECRET_VALUE))
+            .build();
+        extractor.extract_output(
+            "Secrets Manager",
+            "GetSecretValue",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_SECRETSMANAGER_SECRET_ARN),
+            Some(&Value::from(SECRET_ARN))
+        );
+        assert_eq!(
+            span.get(AWS_SECRETSMANAGER_VERSION_ID),
+            Some(&Value::from("v-1"))
+        );
+        assert_eq!(
+            span.get(AWS_SECRETSMANAGER_VERSION_STAGES),
+            Some(&Value::Array(Array::String(vec!["AWSCURRENT".into()])))
+        );
+        assert!(!span.contains(SECRET_VALUE));
+    }
+
+    #[test]
+    fn extract_batch_get_secret_value_partial_failure() {
+        let extractor = SecretsManagerExtractor::new();
+
+        let sdk_input = BatchGetSecretValueInput::builder()
+            .secret_id_list("db")
+            .secret_id_list("api-key")
+            .secret_id_list("missing")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "Secrets Manager",
+            "BatchGetSecretValue",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_SECRETSMANAGER_SECRET_IDS),
+            Some(&Value::Array(Array::String(vec![
+                "db".into(),
+                "api-key".into(),
+                "missing".into()
+            ])))
+        );
+        assert!(!span.contains(SECRET_VALUE));
+
+        let entry = |name: &str| {
+            SecretValueEntry::builder()
+                .name(name)
+                .secret_string(SECRET_VALUE)
+                .build()
+        };
+        let sdk_output = BatchGetSecretValueOutput::builder()
+            .secret_values(entry("db"))
+            .secret_values(entry("api-key"))
+            .errors(
+                ApiErrorType::builder()
+                    .secret_id("missing")
+                    .error_code("ResourceNotFoundException")
+                    .build(),
+            )
+            .build();
+        extractor.extract_output(
+            "Secrets Manager",
+            "BatchGetSecretValue",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_SECRETSMANAGER_RETURNED_COUNT),
+            Some(&Value::I64(2))
+        );
+        assert_eq!(
+            span.get(AWS_SECRETSMANAGER_ERROR_COUNT),
+            Some(&Value::I64(1))
+        );
+        assert_eq!(
+            span.get(AWS_SECRETSMANAGER_ERROR_CODES),
+            Some(&Value::Array(Array::String(vec![
+                "ResourceNotFoundException".into()
+            ])))
+        );
+        assert_eq!(span.status, Some(Status::error("1 of 3 secrets failed")));
+        assert!(!span.contains(SECRET_VALUE));
+    }
+
+    #[test]
+    fn extract_error_records_no_secret() {
+        let extractor = SecretsManagerExtractor::new();
+        let error = GetSecretValueError::ResourceNotFoundException(
+            ResourceNotFoundException::builder()
+                .message(SECRET_VALUE)
+                .build(),
+        );
+        let mut span = TestSpan::new();
+        extractor.extract_error(
+            "Secrets Manager",
+            "GetSecretValue",
+            &context::Error::erase(error),
+            &mut span,
+        );
+        assert!(!span.contains(SECRET_VALUE));
+        assert!(span.status.is_none());
+    }
+}
@@ -0,0 +1,409 @@
+//! SES (v2) attribute extraction.
+//!
+//! This module provides [`SESv2Extractor`], which implements
+//! [`super::super::AttributeExtractor`] for the SES v2 `SendEmail` and
+//! `SendBulkEmail` SDK calls. It is automatically used by
+//! [`super::super::DefaultExtractor`] when the `extract-sesv2` feature is
+//! enabled.
+//!
+//! ## Extracted attributes
+//!
+//! **Set from input:**
+//! - `aws.ses.configuration_set` — the configuration set name
+//! - `aws.ses.from_domain` — the domain of the from address, lowercased
+//! - `aws.ses.recipient.to_count`, `aws.ses.recipient.cc_count`,
+//!   `aws.ses.recipient.bcc_count` — the number of To, Cc and Bcc recipients,
+//!   summed over all entries for `SendBulkEmail`
+//! - `aws.ses.template_name` — the template name, when sending from a
+//!   template
+//! - `aws.ses.bulk_email.entry_count` — for `SendBulkEmail`, the number of
+//!   entries
+//!
+//! **Set from output:**
+//! - `aws.ses.message_id` — for `SendEmail`, the message ID
+//! - for `SendBulkEmail`:
+//!   - `aws.ses.bulk_email.status_counts` (JSON object) — the number of
+//!     entries per status, e.g. `{"MESSAGE_REJECTED":1,"SUCCESS":49}`
+//!   - `aws.ses.bulk_email.failed_count` — the number of entries whose status
+//!     is not `SUCCESS`
+//!
+//! `SendBulkEmail` reports per-entry failures inside a successful response.
+//! When at least one entry failed, the span status is set to error.
+//!
+//! Recipient, reply-to and from addresses, message content and template data
+//! are never recorded.
+
+// SESv2 attribute extraction — downcasts Input/Output to concrete
+// aws-sdk-sesv2 types and extracts sending attributes.
+
+use std::collections::BTreeMap;
+
+use aws_sdk_sesv2::operation::{
+    send_bulk_email::{SendBulkEmailInput, SendBulkEmailOutput},
+    send_email::{SendEmailInput, SendEmailOutput},
+};
+use aws_sdk_sesv2::types::{BulkEmailStatus, Destination, Template};
+use aws_smithy_runtime_api::client::interceptors::context;
+
+use super::super::{AttributeExtractor, SpanWrite};
+use super::set_str;
+use crate::span_write::Status;
+
+/// Attribute key for the configuration set name.
+const AWS_SES_CONFIGURATION_SET: &str = "aws.ses.configuration_set";
+/// Attribute key for the domain of the from address.
+const AWS_SES_FROM_DOMAIN: &str = "aws.ses.from_domain";
+/// Attribute key for the number of To recipients.
+const AWS_SES_RECIPIENT_TO_COUNT: &str = "aws.ses.recipient.to_count";
+/// Attribute key for the number of Cc recipients.
+const AWS_SES_RECIPIENT_CC_COUNT: &str = "aws.ses.recipient.cc_count";
+/// Attribute key for the number of Bcc recipients.
+const AWS_SES_RECIPIENT_BCC_COUNT: &str = "aws.ses.recipient.bcc_count";
+/// Attribute key for the template name.
+const AWS_SES_TEMPLATE_NAME: &str = "aws.ses.template_name";
+/// Attribute key for the message ID.
+const AWS_SES_MESSAGE_ID: &str = "aws.ses.message_id";
+/// Attribute key for the number of `SendBulkEmail` entries.
+const AWS_SES_BULK_EMAIL_ENTRY_COUNT: &str = "aws.ses.bulk_email.entry_count";
+/// Attribute key for the per-status counts of `SendBulkEmail` entries.
+const AWS_SES_BULK_EMAIL_STATUS_COUNTS: &str = "aws.ses.bulk_email.status_counts";
+/// Attribute key for the number of `SendBulkEmail` entries that failed.
+const AWS_SES_BULK_EMAIL_FAILED_COUNT: &str = "aws.ses.bulk_email.failed_count";
+
+/// Attribute extractor for SES v2 SDK calls.
+///
+/// `SESv2Extractor` implements [`AttributeExtractor`] and is automatically
+/// used by [`DefaultExtractor`] when the `extract-sesv2` feature is enabled.
+/// You only need to construct it directly if you are composing a custom
+/// extraction pipeline.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes.
+///
+/// [`DefaultExtractor`]: crate::interceptor::DefaultExtractor
+#[derive(Debug, Default)]
+pub struct SESv2Extractor {
+    _private: (),
+}
+
+impl SESv2Extractor {
+    /// Creates a new `SESv2Extractor`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::extract::sesv2::SESv2Extractor;
+    ///
+    /// let extractor = SESv2Extractor::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+/// Extracts SES attributes from SES v2 SDK inputs and outputs.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes and which operations they apply to.
+impl<SW: SpanWrite> AttributeExtractor<SW> for SESv2Extractor {
+    fn extract_input(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        input: &context::Input,
+        span: &mut SW,
+    ) {
+        match operation {
+            "SendEmail" => {
+                let i = input
+                    .downcast_ref::<SendEmailInput>()
+                    .expect("correct type");
+                set_str(span, AWS_SES_CONFIGURATION_SET, i.configuration_set_name());
+                set_from_domain(span, i.from_email_address());
+                set_recipient_counts(span, i.destination());
+                set_template_name(span, i.content().and_then(|content| content.template()));
+            }
+            "SendBulkEmail" => {
+                let i = input
+                    .downcast_ref::<SendBulkEmailInput>()
+                    .expect("correct type");
+                set_str(span, AWS_SES_CONFIGURATION_SET, i.configuration_set_name());
+                set_from_domain(span, i.from_email_address());
+                let entries = i.bulk_email_entries();
+                span.set_attribute(AWS_SES_BULK_EMAIL_ENTRY_COUNT, entries.len() as i64);
+                set_recipient_counts(span, entries.iter().filter_map(|entry| entry.destination()));
+                set_template_name(
+                    span,
+                    i.default_content().and_then(|content| content.template()),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    fn extract_output(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        output: &context::Output,
+        span: &mut SW,
+    ) {
+        match operation {
+            "SendEmail" => {
+                let o = output
+                    .downcast_ref::<SendEmailOutput>()
+                    .expect("correct type");
+                set_str(span, AWS_SES_MESSAGE_ID, o.message_id());
+            }
+            "SendBulkEmail" => {
+                let o = output
+                    .downcast_ref::<SendBulkEmailOutput>()
+                    .expect("correct type");
+                let mut status_counts = BTreeMap::<&str, usize>::new();
+                let mut failed = 0;
+                for result in o.bulk_email_entry_results() {
+                    let status = result.status().map_or("UNKNOWN", BulkEmailStatus::as_str);
+                    *status_counts.entry(status).or_default() += 1;
+                    if result.status() != Some(&BulkEmailStatus::Success) {
+                        failed += 1;
+                    }
+                }
+                if let Ok(json) = serde_json::to_string(&status_counts) {
+                    span.set_attribute(AWS_SES_BULK_EMAIL_STATUS_COUNTS, json);
+                }
+                span.set_attribute(AWS_SES_BULK_EMAIL_FAILED_COUNT, failed as i64);
+                if failed > 0 {
+                    span.set_status(Status::error(format!(
+                        "{} of {} entries failed",
+                        failed,
+                        o.bulk_email_entry_results().len()
+                    )));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Sets `aws.ses.from_domain` from a from address, which may carry a display
+/// name (`"Name <user@example.com>"`).
+fn set_from_domain(span: &mut impl SpanWrite, from: Option<&str>) {
+    let domain = from
+        .and_then(|from| from.rsplit_once('@'))
+        .map(|(_, domain)| domain.trim().trim_end_matches('>').trim())
+        .filter(|domain| !domain.is_empty());
+    if let Some(domain) = domain {
+        span.set_attribute(AWS_SES_FROM_DOMAIN, domain.to_ascii_lowercase());
+    }
+}
+
+/// Sets the To, Cc and Bcc recipient counts summed over `destinations`.
+fn set_recipient_counts<'a>(
+    span: &mut impl SpanWrite,
+    destinations: impl IntoIterator<Item = &'a Destination>,
+) {
+    let (mut to, mut cc, mut bcc) = (0, 0, 0);
+    for destination in destinations {
+        to += destination.to_addresses().len();
+        cc += destination.cc_addresses().len();
+        bcc += destination.bcc_addresses().len();
+    }
+    span.set_attribute(AWS_SES_RECIPIENT_TO_COUNT, to as i64);
+    span.set_attribute(AWS_SES_RECIPIENT_CC_COUNT, cc as i64);
+    span.set_attribute(AWS_SES_RECIPIENT_BCC_COUNT, bcc as i64);
+}
+
+/// Sets `aws.ses.template_name` from a template, when one is used.
+fn set_template_name(span: &mut impl SpanWrite, template: Option<&Template>) {
+    set_str(
+        span,
+        AWS_SES_TEMPLATE_NAME,
+        template.and_then(|template| template.template_name()),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_sesv2::types::{
+        BulkEmailContent, BulkEmailEntry, BulkEmailEntryResult, EmailContent,
+    };
+    use opentelemetry::Value;
+
+    use crate::span_write::{SpanContext, SpanWrite, Status};
+
+    struct TestSpan {
+        attributes: Vec<(&'static str, Value)>,
+        status: Option<Status>,
+    }
+
+    impl TestSpan {
+        fn new() -> Self {
+            Self {
+                attributes: vec![],
+                status: None,
+            }
+        }
+
+        fn get(&self, key: &str) -> Option<&Value> {
+            self.attributes
+                .iter()
+                .rev()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v)
+        }
+
+        fn contains(&self, needle: &str) -> bool {
+            self.attributes
+                .iter()
+                .any(|(_, value)| value.to_string().contains(needle))
+        }
+    }
+
+    impl SpanWrite for TestSpan {
+        fn set_attribute(&mut self, key: &'static str, value: impl Into<Value>) {
+            self.attributes.push((key, value.into()));
+        }
+
+        fn set_status(&mut self, code: Status) {
+            self.status = Some(code);
+        }
+
+        fn span_context(&self) -> SpanContext {
+            SpanContext::empty_context()
+        }
+    }
+
+    fn template(name: &str) -> Template {
+        Template::builder()
+            .template_name(name)
+            .template_data(r#"{"name":"Alice"}"#)
+            .build()
+    }
+
+    // Tests for SESv2Extractor — 2 consolidated tests
+
+    #[test]
+    fn extract_send_email() {
+        let extractor = SESv2Extractor::new();
+        let sdk_input = SendEmailInput::builder()
+            .from_email_address("Alerts <alerts@Mail.Example.com>")
+            .destination(
+                Destination::builder()
+                    .to_addresses("alice@example.org")
+                    .to_addresses("bob@example.org")
+                    .cc_addresses("carol@example.org")
+                    .bcc_addresses("audit@example.net")
+                    .build(),
+            )
+            .reply_to_addresses("support@example.com")
+            .content(
+                EmailContent::builder()
+                    .template(template("welcome"))
+                    .build(),
+            )
+            .configuration_set_name("transactional")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "SESv2",
+            "SendEmail",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        extractor.extract_output(
+            "SESv2",
+            "SendEmail",
+            &context::Output::erase(SendEmailOutput::builder().message_id("0100-abc").build()),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_SES_CONFIGURATION_SET),
+            Some(&Value::from("transactional"))
+        );
+        assert_eq!(
+            span.get(AWS_SES_FROM_DOMAIN),
+            Some(&Value::from("mail.example.com"))
+        );
+        assert_eq!(span.get(AWS_SES_RECIPIENT_TO_COUNT), Some(&Value::I64(2)));
+        assert_eq!(span.get(AWS_SES_RECIPIENT_CC_COUNT), Some(&Value::I64(1)));
+        assert_eq!(span.get(AWS_SES_RECIPIENT_BCC_COUNT), Some(&Value::I64(1)));
+        assert_eq!(
+            span.get(AWS_SES_TEMPLATE_NAME),
+            Some(&Value::from("welcome"))
+        );
+        assert_eq!(span.get(AWS_SES_MESSAGE_ID), Some(&Value::from("0100-abc")));
+        for needle in ["@", "alerts", "Alice"] {
+            assert!(!span.contains(needle), "recorded {needle:?}");
+        }
+    }
+
+    #[test]
+    fn extract_send_bulk_email() {
+        let extractor = SESv2Extractor::new();
+        let entry = |to: &str| {
+            BulkEmailEntry::builder()
+                .destination(Destination::builder().to_addresses(to).build())
+                .build()
+        };
+        let sdk_input = SendBulkEmailInput::builder()
+            .from_email_address("news@example.com")
+            .default_content(
+                BulkEmailContent::builder()
+                    .template(template("digest"))
+                    .build(),
+            )
+            .bulk_email_entries(entry("alice@example.org"))
+            .bulk_email_entries(entry("bob@example.org"))
+            .bulk_email_entries(entry("carol@example.org"))
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "SESv2",
+            "SendBulkEmail",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_SES_BULK_EMAIL_ENTRY_COUNT),
+            Some(&Value::I64(3))
+        );
+        assert_eq!(span.get(AWS_SES_RECIPIENT_TO_COUNT), Some(&Value::I64(3)));
+        assert_eq!(span.get(AWS_SES_RECIPIENT_CC_COUNT), Some(&Value::I64(0)));
+        assert_eq!(
+            span.get(AWS_SES_TEMPLATE_NAME),
+            Some(&Value::from("digest"))
+        );
+        assert_eq!(
+            span.get(AWS_SES_FROM_DOMAIN),
+            Some(&Value::from("example.com"))
+        );
+
+        let result =
+            |status: BulkEmailStatus| BulkEmailEntryResult::builder().status(status).build();
+        let sdk_output = SendBulkEmailOutput::builder()
+            .bulk_email_entry_results(result(BulkEmailStatus::Success))
+            .bulk_email_entry_results(result(BulkEmailStatus::MessageRejected))
+            .bulk_email_entry_results(result(BulkEmailStatus::Success))
+            .build()
+            .unwrap();
+        extractor.extract_output(
+            "SESv2",
+            "SendBulkEmail",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_SES_BULK_EMAIL_STATUS_COUNTS),
+            Some(&Value::from(r#"{"MESSAGE_REJECTED":1,"SUCCESS":2}"#))
+        );
+        assert_eq!(
+            span.get(AWS_SES_BULK_EMAIL_FAILED_COUNT),
+            Some(&Value::I64(1))
+        );
+        assert_eq!(span.status, Some(Status::error("1 of 3 entries failed")));
+        assert!(!span.contains("@"));
+    }
+}
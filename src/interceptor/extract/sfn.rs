@@ -0,0 +1,541 @@
+//! Step Functions attribute extraction following OTel semantic conventions.
+//!
+//! This module provides [`SFNExtractor`], which implements
+//! [`super::super::AttributeExtractor`] for Step Functions SDK calls. It is
+//! automatically used by [`super::super::DefaultExtractor`] when the
+//! `extract-sfn` feature is enabled.
+//!
+//! ## Extracted attributes
+//!
+//! **Set from `StartExecution` and `StartSyncExecution`:**
+//! - `aws.step_functions.state_machine.arn` — the state machine ARN
+//! - `aws.step_functions.execution.name` — the execution name, when given
+//!   (`StartSyncExecution` also returns it)
+//! - `aws.step_functions.execution.arn` — from the output, the ARN of the
+//!   started execution
+//!
+//! **Set from `StartSyncExecution` and `DescribeExecution` output:**
+//! - `aws.step_functions.execution.status` — the execution status (e.g.
+//!   `"SUCCEEDED"`, `"FAILED"`)
+//! - `aws.step_functions.execution.error`, `aws.step_functions.execution.cause`
+//!   — the error code and failure cause of a failed execution
+//!
+//! `DescribeExecution` also records the execution ARN, name and state machine
+//! ARN. A `StartSyncExecution` whose execution did not succeed sets the span
+//! status to error, since the caller waited for that outcome.
+//!
+//! **Set from the task token operations:**
+//! - `aws.step_functions.task_token.sha256` — for `SendTaskSuccess`,
+//!   `SendTaskFailure` and `SendTaskHeartbeat`, the SHA-256 hex digest of the
+//!   task token. The token itself is never recorded: it is a bearer credential
+//!   that completes the task.
+//! - `aws.step_functions.task.error` — for `SendTaskFailure`, the error code
+//!
+//! Execution inputs and outputs, and the cause given to `SendTaskFailure`, are
+//! never recorded. The failure cause of an execution is recorded as
+//! `aws.step_functions.execution.cause`; like the task failure cause it is
+//! free-form text, which may include data from the execution.
+//!
+//! ## Trace context propagation
+//!
+//! When enabled with [`SFNExtractor::with_trace_header_injection`], the
+//! extractor sets the `traceHeader` of `StartExecution` and
+//! `StartSyncExecution` to the X-Ray trace header of the current span, unless
+//! the caller already set one. Step Functions then continues the trace in the
+//! execution and in the services it calls.
+//!
+//! X-Ray only accepts trace IDs whose first 32 bits are the trace start time in
+//! epoch seconds. Pair this option with the X-Ray ID generator of the
+//! `export-xray` feature.
+
+// Step Functions attribute extraction — downcasts Input/Output to concrete
+// aws-sdk-sfn types and extracts state machine and execution attributes.
+
+use aws_sdk_sfn::operation::{
+    describe_execution::{DescribeExecutionInput, DescribeExecutionOutput},
+    send_task_failure::SendTaskFailureInput,
+    send_task_heartbeat::SendTaskHeartbeatInput,
+    send_task_success::SendTaskSuccessInput,
+    start_execution::{StartExecutionInput, StartExecutionOutput},
+    start_sync_execution::{StartSyncExecutionInput, StartSyncExecutionOutput},
+};
+use aws_sdk_sfn::types::SyncExecutionStatus;
+use aws_smithy_runtime_api::client::interceptors::context;
+use opentelemetry_semantic_conventions::attribute as semco;
+
+use super::super::{AttributeExtractor, SpanWrite};
+use super::hash::sha256_hex;
+use super::set_str;
+use crate::span_write::Status;
+use crate::xray::XRayTraceHeader;
+
+/// Attribute key for the execution ARN.
+const AWS_STEP_FUNCTIONS_EXECUTION_ARN: &str = "aws.step_functions.execution.arn";
+/// Attribute key for the execution name.
+const AWS_STEP_FUNCTIONS_EXECUTION_NAME: &str = "aws.step_functions.execution.name";
+/// Attribute key for the execution status.
+const AWS_STEP_FUNCTIONS_EXECUTION_STATUS: &str = "aws.step_functions.execution.status";
+/// Attribute key for the error code of a failed execution.
+const AWS_STEP_FUNCTIONS_EXECUTION_ERROR: &str = "aws.step_functions.execution.error";
+/// Attribute key for the failure cause of a failed execution.
+const AWS_STEP_FUNCTIONS_EXECUTION_CAUSE: &str = "aws.step_functions.execution.cause";
+/// Attribute key for the SHA-256 hex digest of a task token.
+const AWS_STEP_FUNCTIONS_TASK_TOKEN_SHA256: &str = "aws.step_functions.task_token.sha256";
+/// Attribute key for the error code given to `SendTaskFailure`.
+const AWS_STEP_FUNCTIONS_TASK_ERROR: &str = "aws.step_functions.task.error";
+
+/// Attribute extractor for Step Functions SDK calls.
+///
+/// `SFNExtractor` implements [`AttributeExtractor`] and is automatically used
+/// by [`DefaultExtractor`] when the `extract-sfn` feature is enabled. Construct
+/// it directly to enable trace header injection, then hand it to
+/// [`DefaultExtractor::set_sfn_extractor`].
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes.
+///
+/// [`DefaultExtractor`]: crate::interceptor::DefaultExtractor
+/// [`DefaultExtractor::set_sfn_extractor`]: crate::interceptor::DefaultExtractor::set_sfn_extractor
+#[derive(Debug, Default)]
+pub struct SFNExtractor {
+    inject_trace_header: bool,
+}
+
+impl SFNExtractor {
+    /// Creates a new `SFNExtractor`, with trace header injection disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::extract::sfn::SFNExtractor;
+    ///
+    /// let extractor = SFNExtractor::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables or disables setting the `traceHeader` of `StartExecution` and
+    /// `StartSyncExecution` from the current span.
+    ///
+    /// A `traceHeader` set by the caller is left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::{
+    ///     DefaultInterceptor, extract::sfn::SFNExtractor,
+    /// };
+    ///
+    /// let mut interceptor = DefaultInterceptor::new();
+    /// interceptor
+    ///     .extractor
+    ///     .set_sfn_extractor(SFNExtractor::new().with_trace_header_injection(true));
+    /// ```
+    pub fn with_trace_header_injection(mut self, inject: bool) -> Self {
+        self.inject_trace_header = inject;
+        self
+    }
+}
+
+/// Extracts Step Functions-specific OTel attributes from SDK inputs and
+/// outputs, and optionally injects the trace header into started executions.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes and which operations they apply to.
+impl<SW: SpanWrite> AttributeExtractor<SW> for SFNExtractor {
+    fn extract_input(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        input: &context::Input,
+        span: &mut SW,
+    ) {
+        match operation {
+            "StartExecution" => {
+                let i = input
+                    .downcast_ref::<StartExecutionInput>()
+                    .expect("correct type");
+                set_str(
+                    span,
+                    semco::AWS_STEP_FUNCTIONS_STATE_MACHINE_ARN,
+                    i.state_machine_arn(),
+                );
+                set_str(span, AWS_STEP_FUNCTIONS_EXECUTION_NAME, i.name());
+            }
+            "StartSyncExecution" => {
+                let i = input
+                    .downcast_ref::<StartSyncExecutionInput>()
+                    .expect("correct type");
+                set_str(
+                    span,
+                    semco::AWS_STEP_FUNCTIONS_STATE_MACHINE_ARN,
+                    i.state_machine_arn(),
+                );
+                set_str(span, AWS_STEP_FUNCTIONS_EXECUTION_NAME, i.name());
+            }
+            "DescribeExecution" => set_str(
+                span,
+                AWS_STEP_FUNCTIONS_EXECUTION_ARN,
+                input
+                    .downcast_ref::<DescribeExecutionInput>()
+                    .expect("correct type")
+                    .execution_arn(),
+            ),
+            "SendTaskSuccess" => set_task_token(
+                span,
+                input
+                    .downcast_ref::<SendTaskSuccessInput>()
+                    .expect("correct type")
+                    .task_token(),
+            ),
+            "SendTaskFailure" => {
+                let i = input
+                    .downcast_ref::<SendTaskFailureInput>()
+                    .expect("correct type");
+                set_task_token(span, i.task_token());
+                set_str(span, AWS_STEP_FUNCTIONS_TASK_ERROR, i.error());
+            }
+            "SendTaskHeartbeat" => set_task_token(
+                span,
+                input
+                    .downcast_ref::<SendTaskHeartbeatInput>()
+                    .expect("correct type")
+                    .task_token(),
+            ),
+            _ => {}
+        }
+    }
+
+    fn modify_input(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        input: &mut context::Input,
+        span: &mut SW,
+    ) {
+        if !self.inject_trace_header {
+            return;
+        }
+        let trace_header = match operation {
+            "StartExecution" => {
+                &mut input
+                    .downcast_mut::<StartExecutionInput>()
+                    .expect("correct type")
+                    .trace_header
+            }
+            "StartSyncExecution" => {
+                &mut input
+                    .downcast_mut::<StartSyncExecutionInput>()
+                    .expect("correct type")
+                    .trace_header
+            }
+            _ => return,
+        };
+        if trace_header.is_none() {
+            *trace_header = XRayTraceHeader::from_span_context(&span.span_context())
+                .map(|header| header.to_string());
+        }
+    }
+
+    fn extract_output(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        output: &context::Output,
+        span: &mut SW,
+    ) {
+        match operation {
+            "StartExecution" => set_str(
+                span,
+                AWS_STEP_FUNCTIONS_EXECUTION_ARN,
+                Some(
+                    output
+                        .downcast_ref::<StartExecutionOutput>()
+                        .expect("correct type")
+                        .execution_arn(),
+                ),
+            ),
+            "StartSyncExecution" => {
+                let o = output
+                    .downcast_ref::<StartSyncExecutionOutput>()
+                    .expect("correct type");
+                set_str(
+                    span,
+                    AWS_STEP_FUNCTIONS_EXECUTION_ARN,
+                    Some(o.execution_arn()),
+                );
+                set_str(span, AWS_STEP_FUNCTIONS_EXECUTION_NAME, o.name());
+                let status = o.status();
+                set_execution_outcome(span, status.as_str(), o.error(), o.cause());
+                if *status != SyncExecutionStatus::Succeeded {
+                    span.set_status(Status::error(match o.error() {
+                        Some(error) => format!("execution {}: {error}", status.as_str()),
+                        None => format!("execution {}", status.as_str()),
+                    }));
+                }
+            }
+            "DescribeExecution" => {
+                let o = output
+                    .downcast_ref::<DescribeExecutionOutput>()
+                    .expect("correct type");
+                set_str(
+                    span,
+                    semco::AWS_STEP_FUNCTIONS_STATE_MACHINE_ARN,
+                    Some(o.state_machine_arn()),
+                );
+                set_str(
+                    span,
+                    AWS_STEP_FUNCTIONS_EXECUTION_ARN,
+                    Some(o.execution_arn()),
+                );
+                set_str(span, AWS_STEP_FUNCTIONS_EXECUTION_NAME, o.name());
+                set_execution_outcome(span, o.status().as_str(), o.error(), o.cause());
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Sets the execution status, and the error code and cause when present.
+fn set_execution_outcome(
+    span: &mut impl SpanWrite,
+    status: &str,
+    error: Option<&str>,
+    cause: Option<&str>,
+) {
+    set_str(span, AWS_STEP_FUNCTIONS_EXECUTION_STATUS, Some(status));
+    set_str(span, AWS_STEP_FUNCTIONS_EXECUTION_ERROR, error);
+    set_str(span, AWS_STEP_FUNCTIONS_EXECUTION_CAUSE, cause);
+}
+
+/// Sets the SHA-256 hex digest of a task token, never the token itself.
+fn set_task_token(span: &mut impl SpanWrite, task_token: Option<&str>) {
+    if let Some(task_token) = task_token.filter(|s| !s.is_empty()) {
+        span.set_attribute(AWS_STEP_FUNCTIONS_TASK_TOKEN_SHA256, sha256_hex(task_token));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::Value;
+    use opentelemetry_semantic_conventions::attribute as semco;
+
+    use crate::span_write::{SpanContext, SpanWrite, Status};
+
+    struct TestSpan {
+        attributes: Vec<(&'static str, Value)>,
+        status: Option<Status>,
+        span_context: SpanContext,
+    }
+
+    impl TestSpan {
+        fn new() -> Self {
+            Self {
+                attributes: vec![],
+                status: None,
+                span_context: SpanContext::empty_context(),
+            }
+        }
+
+        fn get(&self, key: &str) -> Option<&Value> {
+            self.attributes
+                .iter()
+                .rev()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v)
+        }
+    }
+
+    impl SpanWrite for TestSpan {
+        fn set_attribute(&mut self, key: &'static str, value: impl Into<Value>) {
+            self.attributes.push((key, value.into()));
+        }
+
+        fn set_status(&mut self, code: Status) {
+            self.status = Some(code);
+        }
+
+        fn span_context(&self) -> SpanContext {
+            self.span_context.clone()
+        }
+    }
+
+    const STATE_MACHINE_ARN: &str = "arn:aws:states:us-east-1:123456789012:stateMachine:orders";
+    const EXECUTION_ARN: &str = "arn:aws:states:us-east-1:123456789012:execution:orders:run-1";
+
+    // Tests for SFNExtractor::extract_input / extract_output — 3 consolidated tests
+
+    #[test]
+    fn extract_start_execution() {
+        let extractor = SFNExtractor::new();
+
+        let sdk_input = StartExecutionInput::builder()
+            .state_machine_arn(STATE_MACHINE_ARN)
+            .name("run-1")
+            .input("{\"card\":\"4111\"}")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "SFN",
+            "StartExecution",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(semco::AWS_STEP_FUNCTIONS_STATE_MACHINE_ARN),
+            Some(&Value::from(STATE_MACHINE_ARN))
+        );
+        assert_eq!(
+            span.get(AWS_STEP_FUNCTIONS_EXECUTION_NAME),
+            Some(&Value::from("run-1"))
+        );
+
+        let sdk_output = StartExecutionOutput::builder()
+            .execution_arn(EXECUTION_ARN)
+            .start_date(aws_smithy_types::DateTime::from_secs(0))
+            .build()
+            .unwrap();
+        extractor.extract_output(
+            "SFN",
+            "StartExecution",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_STEP_FUNCTIONS_EXECUTION_ARN),
+            Some(&Value::from(EXECUTION_ARN))
+        );
+        assert!(
+            span.attributes
+                .iter()
+                .all(|(_, value)| !value.as_str().contains("4111"))
+        );
+    }
+
+    #[test]
+    fn extract_start_sync_execution_failure() {
+        let sdk_output = StartSyncExecutionOutput::builder()
+            .execution_arn(EXECUTION_ARN)
+            .name("run-1")
+            .start_date(aws_smithy_types::DateTime::from_secs(0))
+            .stop_date(aws_smithy_types::DateTime::from_secs(1))
+            .status(SyncExecutionStatus::Failed)
+            .error("States.TaskFailed")
+            .cause("payment declined")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        SFNExtractor::new().extract_output(
+            "SFN",
+            "StartSyncExecution",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_STEP_FUNCTIONS_EXECUTION_STATUS),
+            Some(&Value::from("FAILED"))
+        );
+        assert_eq!(
+            span.get(AWS_STEP_FUNCTIONS_EXECUTION_ERROR),
+            Some(&Value::from("States.TaskFailed"))
+        );
+        assert_eq!(
+            span.get(AWS_STEP_FUNCTIONS_EXECUTION_CAUSE),
+            Some(&Value::from("payment declined"))
+        );
+        assert_eq!(
+            span.status,
+            Some(Status::error("execution FAILED: States.TaskFailed"))
+        );
+    }
+
+    #[test]
+    fn extract_task_token_is_hashed() {
+        let sdk_input = SendTaskFailureInput::builder()
+            .task_token("AAAAKgAAAAIAAAAAAAAAAQ-secret-token")
+            .error("Timeout")
+            .cause("upstream unavailable")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        SFNExtractor::new().extract_input(
+            "SFN",
+            "SendTaskFailure",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_STEP_FUNCTIONS_TASK_TOKEN_SHA256),
+            Some(&Value::from(sha256_hex(
+                "AAAAKgAAAAIAAAAAAAAAAQ-secret-token"
+            )))
+        );
+        assert_eq!(
+            span.get(AWS_STEP_FUNCTIONS_TASK_ERROR),
+            Some(&Value::from("Timeout"))
+        );
+        assert!(span.attributes.iter().all(|(_, value)| {
+            let value = value.as_str();
+            !value.contains("secret-token") && !value.contains("upstream")
+        }));
+    }
+
+    // Tests for trace header injection — 1 consolidated test
+
+    #[test]
+    fn modify_input_injects_trace_header() {
+        use opentelemetry::trace::{SpanId, TraceFlags, TraceId, TraceState};
+
+        let sdk_input = || {
+            StartExecutionInput::builder()
+                .state_machine_arn(STATE_MACHINE_ARN)
+                .build()
+                .unwrap()
+        };
+        let mut span = TestSpan::new();
+        span.span_context = SpanContext::new(
+            TraceId::from_hex("5759e988bd862e3fe1be46a994272793").unwrap(),
+            SpanId::from_hex("53995c3f42cd8ad8").unwrap(),
+            TraceFlags::SAMPLED,
+            false,
+            TraceState::default(),
+        );
+
+        // Disabled by default
+        let mut input = context::Input::erase(sdk_input());
+        SFNExtractor::new().modify_input("SFN", "StartExecution", &mut input, &mut span);
+        assert!(
+            input
+                .downcast_ref::<StartExecutionInput>()
+                .unwrap()
+                .trace_header()
+                .is_none()
+        );
+
+        let extractor = SFNExtractor::new().with_trace_header_injection(true);
+        extractor.modify_input("SFN", "StartExecution", &mut input, &mut span);
+        assert_eq!(
+            input
+                .downcast_ref::<StartExecutionInput>()
+                .unwrap()
+                .trace_header(),
+            Some("Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=1")
+        );
+
+        // A caller-provided header is kept
+        let mut caller = sdk_input();
+        caller.trace_header = Some("custom".to_owned());
+        let mut input = context::Input::erase(caller);
+        extractor.modify_input("SFN", "StartExecution", &mut input, &mut span);
+        assert_eq!(
+            input
+                .downcast_ref::<StartExecutionInput>()
+                .unwrap()
+                .trace_header(),
+            Some("custom")
+        );
+    }
+}
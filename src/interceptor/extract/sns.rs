@@ -0,0 +1,873 @@
+//! SNS attribute extraction following OTel semantic conventions.
+//!
+//! This module provides [`SNSExtractor`], which implements
+//! [`super::super::AttributeExtractor`] for SNS SDK calls. It is automatically
+//! used by [`super::super::DefaultExtractor`] when the `extract-sns` feature is
+//! enabled.
+//!
+//! ## Extracted attributes
+//!
+//! Attributes follow the
+//! [OTel Messaging semconv](https://opentelemetry.io/docs/specs/semconv/messaging/):
+//!
+//! **Always set for every SNS operation:**
+//! - `messaging.system` = `"aws_sns"`
+//! - `messaging.operation.name` — the SDK operation name (e.g. `"Publish"`)
+//!
+//! **Set when a clear mapping exists:**
+//! - `messaging.operation.type` — `"send"` for `Publish`/`PublishBatch`
+//!
+//! **Set for operations that target a topic:**
+//! - `aws.sns.topic.arn` — the topic ARN
+//! - `messaging.destination.name` — the topic name (last segment of the ARN)
+//!
+//! Topic operations are `Publish`, `PublishBatch`, `Subscribe`,
+//! `ConfirmSubscription`, `CreateTopic` (name from the input, ARN from the
+//! output), `DeleteTopic`, `GetTopicAttributes`, `SetTopicAttributes`,
+//! `ListSubscriptionsByTopic`, `AddPermission`, `RemovePermission`, the tagging
+//! operations and the data protection policy operations. `Unsubscribe`,
+//! `GetSubscriptionAttributes` and `SetSubscriptionAttributes` derive the topic
+//! from the subscription ARN.
+//!
+//! **Set from input:**
+//! - `aws.sns.target_arn` — for `Publish` to a mobile platform endpoint
+//! - `messaging.message.body.size` — for `Publish`, size of the message, in bytes
+//! - `aws.sns.message_group_id` — for `Publish` to a FIFO topic
+//! - `aws.sns.subscription.protocol` — for `Subscribe` (the endpoint is not
+//!   recorded: it may be an e-mail address or a phone number)
+//! - `aws.sns.subscription.arn` — for the subscription operations
+//!
+//! Phone numbers given to `Publish` are never recorded.
+//!
+//! **Set from output:**
+//! - `messaging.message.id` — for `Publish`
+//! - `messaging.batch.message_count` — for `PublishBatch`, the number of
+//!   successfully published messages
+//! - `aws.sns.failed_entry_count`, `aws.sns.failed_entry_codes` — for
+//!   `PublishBatch`, the number of failed entries and their distinct error codes
+//! - `aws.sns.subscription.arn` — for `Subscribe`
+//!
+//! `PublishBatch` reports per-entry failures inside a successful response.
+//! When at least one entry failed, the span status is set to error.
+//!
+//! ## Trace context propagation
+//!
+//! When enabled with [`SNSExtractor::with_trace_context_injection`], the
+//! extractor adds the W3C Trace Context `traceparent` (and `tracestate`) message
+//! attributes to every message sent by `Publish` and `PublishBatch`. These are
+//! the attributes the SQS extractor reads on `ReceiveMessage` to link the
+//! receive span back to the producer, so a message fanned out from SNS to an
+//! SQS queue with raw message delivery keeps its trace.
+//!
+//! A message is left untouched when it already carries a `traceparent`
+//! attribute, or when adding the attributes would exceed the SNS limit of 10
+//! message attributes per message.
+
+// SNS attribute extraction — downcasts Input/Output to concrete
+// aws-sdk-sns types and extracts topic ARN, messaging attributes, etc.
+
+use std::collections::{BTreeSet, HashMap};
+
+use aws_sdk_sns::operation::{
+    add_permission::AddPermissionInput,
+    confirm_subscription::ConfirmSubscriptionInput,
+    create_topic::{CreateTopicInput, CreateTopicOutput},
+    delete_topic::DeleteTopicInput,
+    get_data_protection_policy::GetDataProtectionPolicyInput,
+    get_subscription_attributes::GetSubscriptionAttributesInput,
+    get_topic_attributes::GetTopicAttributesInput,
+    list_subscriptions_by_topic::ListSubscriptionsByTopicInput,
+    list_tags_for_resource::ListTagsForResourceInput,
+    publish::{PublishInput, PublishOutput},
+    publish_batch::{PublishBatchInput, PublishBatchOutput},
+    put_data_protection_policy::PutDataProtectionPolicyInput,
+    remove_permission::RemovePermissionInput,
+    set_subscription_attributes::SetSubscriptionAttributesInput,
+    set_topic_attributes::SetTopicAttributesInput,
+    subscribe::{SubscribeInput, SubscribeOutput},
+    tag_resource::TagResourceInput,
+    unsubscribe::UnsubscribeInput,
+    untag_resource::UntagResourceInput,
+};
+use aws_sdk_sns::types::MessageAttributeValue;
+use aws_smithy_runtime_api::client::interceptors::context;
+use opentelemetry::{Array, StringValue, Value};
+use opentelemetry_semantic_conventions::attribute as semco;
+
+use super::super::{AttributeExtractor, SpanWrite};
+use super::propagation::{TRACEPARENT, inject_trace_context};
+use crate::span_write::Status;
+
+/// The well-known `messaging.system` value for Amazon SNS.
+const MESSAGING_SYSTEM_VALUE: &str = "aws_sns";
+
+/// Attribute key for the mobile platform endpoint ARN targeted by `Publish`.
+const AWS_SNS_TARGET_ARN: &str = "aws.sns.target_arn";
+/// Attribute key for the FIFO message group ID.
+const AWS_SNS_MESSAGE_GROUP_ID: &str = "aws.sns.message_group_id";
+/// Attribute key for the subscription ARN.
+const AWS_SNS_SUBSCRIPTION_ARN: &str = "aws.sns.subscription.arn";
+/// Attribute key for the subscription protocol (e.g. `sqs`, `lambda`).
+const AWS_SNS_SUBSCRIPTION_PROTOCOL: &str = "aws.sns.subscription.protocol";
+/// Attribute key for the number of failed entries of `PublishBatch`.
+const AWS_SNS_FAILED_ENTRY_COUNT: &str = "aws.sns.failed_entry_count";
+/// Attribute key for the distinct error codes of the failed entries of `PublishBatch`.
+const AWS_SNS_FAILED_ENTRY_CODES: &str = "aws.sns.failed_entry_codes";
+
+/// Maximum number of message attributes SNS accepts on a single message.
+const MAX_MESSAGE_ATTRIBUTES: usize = 10;
+
+/// Attribute extractor for SNS SDK calls.
+///
+/// `SNSExtractor` implements [`AttributeExtractor`] and is automatically used
+/// by [`DefaultExtractor`] when the `extract-sns` feature is enabled. Construct
+/// it directly to enable trace context injection, then hand it to
+/// [`DefaultExtractor::set_sns_extractor`].
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes.
+///
+/// [`DefaultExtractor`]: crate::interceptor::DefaultExtractor
+/// [`DefaultExtractor::set_sns_extractor`]: crate::interceptor::DefaultExtractor::set_sns_extractor
+#[derive(Debug, Default)]
+pub struct SNSExtractor {
+    inject_trace_context: bool,
+}
+
+impl SNSExtractor {
+    /// Creates a new `SNSExtractor`, with trace context injection disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::extract::sns::SNSExtractor;
+    ///
+    /// let extractor = SNSExtractor::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables or disables the injection of the W3C Trace Context message
+    /// attributes into published messages.
+    ///
+    /// Injection adds up to two message attributes to each message, which count
+    /// towards the SNS limit of 10 attributes per message and are delivered to
+    /// every subscriber.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::{
+    ///     DefaultInterceptor, extract::sns::SNSExtractor,
+    /// };
+    ///
+    /// let mut interceptor = DefaultInterceptor::new();
+    /// interceptor
+    ///     .extractor
+    ///     .set_sns_extractor(SNSExtractor::new().with_trace_context_injection(true));
+    /// ```
+    pub fn with_trace_context_injection(mut self, inject: bool) -> Self {
+        self.inject_trace_context = inject;
+        self
+    }
+}
+
+/// Extracts SNS-specific OTel attributes from SDK inputs and outputs, and
+/// optionally injects trace context into published messages.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes and which operations they apply to.
+impl<SW: SpanWrite> AttributeExtractor<SW> for SNSExtractor {
+    fn extract_input(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        input: &context::Input,
+        span: &mut SW,
+    ) {
+        // Set messaging.system for all SNS operations
+        span.set_attribute(semco::MESSAGING_SYSTEM, MESSAGING_SYSTEM_VALUE);
+
+        // Set messaging.operation.name (the SDK operation name, e.g. "Publish")
+        span.set_attribute(semco::MESSAGING_OPERATION_NAME, operation.to_owned());
+
+        // Set messaging.operation.type when a clear mapping exists
+        if let Some(op_type) = operation_type(operation) {
+            span.set_attribute(semco::MESSAGING_OPERATION_TYPE, op_type);
+        }
+
+        match operation {
+            "Publish" => {
+                let i = input.downcast_ref::<PublishInput>().expect("correct type");
+                set_topic_arn_attrs(span, i.topic_arn());
+                if let Some(target_arn) = i.target_arn() {
+                    span.set_attribute(AWS_SNS_TARGET_ARN, target_arn.to_owned());
+                }
+                if let Some(message) = i.message() {
+                    span.set_attribute(semco::MESSAGING_MESSAGE_BODY_SIZE, message.len() as i64);
+                }
+                if let Some(group_id) = i.message_group_id() {
+                    span.set_attribute(AWS_SNS_MESSAGE_GROUP_ID, group_id.to_owned());
+                }
+            }
+            "PublishBatch" => set_topic_arn_attrs(
+                span,
+                input
+                    .downcast_ref::<PublishBatchInput>()
+                    .expect("correct type")
+                    .topic_arn(),
+            ),
+            "Subscribe" => {
+                let i = input
+                    .downcast_ref::<SubscribeInput>()
+                    .expect("correct type");
+                set_topic_arn_attrs(span, i.topic_arn());
+                if let Some(protocol) = i.protocol() {
+                    span.set_attribute(AWS_SNS_SUBSCRIPTION_PROTOCOL, protocol.to_owned());
+                }
+            }
+            "ConfirmSubscription" => set_topic_arn_attrs(
+                span,
+                input
+                    .downcast_ref::<ConfirmSubscriptionInput>()
+                    .expect("correct type")
+                    .topic_arn(),
+            ),
+            "CreateTopic" => {
+                if let Some(name) = input
+                    .downcast_ref::<CreateTopicInput>()
+                    .expect("correct type")
+                    .name()
+                {
+                    span.set_attribute(semco::MESSAGING_DESTINATION_NAME, name.to_owned());
+                }
+            }
+            "DeleteTopic" => set_topic_arn_attrs(
+                span,
+                input
+                    .downcast_ref::<DeleteTopicInput>()
+                    .expect("correct type")
+                    .topic_arn(),
+            ),
+            "GetTopicAttributes" => set_topic_arn_attrs(
+                span,
+                input
+                    .downcast_ref::<GetTopicAttributesInput>()
+                    .expect("correct type")
+                    .topic_arn(),
+            ),
+            "SetTopicAttributes" => set_topic_arn_attrs(
+                span,
+                input
+                    .downcast_ref::<SetTopicAttributesInput>()
+                    .expect("correct type")
+                    .topic_arn(),
+            ),
+            "ListSubscriptionsByTopic" => set_topic_arn_attrs(
+                span,
+                input
+                    .downcast_ref::<ListSubscriptionsByTopicInput>()
+                    .expect("correct type")
+                    .topic_arn(),
+            ),
+            "AddPermission" => set_topic_arn_attrs(
+                span,
+                input
+                    .downcast_ref::<AddPermissionInput>()
+                    .expect("correct type")
+                    .topic_arn(),
+            ),
+            "RemovePermission" => set_topic_arn_attrs(
+                span,
+                input
+                    .downcast_ref::<RemovePermissionInput>()
+                    .expect("correct type")
+                    .topic_arn(),
+            ),
+            // SNS only tags topics, so the resource ARN is a topic ARN
+            "TagResource" => set_topic_arn_attrs(
+                span,
+                input
+                    .downcast_ref::<TagResourceInput>()
+                    .expect("correct type")
+                    .resource_arn(),
+            ),
+            "UntagResource" => set_topic_arn_attrs(
+                span,
+                input
+                    .downcast_ref::<UntagResourceInput>()
+                    .expect("correct type")
+                    .resource_arn(),
+            ),
+            "ListTagsForResource" => set_topic_arn_attrs(
+                span,
+                input
+                    .downcast_ref::<ListTagsForResourceInput>()
+                    .expect("correct type")
+                    .resource_arn(),
+            ),
+            "GetDataProtectionPolicy" => set_topic_arn_attrs(
+                span,
+                input
+                    .downcast_ref::<GetDataProtectionPolicyInput>()
+                    .expect("correct type")
+                    .resource_arn(),
+            ),
+            "PutDataProtectionPolicy" => set_topic_arn_attrs(
+                span,
+                input
+                    .downcast_ref::<PutDataProtectionPolicyInput>()
+                    .expect("correct type")
+                    .resource_arn(),
+            ),
+            "Unsubscribe" => set_subscription_arn_attrs(
+                span,
+                input
+                    .downcast_ref::<UnsubscribeInput>()
+                    .expect("correct type")
+                    .subscription_arn(),
+            ),
+            "GetSubscriptionAttributes" => set_subscription_arn_attrs(
+                span,
+                input
+                    .downcast_ref::<GetSubscriptionAttributesInput>()
+                    .expect("correct type")
+                    .subscription_arn(),
+            ),
+            "SetSubscriptionAttributes" => set_subscription_arn_attrs(
+                span,
+                input
+                    .downcast_ref::<SetSubscriptionAttributesInput>()
+                    .expect("correct type")
+                    .subscription_arn(),
+            ),
+            // Platform application, SMS and listing operations do not target a topic
+            _ => {}
+        };
+    }
+
+    fn modify_input(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        input: &mut context::Input,
+        span: &mut SW,
+    ) {
+        if !self.inject_trace_context {
+            return;
+        }
+        let carrier = inject_trace_context(&span.span_context());
+        if carrier.is_empty() {
+            return;
+        }
+        match operation {
+            "Publish" => {
+                let i = input.downcast_mut::<PublishInput>().expect("correct type");
+                inject_message_attributes(&mut i.message_attributes, &carrier);
+            }
+            "PublishBatch" => {
+                let i = input
+                    .downcast_mut::<PublishBatchInput>()
+                    .expect("correct type");
+                for entry in i.publish_batch_request_entries.iter_mut().flatten() {
+                    inject_message_attributes(&mut entry.message_attributes, &carrier);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn extract_output(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        output: &context::Output,
+        span: &mut SW,
+    ) {
+        match operation {
+            "Publish" => {
+                if let Some(message_id) = output
+                    .downcast_ref::<PublishOutput>()
+                    .expect("correct type")
+                    .message_id()
+                {
+                    span.set_attribute(semco::MESSAGING_MESSAGE_ID, message_id.to_owned());
+                }
+            }
+            "PublishBatch" => {
+                if let Some(output) = output.downcast_ref::<PublishBatchOutput>() {
+                    let count = output.successful().len();
+                    span.set_attribute(semco::MESSAGING_BATCH_MESSAGE_COUNT, count as i64);
+                    set_failed_entries(
+                        span,
+                        count,
+                        output.failed().iter().map(|entry| entry.code()),
+                    );
+                }
+            }
+            "Subscribe" => {
+                if let Some(subscription_arn) = output
+                    .downcast_ref::<SubscribeOutput>()
+                    .expect("correct type")
+                    .subscription_arn()
+                {
+                    span.set_attribute(AWS_SNS_SUBSCRIPTION_ARN, subscription_arn.to_owned());
+                }
+            }
+            "CreateTopic" => set_topic_arn_attrs(
+                span,
+                output
+                    .downcast_ref::<CreateTopicOutput>()
+                    .expect("correct type")
+                    .topic_arn(),
+            ),
+            _ => {}
+        }
+    }
+}
+
+/// Maps SNS operation names to OTel `messaging.operation.type` values.
+///
+/// Only publishing operations map to a messaging operation type; topic and
+/// subscription management operations return `None`.
+fn operation_type(operation: &str) -> Option<&'static str> {
+    match operation {
+        "Publish" | "PublishBatch" => Some("send"),
+        _ => None,
+    }
+}
+
+/// Sets `aws.sns.topic.arn` and `messaging.destination.name` (the topic name
+/// extracted from the last segment of the ARN).
+fn set_topic_arn_attrs(span: &mut impl SpanWrite, topic_arn: Option<&str>) {
+    if let Some(arn) = topic_arn {
+        span.set_attribute(semco::AWS_SNS_TOPIC_ARN, arn.to_owned());
+
+        // SNS topic ARNs follow the pattern:
+        //   arn:<partition>:sns:<region>:<account-id>:<topic-name>
+        // The topic name is the last colon-separated segment.
+        if let Some(topic_name) = arn.rsplit(':').next().filter(|s| !s.is_empty()) {
+            span.set_attribute(semco::MESSAGING_DESTINATION_NAME, topic_name.to_owned());
+        }
+    }
+}
+
+/// Sets `aws.sns.subscription.arn` and the attributes of the topic the
+/// subscription belongs to.
+fn set_subscription_arn_attrs(span: &mut impl SpanWrite, subscription_arn: Option<&str>) {
+    if let Some(arn) = subscription_arn {
+        span.set_attribute(AWS_SNS_SUBSCRIPTION_ARN, arn.to_owned());
+
+        // Subscription ARNs are the topic ARN followed by a subscription ID:
+        //   arn:<partition>:sns:<region>:<account-id>:<topic-name>:<subscription-id>
+        if let Some((topic_arn, _)) = arn.rsplit_once(':') {
+            if topic_arn.matches(':').count() == 5 {
+                set_topic_arn_attrs(span, Some(topic_arn));
+            }
+        }
+    }
+}
+
+/// Sets the failed-entry count and distinct error codes of `PublishBatch`, and
+/// an error status when at least one of its entries failed.
+fn set_failed_entries<'a>(
+    span: &mut impl SpanWrite,
+    successful: usize,
+    failed_codes: impl ExactSizeIterator<Item = &'a str>,
+) {
+    let failed = failed_codes.len();
+    span.set_attribute(AWS_SNS_FAILED_ENTRY_COUNT, failed as i64);
+    if failed == 0 {
+        return;
+    }
+    let codes = failed_codes.collect::<BTreeSet<_>>();
+    span.set_attribute(
+        AWS_SNS_FAILED_ENTRY_CODES,
+        Value::Array(Array::String(
+            codes
+                .into_iter()
+                .map(|code| StringValue::from(code.to_owned()))
+                .collect(),
+        )),
+    );
+    span.set_status(Status::error(format!(
+        "{} of {} batch entries failed",
+        failed,
+        failed + successful
+    )));
+}
+
+/// Adds the trace context `carrier` entries to the message attributes of one
+/// message, unless it already carries a `traceparent` or the SNS attribute
+/// limit would be exceeded.
+fn inject_message_attributes(
+    message_attributes: &mut Option<HashMap<String, MessageAttributeValue>>,
+    carrier: &[(&'static str, String)],
+) {
+    let existing = message_attributes.as_ref().map_or(0, HashMap::len);
+    let has_traceparent = message_attributes
+        .as_ref()
+        .is_some_and(|attributes| attributes.contains_key(TRACEPARENT));
+    if has_traceparent || existing + carrier.len() > MAX_MESSAGE_ATTRIBUTES {
+        log::debug!("not injecting trace context into SNS message attributes");
+        return;
+    }
+    let attributes = message_attributes.get_or_insert_with(HashMap::new);
+    for (name, value) in carrier {
+        if let Ok(value) = MessageAttributeValue::builder()
+            .data_type("String")
+            .string_value(value)
+            .build()
+        {
+            attributes.insert((*name).to_owned(), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::Value;
+    use opentelemetry_semantic_conventions::attribute as semco;
+
+    use crate::span_write::{SpanContext, SpanWrite, Status};
+
+    struct TestSpan {
+        attributes: Vec<(&'static str, Value)>,
+        status: Option<Status>,
+        span_context: SpanContext,
+    }
+
+    impl TestSpan {
+        fn new() -> Self {
+            Self {
+                attributes: vec![],
+                status: None,
+                span_context: SpanContext::empty_context(),
+            }
+        }
+
+        fn get(&self, key: &str) -> Option<&Value> {
+            self.attributes
+                .iter()
+                .rev()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v)
+        }
+    }
+
+    impl SpanWrite for TestSpan {
+        fn set_attribute(&mut self, key: &'static str, value: impl Into<Value>) {
+            self.attributes.push((key, value.into()));
+        }
+
+        fn set_status(&mut self, code: Status) {
+            self.status = Some(code);
+        }
+
+        fn span_context(&self) -> SpanContext {
+            self.span_context.clone()
+        }
+    }
+
+    const TOPIC_ARN: &str = "arn:aws:sns:us-east-1:123456789012:orders";
+
+    // Tests for SNSExtractor::extract_input / extract_output — 2 consolidated tests
+
+    #[test]
+    fn extract_publish_input_and_output() {
+        let extractor = SNSExtractor::new();
+
+        let sdk_input = PublishInput::builder()
+            .topic_arn(TOPIC_ARN)
+            .message("héllo")
+            .message_group_id("g1")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "SNS",
+            "Publish",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(semco::MESSAGING_SYSTEM),
+            Some(&Value::from("aws_sns"))
+        );
+        assert_eq!(
+            span.get(semco::MESSAGING_OPERATION_TYPE),
+            Some(&Value::from("send"))
+        );
+        assert_eq!(
+            span.get(semco::AWS_SNS_TOPIC_ARN),
+            Some(&Value::from(TOPIC_ARN))
+        );
+        assert_eq!(
+            span.get(semco::MESSAGING_DESTINATION_NAME),
+            Some(&Value::from("orders"))
+        );
+        assert_eq!(
+            span.get(semco::MESSAGING_MESSAGE_BODY_SIZE),
+            Some(&Value::I64(6))
+        );
+        assert_eq!(span.get(AWS_SNS_MESSAGE_GROUP_ID), Some(&Value::from("g1")));
+
+        let output = context::Output::erase(PublishOutput::builder().message_id("m-1").build());
+        extractor.extract_output("SNS", "Publish", &output, &mut span);
+        assert_eq!(
+            span.get(semco::MESSAGING_MESSAGE_ID),
+            Some(&Value::from("m-1"))
+        );
+
+        // Publishing by phone number records no destination at all
+        let sdk_input = PublishInput::builder()
+            .phone_number("+15555550100")
+            .message("x")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "SNS",
+            "Publish",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert!(span.get(semco::MESSAGING_DESTINATION_NAME).is_none());
+        assert!(
+            span.attributes
+                .iter()
+                .all(|(_, value)| !value.as_str().contains("5555550100"))
+        );
+    }
+
+    #[test]
+    fn extract_batch_and_subscription_operations() {
+        use aws_sdk_sns::types::{BatchResultErrorEntry, PublishBatchResultEntry};
+
+        let extractor = SNSExtractor::new();
+
+        let sdk_output = PublishBatchOutput::builder()
+            .successful(PublishBatchResultEntry::builder().id("1").build())
+            .failed(
+                BatchResultErrorEntry::builder()
+                    .id("2")
+                    .code("InvalidParameter")
+                    .sender_fault(true)
+                    .build()
+                    .unwrap(),
+            )
+            .build();
+        let mut span = TestSpan::new();
+        extractor.extract_output(
+            "SNS",
+            "PublishBatch",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(semco::MESSAGING_BATCH_MESSAGE_COUNT),
+            Some(&Value::I64(1))
+        );
+        assert_eq!(span.get(AWS_SNS_FAILED_ENTRY_COUNT), Some(&Value::I64(1)));
+        assert_eq!(
+            span.get(AWS_SNS_FAILED_ENTRY_CODES),
+            Some(&Value::Array(Array::String(vec![
+                "InvalidParameter".into()
+            ])))
+        );
+        assert_eq!(
+            span.status,
+            Some(Status::error("1 of 2 batch entries failed"))
+        );
+
+        // Subscribe: protocol recorded, endpoint never recorded
+        let sdk_input = SubscribeInput::builder()
+            .topic_arn(TOPIC_ARN)
+            .protocol("email")
+            .endpoint("someone@example.com")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "SNS",
+            "Subscribe",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_SNS_SUBSCRIPTION_PROTOCOL),
+            Some(&Value::from("email"))
+        );
+        assert!(span.get(semco::MESSAGING_OPERATION_TYPE).is_none());
+        assert!(
+            span.attributes
+                .iter()
+                .all(|(_, value)| !value.as_str().contains("example.com"))
+        );
+
+        // Unsubscribe: topic derived from the subscription ARN
+        let subscription_arn = format!("{TOPIC_ARN}:0a1b2c3d-0000-1111-2222-333344445555");
+        let sdk_input = UnsubscribeInput::builder()
+            .subscription_arn(&subscription_arn)
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "SNS",
+            "Unsubscribe",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_SNS_SUBSCRIPTION_ARN),
+            Some(&Value::from(subscription_arn))
+        );
+        assert_eq!(
+            span.get(semco::AWS_SNS_TOPIC_ARN),
+            Some(&Value::from(TOPIC_ARN))
+        );
+
+        // CreateTopic: name from the input, ARN from the output
+        let sdk_input = CreateTopicInput::builder().name("orders").build().unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "SNS",
+            "CreateTopic",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(semco::MESSAGING_DESTINATION_NAME),
+            Some(&Value::from("orders"))
+        );
+        let output =
+            context::Output::erase(CreateTopicOutput::builder().topic_arn(TOPIC_ARN).build());
+        extractor.extract_output("SNS", "CreateTopic", &output, &mut span);
+        assert_eq!(
+            span.get(semco::AWS_SNS_TOPIC_ARN),
+            Some(&Value::from(TOPIC_ARN))
+        );
+    }
+
+    // Tests for trace context injection — 2 consolidated tests
+
+    #[test]
+    fn modify_input_injects_trace_context() {
+        use aws_sdk_sns::types::PublishBatchRequestEntry;
+        use opentelemetry::trace::{SpanId, TraceFlags, TraceId, TraceState};
+
+        let mut span = TestSpan::new();
+        span.span_context = SpanContext::new(
+            TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736").unwrap(),
+            SpanId::from_hex("00f067aa0ba902b7").unwrap(),
+            TraceFlags::SAMPLED,
+            false,
+            TraceState::default(),
+        );
+        let traceparent = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+        let extractor = SNSExtractor::new().with_trace_context_injection(true);
+
+        let mut input = context::Input::erase(
+            PublishInput::builder()
+                .topic_arn(TOPIC_ARN)
+                .message("x")
+                .build()
+                .unwrap(),
+        );
+        extractor.modify_input("SNS", "Publish", &mut input, &mut span);
+        let attributes = input
+            .downcast_ref::<PublishInput>()
+            .unwrap()
+            .message_attributes()
+            .unwrap();
+        assert_eq!(attributes.len(), 1);
+        assert_eq!(attributes[TRACEPARENT].data_type(), "String");
+        assert_eq!(attributes[TRACEPARENT].string_value(), Some(traceparent));
+
+        let entry = |id: &str| {
+            PublishBatchRequestEntry::builder()
+                .id(id)
+                .message("x")
+                .build()
+                .unwrap()
+        };
+        let mut input = context::Input::erase(
+            PublishBatchInput::builder()
+                .topic_arn(TOPIC_ARN)
+                .publish_batch_request_entries(entry("1"))
+                .publish_batch_request_entries(entry("2"))
+                .build()
+                .unwrap(),
+        );
+        extractor.modify_input("SNS", "PublishBatch", &mut input, &mut span);
+        let entries = input
+            .downcast_ref::<PublishBatchInput>()
+            .unwrap()
+            .publish_batch_request_entries();
+        assert!(entries.iter().all(|entry| {
+            entry.message_attributes().unwrap()[TRACEPARENT].string_value() == Some(traceparent)
+        }));
+    }
+
+    #[test]
+    fn modify_input_leaves_messages_untouched() {
+        let string_value = |value: &str| {
+            MessageAttributeValue::builder()
+                .data_type("String")
+                .string_value(value)
+                .build()
+                .unwrap()
+        };
+
+        // Injection disabled (the default)
+        let mut input = context::Input::erase(
+            PublishInput::builder()
+                .topic_arn(TOPIC_ARN)
+                .message("x")
+                .build()
+                .unwrap(),
+        );
+        SNSExtractor::new().modify_input("SNS", "Publish", &mut input, &mut TestSpan::new());
+        assert!(
+            input
+                .downcast_ref::<PublishInput>()
+                .unwrap()
+                .message_attributes()
+                .is_none()
+        );
+
+        // Invalid span context: nothing to inject
+        let extractor = SNSExtractor::new().with_trace_context_injection(true);
+        extractor.modify_input("SNS", "Publish", &mut input, &mut TestSpan::new());
+        assert!(
+            input
+                .downcast_ref::<PublishInput>()
+                .unwrap()
+                .message_attributes()
+                .is_none()
+        );
+
+        // Existing traceparent is preserved
+        let carrier = [(TRACEPARENT, "00-new-context".to_owned())];
+        let mut attributes = Some(HashMap::from([(
+            TRACEPARENT.to_owned(),
+            string_value("00-original"),
+        )]));
+        inject_message_attributes(&mut attributes, &carrier);
+        assert_eq!(
+            attributes.as_ref().unwrap()[TRACEPARENT].string_value(),
+            Some("00-original")
+        );
+
+        // Attribute limit reached
+        let mut attributes = Some(
+            (0..MAX_MESSAGE_ATTRIBUTES)
+                .map(|i| (format!("attr{i}"), string_value("v")))
+                .collect::<HashMap<_, _>>(),
+        );
+        inject_message_attributes(&mut attributes, &carrier);
+        assert!(!attributes.unwrap().contains_key(TRACEPARENT));
+    }
+}
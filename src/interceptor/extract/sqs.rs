@@ -22,9 +22,75 @@
 //! - `aws.sqs.queue.url` — the full queue URL
 //! - `messaging.destination.name` — the queue name (last path segment of the URL)
 //!
+//! Operations that take no queue URL name the queue differently:
+//! `CreateQueue` and `GetQueueUrl` set `messaging.destination.name` from the
+//! `QueueName` input and `aws.sqs.queue.url` from the returned queue URL, while
+//! `StartMessageMoveTask` and `ListMessageMoveTasks` take the queue name from
+//! the `SourceArn` input (the dead-letter queue the messages are moved out of).
+//! `ListQueues` and `CancelMessageMoveTask` do not target a queue and get
+//! neither attribute.
+//!
+//! **Set from `SendMessage` input:**
+//! - `messaging.message.body.size` — size of the message body, in bytes
+//! - `aws.sqs.message_group_id` — the FIFO message group ID
+//! - `aws.sqs.message_deduplication_id_present` — for FIFO queues (URL ending
+//!   in `.fifo`), whether an explicit deduplication ID was given (the ID
+//!   itself is not recorded)
+//! - `aws.sqs.delay_seconds` — the requested delivery delay
+//!
+//! **Set from `SendMessageBatch` input,** aggregated over the entries:
+//! - `aws.sqs.batch_body_size` — total size of the message bodies, in bytes
+//! - `aws.sqs.message_group_id` — when all entries share the same group
+//! - `aws.sqs.message_deduplication_id_present` — for FIFO queues, whether an
+//!   explicit deduplication ID was given for any entry
+//! - `aws.sqs.delay_seconds` — the longest delivery delay requested by an entry
+//!
 //! **Set from output:**
-//! - `messaging.message.id` — for `SendMessage`
+//! - `messaging.message.id` — for `SendMessage`, and for `ReceiveMessage` when
+//!   exactly one message was received
 //! - `messaging.batch.message_count` — for `SendMessageBatch` and `ReceiveMessage`
+//! - `aws.sqs.message_ids` — for `ReceiveMessage`, the IDs of all received
+//!   messages
+//! - `messaging.message.body.size` — for `ReceiveMessage` when exactly one
+//!   message was received
+//! - `aws.sqs.failed_entry_count`, `aws.sqs.failed_entry_codes` — for
+//!   `SendMessageBatch`, `DeleteMessageBatch` and `ChangeMessageVisibilityBatch`,
+//!   the number of failed entries and their distinct error codes
+//!
+//! Batch operations report per-entry failures inside a successful response.
+//! When at least one entry failed, the span status is set to error.
+//!
+//! ## Receive span links
+//!
+//! Following the messaging semconv, the `ReceiveMessage` span gets one span link
+//! per received message that carries a producer trace context, with the
+//! `messaging.message.id` of the message as link attribute. The context is read
+//! from:
+//!
+//! - the `AWSTraceHeader` system attribute, set by SQS when the producer was
+//!   traced with X-Ray or forwarded an X-Ray trace header
+//! - the `traceparent` (and optional `tracestate`) message attributes, for
+//!   producers using W3C Trace Context propagation
+//!
+//! SQS only returns these attributes when the receive request asks for them,
+//! and the extractor never changes requests. Callers that want the links must
+//! request them explicitly:
+//!
+//! ```no_run
+//! use aws_sdk_sqs::types::MessageSystemAttributeName;
+//!
+//! # async fn example(client: aws_sdk_sqs::Client) -> Result<(), aws_sdk_sqs::Error> {
+//! let output = client
+//!     .receive_message()
+//!     .queue_url("https://sqs.us-east-1.amazonaws.com/123456789012/my-queue")
+//!     .message_system_attribute_names(MessageSystemAttributeName::AwsTraceHeader)
+//!     .message_attribute_names("traceparent")
+//!     .message_attribute_names("tracestate")
+//!     .send()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
 
 // SQS attribute extraction — downcasts Input/Output to concrete
 // aws-sdk-sqs types and extracts queue URL, messaging attributes, etc.
@@ -32,12 +98,17 @@
 use aws_sdk_sqs::operation::{
     add_permission::AddPermissionInput,
     change_message_visibility::ChangeMessageVisibilityInput,
-    change_message_visibility_batch::ChangeMessageVisibilityBatchInput,
+    change_message_visibility_batch::{
+        ChangeMessageVisibilityBatchInput, ChangeMessageVisibilityBatchOutput,
+    },
+    create_queue::{CreateQueueInput, CreateQueueOutput},
     delete_message::DeleteMessageInput,
-    delete_message_batch::DeleteMessageBatchInput,
+    delete_message_batch::{DeleteMessageBatchInput, DeleteMessageBatchOutput},
     delete_queue::DeleteQueueInput,
     get_queue_attributes::GetQueueAttributesInput,
+    get_queue_url::{GetQueueUrlInput, GetQueueUrlOutput},
     list_dead_letter_source_queues::ListDeadLetterSourceQueuesInput,
+    list_message_move_tasks::ListMessageMoveTasksInput,
     list_queue_tags::ListQueueTagsInput,
     purge_queue::PurgeQueueInput,
     receive_message::{ReceiveMessageInput, ReceiveMessageOutput},
@@ -45,17 +116,41 @@ use aws_sdk_sqs::operation::{
     send_message::{SendMessageInput, SendMessageOutput},
     send_message_batch::{SendMessageBatchInput, SendMessageBatchOutput},
     set_queue_attributes::SetQueueAttributesInput,
+    start_message_move_task::StartMessageMoveTaskInput,
     tag_queue::TagQueueInput,
     untag_queue::UntagQueueInput,
 };
+use std::collections::BTreeSet;
+
+use aws_sdk_sqs::types::{BatchResultErrorEntry, Message, MessageSystemAttributeName};
 use aws_smithy_runtime_api::client::interceptors::context;
+use opentelemetry::{Array, StringValue, Value, trace::TraceState};
 use opentelemetry_semantic_conventions::attribute as semco;
 
 use super::super::{AttributeExtractor, SpanWrite};
+use super::propagation::{TRACEPARENT, TRACESTATE, extract_trace_context};
+use crate::span_write::{KeyValue, SpanContext, Status};
+use crate::xray::XRayTraceHeader;
 
 /// The well-known `messaging.system` value for Amazon SQS.
 const MESSAGING_SYSTEM_VALUE: &str = "aws_sqs";
 
+/// Attribute key for the IDs of the messages returned by `ReceiveMessage`.
+const AWS_SQS_MESSAGE_IDS: &str = "aws.sqs.message_ids";
+
+/// Attribute key for the FIFO message group ID.
+const AWS_SQS_MESSAGE_GROUP_ID: &str = "aws.sqs.message_group_id";
+/// Attribute key flagging that an explicit FIFO deduplication ID was given.
+const AWS_SQS_MESSAGE_DEDUPLICATION_ID_PRESENT: &str = "aws.sqs.message_deduplication_id_present";
+/// Attribute key for the requested message delivery delay, in seconds.
+const AWS_SQS_DELAY_SECONDS: &str = "aws.sqs.delay_seconds";
+/// Attribute key for the total size of the message bodies of a batch, in bytes.
+const AWS_SQS_BATCH_BODY_SIZE: &str = "aws.sqs.batch_body_size";
+/// Attribute key for the number of failed entries of a batch operation.
+const AWS_SQS_FAILED_ENTRY_COUNT: &str = "aws.sqs.failed_entry_count";
+/// Attribute key for the distinct error codes of the failed entries of a batch operation.
+const AWS_SQS_FAILED_ENTRY_CODES: &str = "aws.sqs.failed_entry_codes";
+
 /// Attribute extractor for SQS SDK calls.
 ///
 /// `SQSExtractor` implements [`AttributeExtractor`] and is automatically used
@@ -112,20 +207,66 @@ impl<SW: SpanWrite> AttributeExtractor<SW> for SQSExtractor {
 
         // Extract and set queue URL + destination name for every operation that has it
         match operation {
-            "SendMessage" => set_queue_url_attrs(
-                span,
-                input
+            "SendMessage" => {
+                let i = input
                     .downcast_ref::<SendMessageInput>()
-                    .expect("correct type")
-                    .queue_url(),
-            ),
-            "SendMessageBatch" => set_queue_url_attrs(
-                span,
-                input
+                    .expect("correct type");
+                set_queue_url_attrs(span, i.queue_url());
+                if let Some(body) = i.message_body() {
+                    span.set_attribute(semco::MESSAGING_MESSAGE_BODY_SIZE, body.len() as i64);
+                }
+                if let Some(group_id) = i.message_group_id() {
+                    span.set_attribute(AWS_SQS_MESSAGE_GROUP_ID, group_id.to_owned());
+                }
+                if is_fifo_queue(i.queue_url()) {
+                    span.set_attribute(
+                        AWS_SQS_MESSAGE_DEDUPLICATION_ID_PRESENT,
+                        i.message_deduplication_id().is_some(),
+                    );
+                }
+                if let Some(delay_seconds) = i.delay_seconds() {
+                    span.set_attribute(AWS_SQS_DELAY_SECONDS, i64::from(delay_seconds));
+                }
+            }
+            "SendMessageBatch" => {
+                let i = input
                     .downcast_ref::<SendMessageBatchInput>()
-                    .expect("correct type")
-                    .queue_url(),
-            ),
+                    .expect("correct type");
+                set_queue_url_attrs(span, i.queue_url());
+                // A batch may span several message groups: only record the
+                // group when it is shared by every entry
+                let group_ids = i
+                    .entries()
+                    .iter()
+                    .map(|entry| entry.message_group_id())
+                    .collect::<BTreeSet<_>>();
+                if let [Some(group_id)] = group_ids.into_iter().collect::<Vec<_>>().as_slice() {
+                    span.set_attribute(AWS_SQS_MESSAGE_GROUP_ID, group_id.to_string());
+                }
+                let body_size = i
+                    .entries()
+                    .iter()
+                    .map(|entry| entry.message_body())
+                    .map(str::len)
+                    .sum::<usize>();
+                span.set_attribute(AWS_SQS_BATCH_BODY_SIZE, body_size as i64);
+                if is_fifo_queue(i.queue_url()) {
+                    span.set_attribute(
+                        AWS_SQS_MESSAGE_DEDUPLICATION_ID_PRESENT,
+                        i.entries()
+                            .iter()
+                            .any(|entry| entry.message_deduplication_id().is_some()),
+                    );
+                }
+                if let Some(delay_seconds) = i
+                    .entries()
+                    .iter()
+                    .filter_map(|entry| entry.delay_seconds())
+                    .max()
+                {
+                    span.set_attribute(AWS_SQS_DELAY_SECONDS, i64::from(delay_seconds));
+                }
+            }
             "ReceiveMessage" => set_queue_url_attrs(
                 span,
                 input
@@ -231,9 +372,36 @@ impl<SW: SpanWrite> AttributeExtractor<SW> for SQSExtractor {
                     .expect("correct type")
                     .queue_url(),
             ),
-            // Operations without queue_url: CreateQueue, GetQueueUrl,
-            // ListQueues, CancelMessageMoveTask, ListMessageMoveTasks,
-            // StartMessageMoveTask
+            // Operations without queue_url name the queue or its ARN instead
+            "CreateQueue" => set_queue_name(
+                span,
+                input
+                    .downcast_ref::<CreateQueueInput>()
+                    .expect("correct type")
+                    .queue_name(),
+            ),
+            "GetQueueUrl" => set_queue_name(
+                span,
+                input
+                    .downcast_ref::<GetQueueUrlInput>()
+                    .expect("correct type")
+                    .queue_name(),
+            ),
+            "StartMessageMoveTask" => set_queue_arn_attrs(
+                span,
+                input
+                    .downcast_ref::<StartMessageMoveTaskInput>()
+                    .expect("correct type")
+                    .source_arn(),
+            ),
+            "ListMessageMoveTasks" => set_queue_arn_attrs(
+                span,
+                input
+                    .downcast_ref::<ListMessageMoveTasksInput>()
+                    .expect("correct type")
+                    .source_arn(),
+            ),
+            // ListQueues and CancelMessageMoveTask do not target a single queue
             _ => {}
         };
     }
@@ -259,12 +427,32 @@ impl<SW: SpanWrite> AttributeExtractor<SW> for SQSExtractor {
                 if let Some(output) = output.downcast_ref::<SendMessageBatchOutput>() {
                     let count = output.successful().len();
                     span.set_attribute(semco::MESSAGING_BATCH_MESSAGE_COUNT, count as i64);
+                    set_failed_entries(span, count, output.failed());
+                }
+            }
+            "CreateQueue" => {
+                if let Some(output) = output.downcast_ref::<CreateQueueOutput>() {
+                    set_queue_url_attrs(span, output.queue_url());
+                }
+            }
+            "GetQueueUrl" => {
+                if let Some(output) = output.downcast_ref::<GetQueueUrlOutput>() {
+                    set_queue_url_attrs(span, output.queue_url());
+                }
+            }
+            "DeleteMessageBatch" => {
+                if let Some(output) = output.downcast_ref::<DeleteMessageBatchOutput>() {
+                    set_failed_entries(span, output.successful().len(), output.failed());
+                }
+            }
+            "ChangeMessageVisibilityBatch" => {
+                if let Some(output) = output.downcast_ref::<ChangeMessageVisibilityBatchOutput>() {
+                    set_failed_entries(span, output.successful().len(), output.failed());
                 }
             }
             "ReceiveMessage" => {
                 if let Some(output) = output.downcast_ref::<ReceiveMessageOutput>() {
-                    let count = output.messages().len();
-                    span.set_attribute(semco::MESSAGING_BATCH_MESSAGE_COUNT, count as i64);
+                    extract_receive_message_output(output.messages(), span);
                 }
             }
             _ => {}
@@ -272,6 +460,115 @@ impl<SW: SpanWrite> AttributeExtractor<SW> for SQSExtractor {
     }
 }
 
+/// Records the received message count and IDs, and links the span to the
+/// producer context of each message.
+fn extract_receive_message_output(messages: &[Message], span: &mut impl SpanWrite) {
+    span.set_attribute(semco::MESSAGING_BATCH_MESSAGE_COUNT, messages.len() as i64);
+
+    let message_ids = messages
+        .iter()
+        .filter_map(|message| message.message_id())
+        .collect::<Vec<_>>();
+    if let [message_id] = message_ids.as_slice() {
+        span.set_attribute(semco::MESSAGING_MESSAGE_ID, message_id.to_string());
+    }
+    if let [message] = messages {
+        if let Some(body) = message.body() {
+            span.set_attribute(semco::MESSAGING_MESSAGE_BODY_SIZE, body.len() as i64);
+        }
+    }
+    if !message_ids.is_empty() {
+        span.set_attribute(
+            AWS_SQS_MESSAGE_IDS,
+            Value::Array(Array::String(
+                message_ids
+                    .iter()
+                    .map(|id| StringValue::from(id.to_string()))
+                    .collect(),
+            )),
+        );
+    }
+
+    for message in messages {
+        let attributes = message
+            .message_id()
+            .map(|id| vec![KeyValue::new(semco::MESSAGING_MESSAGE_ID, id.to_owned())])
+            .unwrap_or_default();
+        for span_context in producer_span_contexts(message) {
+            span.add_link(span_context, attributes.clone());
+        }
+    }
+}
+
+/// Sets the failed-entry count and distinct error codes of a batch operation,
+/// and an error status when at least one of its entries failed.
+fn set_failed_entries(
+    span: &mut impl SpanWrite,
+    successful: usize,
+    failed: &[BatchResultErrorEntry],
+) {
+    span.set_attribute(AWS_SQS_FAILED_ENTRY_COUNT, failed.len() as i64);
+    if failed.is_empty() {
+        return;
+    }
+    let codes = failed
+        .iter()
+        .map(|entry| entry.code())
+        .collect::<BTreeSet<_>>();
+    span.set_attribute(
+        AWS_SQS_FAILED_ENTRY_CODES,
+        Value::Array(Array::String(
+            codes
+                .into_iter()
+                .map(|code| StringValue::from(code.to_owned()))
+                .collect(),
+        )),
+    );
+    span.set_status(Status::error(format!(
+        "{} of {} batch entries failed",
+        failed.len(),
+        failed.len() + successful
+    )));
+}
+
+/// Returns the distinct, valid producer span contexts propagated with a message,
+/// from its `AWSTraceHeader` system attribute and `traceparent` message attribute.
+fn producer_span_contexts(message: &Message) -> Vec<SpanContext> {
+    let xray = message
+        .attributes()
+        .and_then(|attributes| attributes.get(&MessageSystemAttributeName::AwsTraceHeader))
+        .and_then(|header| header.parse::<XRayTraceHeader>().ok())
+        .map(|header| {
+            SpanContext::new(
+                header.trace_id,
+                header.parent_id,
+                header.sampled,
+                true,
+                TraceState::default(),
+            )
+        });
+
+    let message_attribute = |name: &str| {
+        message
+            .message_attributes()
+            .and_then(|attributes| attributes.get(name))
+            .and_then(|value| value.string_value())
+    };
+    let w3c = message_attribute(TRACEPARENT)
+        .map(|traceparent| extract_trace_context(traceparent, message_attribute(TRACESTATE)));
+
+    let mut span_contexts: Vec<SpanContext> = Vec::with_capacity(2);
+    for span_context in [xray, w3c].into_iter().flatten() {
+        let duplicate = span_contexts.iter().any(|known| {
+            known.trace_id() == span_context.trace_id() && known.span_id() == span_context.span_id()
+        });
+        if span_context.is_valid() && !duplicate {
+            span_contexts.push(span_context);
+        }
+    }
+    span_contexts
+}
+
 /// Maps SQS operation names to OTel `messaging.operation.type` values.
 ///
 /// Mapping rationale:
@@ -316,6 +613,26 @@ fn set_queue_url_attrs(span: &mut impl SpanWrite, queue_url: Option<&str>) {
     }
 }
 
+/// Returns whether `queue_url` names a FIFO queue, whose name ends in `.fifo`.
+fn is_fifo_queue(queue_url: Option<&str>) -> bool {
+    queue_url.is_some_and(|url| url.ends_with(".fifo"))
+}
+
+/// Sets `messaging.destination.name` from a queue name.
+fn set_queue_name(span: &mut impl SpanWrite, queue_name: Option<&str>) {
+    if let Some(queue_name) = queue_name.filter(|s| !s.is_empty()) {
+        span.set_attribute(semco::MESSAGING_DESTINATION_NAME, queue_name.to_owned());
+    }
+}
+
+/// Sets `messaging.destination.name` from a queue ARN.
+fn set_queue_arn_attrs(span: &mut impl SpanWrite, queue_arn: Option<&str>) {
+    // SQS queue ARNs follow the pattern:
+    //   arn:<partition>:sqs:<region>:<account-id>:<queue-name>
+    // The queue name is the last colon-separated segment.
+    set_queue_name(span, queue_arn.and_then(|arn| arn.rsplit(':').next()));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -327,6 +644,7 @@ mod tests {
     struct TestSpan {
         attributes: Vec<(&'static str, Value)>,
         status: Option<Status>,
+        links: Vec<(SpanContext, Vec<KeyValue>)>,
     }
 
     impl TestSpan {
@@ -334,6 +652,7 @@ mod tests {
             Self {
                 attributes: vec![],
                 status: None,
+                links: vec![],
             }
         }
 
@@ -354,6 +673,10 @@ mod tests {
         fn set_status(&mut self, code: Status) {
             self.status = Some(code);
         }
+
+        fn add_link(&mut self, span_context: SpanContext, attributes: Vec<KeyValue>) {
+            self.links.push((span_context, attributes));
+        }
     }
 
     // Tests for operation_type — 2 consolidated tests
@@ -503,6 +826,454 @@ mod tests {
         );
     }
 
+    // Tests for batch failures and FIFO attributes — 2 consolidated tests
+
+    #[test]
+    fn extract_output_batch_failures() {
+        use aws_sdk_sqs::types::{
+            ChangeMessageVisibilityBatchResultEntry, DeleteMessageBatchResultEntry,
+        };
+
+        let extractor = SQSExtractor::new();
+        let failure = |id: &str, code: &str| {
+            BatchResultErrorEntry::builder()
+                .id(id)
+                .code(code)
+                .sender_fault(true)
+                .build()
+                .unwrap()
+        };
+
+        // DeleteMessageBatch with 2 failed entries out of 3
+        let sdk_output = DeleteMessageBatchOutput::builder()
+            .successful(
+                DeleteMessageBatchResultEntry::builder()
+                    .id("a")
+                    .build()
+                    .unwrap(),
+            )
+            .failed(failure("b", "ReceiptHandleIsInvalid"))
+            .failed(failure("c", "ReceiptHandleIsInvalid"))
+            .build()
+            .unwrap();
+        let output = context::Output::erase(sdk_output);
+        let mut span = TestSpan::new();
+        extractor.extract_output("SQS", "DeleteMessageBatch", &output, &mut span);
+        assert_eq!(span.get(AWS_SQS_FAILED_ENTRY_COUNT), Some(&Value::I64(2)));
+        assert_eq!(
+            span.get(AWS_SQS_FAILED_ENTRY_CODES),
+            Some(&Value::Array(Array::String(vec![
+                "ReceiptHandleIsInvalid".into()
+            ])))
+        );
+        assert_eq!(
+            span.status,
+            Some(Status::error("2 of 3 batch entries failed"))
+        );
+
+        // ChangeMessageVisibilityBatch fully successful: count only, status untouched
+        let sdk_output = ChangeMessageVisibilityBatchOutput::builder()
+            .successful(
+                ChangeMessageVisibilityBatchResultEntry::builder()
+                    .id("a")
+                    .build()
+                    .unwrap(),
+            )
+            .set_failed(Some(vec![]))
+            .build()
+            .unwrap();
+        let output = context::Output::erase(sdk_output);
+        let mut span = TestSpan::new();
+        extractor.extract_output("SQS", "ChangeMessageVisibilityBatch", &output, &mut span);
+        assert_eq!(span.get(AWS_SQS_FAILED_ENTRY_COUNT), Some(&Value::I64(0)));
+        assert!(span.get(AWS_SQS_FAILED_ENTRY_CODES).is_none());
+        assert!(span.status.is_none());
+
+        // SendMessageBatch with a throttled entry
+        let sdk_output = SendMessageBatchOutput::builder()
+            .set_successful(Some(vec![]))
+            .failed(failure("a", "RequestThrottled"))
+            .build()
+            .unwrap();
+        let output = context::Output::erase(sdk_output);
+        let mut span = TestSpan::new();
+        extractor.extract_output("SQS", "SendMessageBatch", &output, &mut span);
+        assert_eq!(span.get(AWS_SQS_FAILED_ENTRY_COUNT), Some(&Value::I64(1)));
+        assert_eq!(
+            span.status,
+            Some(Status::error("1 of 1 batch entries failed"))
+        );
+    }
+
+    #[test]
+    fn extract_input_send_message_fifo_and_body_size() {
+        use aws_sdk_sqs::types::SendMessageBatchRequestEntry;
+
+        let extractor = SQSExtractor::new();
+        let queue_url = "https://sqs.us-east-1.amazonaws.com/123456789012/orders.fifo";
+
+        let sdk_input = SendMessageInput::builder()
+            .queue_url(queue_url)
+            .message_body("héllo")
+            .message_group_id("customer-42")
+            .message_deduplication_id("dedup-1")
+            .delay_seconds(5)
+            .build()
+            .unwrap();
+        let input = context::Input::erase(sdk_input);
+        let mut span = TestSpan::new();
+        extractor.extract_input("SQS", "SendMessage", &input, &mut span);
+        // Size in bytes, not characters
+        assert_eq!(
+            span.get(semco::MESSAGING_MESSAGE_BODY_SIZE),
+            Some(&Value::I64(6))
+        );
+        assert_eq!(
+            span.get(AWS_SQS_MESSAGE_GROUP_ID),
+            Some(&Value::from("customer-42"))
+        );
+        assert_eq!(
+            span.get(AWS_SQS_MESSAGE_DEDUPLICATION_ID_PRESENT),
+            Some(&Value::Bool(true))
+        );
+        assert_eq!(span.get(AWS_SQS_DELAY_SECONDS), Some(&Value::I64(5)));
+
+        // FIFO queue without dedup ID: the flag is false
+        let sdk_input = SendMessageInput::builder()
+            .queue_url(queue_url)
+            .message_body("x")
+            .build()
+            .unwrap();
+        let input = context::Input::erase(sdk_input);
+        let mut span = TestSpan::new();
+        extractor.extract_input("SQS", "SendMessage", &input, &mut span);
+        assert!(span.get(AWS_SQS_MESSAGE_GROUP_ID).is_none());
+        assert_eq!(
+            span.get(AWS_SQS_MESSAGE_DEDUPLICATION_ID_PRESENT),
+            Some(&Value::Bool(false))
+        );
+        assert!(span.get(AWS_SQS_DELAY_SECONDS).is_none());
+
+        // Standard queue: no dedup flag at all
+        let sdk_input = SendMessageInput::builder()
+            .queue_url("https://sqs.us-east-1.amazonaws.com/123456789012/orders")
+            .message_body("x")
+            .build()
+            .unwrap();
+        let input = context::Input::erase(sdk_input);
+        let mut span = TestSpan::new();
+        extractor.extract_input("SQS", "SendMessage", &input, &mut span);
+        assert!(span.get(AWS_SQS_MESSAGE_DEDUPLICATION_ID_PRESENT).is_none());
+
+        // Batch: group recorded only when shared by every entry, the other
+        // attributes aggregated over the entries
+        let entry = |id: &str, group: &str| {
+            SendMessageBatchRequestEntry::builder()
+                .id(id)
+                .message_body("x")
+                .message_group_id(group)
+                .build()
+                .unwrap()
+        };
+        let sdk_input = SendMessageBatchInput::builder()
+            .queue_url(queue_url)
+            .entries(entry("1", "g1"))
+            .entries(
+                SendMessageBatchRequestEntry::builder()
+                    .id("2")
+                    .message_body("héllo")
+                    .message_group_id("g1")
+                    .message_deduplication_id("dedup-2")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "SQS",
+            "SendMessageBatch",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(span.get(AWS_SQS_MESSAGE_GROUP_ID), Some(&Value::from("g1")));
+        assert_eq!(span.get(AWS_SQS_BATCH_BODY_SIZE), Some(&Value::I64(7)));
+        assert_eq!(
+            span.get(AWS_SQS_MESSAGE_DEDUPLICATION_ID_PRESENT),
+            Some(&Value::Bool(true))
+        );
+        assert!(span.get(semco::MESSAGING_MESSAGE_BODY_SIZE).is_none());
+
+        let sdk_input = SendMessageBatchInput::builder()
+            .queue_url(queue_url)
+            .entries(entry("1", "g1"))
+            .entries(entry("2", "g2"))
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "SQS",
+            "SendMessageBatch",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert!(span.get(AWS_SQS_MESSAGE_GROUP_ID).is_none());
+        assert_eq!(
+            span.get(AWS_SQS_MESSAGE_DEDUPLICATION_ID_PRESENT),
+            Some(&Value::Bool(false))
+        );
+
+        // Standard queue batch: the longest delay, no dedup flag
+        let delayed = |id: &str, delay_seconds: i32| {
+            SendMessageBatchRequestEntry::builder()
+                .id(id)
+                .message_body("x")
+                .delay_seconds(delay_seconds)
+                .build()
+                .unwrap()
+        };
+        let sdk_input = SendMessageBatchInput::builder()
+            .queue_url("https://sqs.us-east-1.amazonaws.com/123456789012/orders")
+            .entries(delayed("1", 10))
+            .entries(delayed("2", 30))
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "SQS",
+            "SendMessageBatch",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(span.get(AWS_SQS_DELAY_SECONDS), Some(&Value::I64(30)));
+        assert!(span.get(AWS_SQS_MESSAGE_DEDUPLICATION_ID_PRESENT).is_none());
+    }
+
+    // Tests for ReceiveMessage span links — 2 consolidated tests
+
+    #[test]
+    fn extract_receive_message_output_links_producers() {
+        use aws_sdk_sqs::types::MessageAttributeValue;
+        use opentelemetry::trace::{SpanId, TraceId};
+
+        let xray_header =
+            "Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=1";
+        let traceparent = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+        let string_attribute = |value: &str| {
+            MessageAttributeValue::builder()
+                .data_type("String")
+                .string_value(value)
+                .build()
+                .unwrap()
+        };
+
+        let messages = vec![
+            // X-Ray system attribute
+            Message::builder()
+                .message_id("m1")
+                .attributes(MessageSystemAttributeName::AwsTraceHeader, xray_header)
+                .build(),
+            // W3C message attribute
+            Message::builder()
+                .message_id("m2")
+                .message_attributes(TRACEPARENT, string_attribute(traceparent))
+                .build(),
+            // Both, carrying the same context: a single link
+            Message::builder()
+                .message_id("m3")
+                .attributes(MessageSystemAttributeName::AwsTraceHeader, xray_header)
+                .message_attributes(
+                    TRACEPARENT,
+                    string_attribute("00-5759e988bd862e3fe1be46a994272793-53995c3f42cd8ad8-01"),
+                )
+                .build(),
+            // No propagated context
+            Message::builder().message_id("m4").build(),
+        ];
+
+        let mut span = TestSpan::new();
+        extract_receive_message_output(&messages, &mut span);
+
+        assert_eq!(
+            span.get(semco::MESSAGING_BATCH_MESSAGE_COUNT),
+            Some(&Value::I64(4))
+        );
+        assert_eq!(
+            span.get(AWS_SQS_MESSAGE_IDS),
+            Some(&Value::Array(Array::String(vec![
+                "m1".into(),
+                "m2".into(),
+                "m3".into(),
+                "m4".into()
+            ])))
+        );
+        // messaging.message.id is only set for a single message
+        assert!(span.get(semco::MESSAGING_MESSAGE_ID).is_none());
+
+        let links = span
+            .links
+            .iter()
+            .map(|(context, attributes)| {
+                (
+                    context.trace_id(),
+                    context.span_id(),
+                    attributes[0].value.as_str().into_owned(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let xray_ids = (
+            TraceId::from_hex("5759e988bd862e3fe1be46a994272793").unwrap(),
+            SpanId::from_hex("53995c3f42cd8ad8").unwrap(),
+        );
+        assert_eq!(
+            links,
+            vec![
+                (xray_ids.0, xray_ids.1, "m1".to_owned()),
+                (
+                    TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736").unwrap(),
+                    SpanId::from_hex("00f067aa0ba902b7").unwrap(),
+                    "m2".to_owned()
+                ),
+                (xray_ids.0, xray_ids.1, "m3".to_owned()),
+            ]
+        );
+        assert!(span.links.iter().all(|(context, _)| context.is_remote()));
+    }
+
+    #[test]
+    fn extract_receive_message_output_single_and_invalid_headers() {
+        let messages = vec![
+            Message::builder()
+                .message_id("only")
+                .attributes(MessageSystemAttributeName::AwsTraceHeader, "not-a-header")
+                .build(),
+        ];
+        let mut span = TestSpan::new();
+        extract_receive_message_output(&messages, &mut span);
+
+        assert_eq!(
+            span.get(semco::MESSAGING_MESSAGE_ID),
+            Some(&Value::String("only".into()))
+        );
+        assert!(span.links.is_empty());
+
+        // No messages: count only
+        let mut span = TestSpan::new();
+        extract_receive_message_output(&[], &mut span);
+        assert_eq!(
+            span.get(semco::MESSAGING_BATCH_MESSAGE_COUNT),
+            Some(&Value::I64(0))
+        );
+        assert!(span.get(AWS_SQS_MESSAGE_IDS).is_none());
+    }
+
+    // Tests for queue-less operations — 2 consolidated tests
+
+    #[test]
+    fn extract_queue_less_operations_inputs() {
+        let extractor = SQSExtractor::new();
+
+        let sdk_input = CreateQueueInput::builder()
+            .queue_name("orders.fifo")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "SQS",
+            "CreateQueue",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(semco::MESSAGING_DESTINATION_NAME),
+            Some(&Value::from("orders.fifo"))
+        );
+
+        let sdk_input = GetQueueUrlInput::builder()
+            .queue_name("orders")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "SQS",
+            "GetQueueUrl",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(semco::MESSAGING_DESTINATION_NAME),
+            Some(&Value::from("orders"))
+        );
+
+        let sdk_input = StartMessageMoveTaskInput::builder()
+            .source_arn("arn:aws:sqs:us-east-1:123456789012:orders-dlq")
+            .destination_arn("arn:aws:sqs:us-east-1:123456789012:orders")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "SQS",
+            "StartMessageMoveTask",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(semco::MESSAGING_DESTINATION_NAME),
+            Some(&Value::from("orders-dlq"))
+        );
+        assert!(span.get(semco::AWS_SQS_QUEUE_URL).is_none());
+
+        let sdk_input = ListMessageMoveTasksInput::builder()
+            .source_arn("arn:aws:sqs:us-east-1:123456789012:orders-dlq")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "SQS",
+            "ListMessageMoveTasks",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(semco::MESSAGING_DESTINATION_NAME),
+            Some(&Value::from("orders-dlq"))
+        );
+    }
+
+    #[test]
+    fn extract_queue_less_operations_outputs() {
+        let extractor = SQSExtractor::new();
+        let queue_url = "https://sqs.us-east-1.amazonaws.com/123456789012/orders";
+
+        let output =
+            context::Output::erase(CreateQueueOutput::builder().queue_url(queue_url).build());
+        let mut span = TestSpan::new();
+        extractor.extract_output("SQS", "CreateQueue", &output, &mut span);
+        assert_eq!(
+            span.get(semco::AWS_SQS_QUEUE_URL),
+            Some(&Value::from(queue_url))
+        );
+        assert_eq!(
+            span.get(semco::MESSAGING_DESTINATION_NAME),
+            Some(&Value::from("orders"))
+        );
+
+        let output =
+            context::Output::erase(GetQueueUrlOutput::builder().queue_url(queue_url).build());
+        let mut span = TestSpan::new();
+        extractor.extract_output("SQS", "GetQueueUrl", &output, &mut span);
+        assert_eq!(
+            span.get(semco::AWS_SQS_QUEUE_URL),
+            Some(&Value::from(queue_url))
+        );
+
+        // Missing URL (e.g. a stubbed response): nothing recorded
+        let output = context::Output::erase(GetQueueUrlOutput::builder().build());
+        let mut span = TestSpan::new();
+        extractor.extract_output("SQS", "GetQueueUrl", &output, &mut span);
+        assert!(span.attributes.is_empty());
+    }
+
     // Tests for set_queue_url_attrs — 2 consolidated tests
 
     #[test]
@@ -0,0 +1,488 @@
+//! SSM Parameter Store attribute extraction.
+//!
+//! This module provides [`SSMExtractor`], which implements
+//! [`super::super::AttributeExtractor`] for the Parameter Store
+//! `GetParameter`, `GetParameters` and `GetParametersByPath` SDK calls. It is
+//! automatically used by [`super::super::DefaultExtractor`] when the
+//! `extract-ssm` feature is enabled.
+//!
+//! ## Extracted attributes
+//!
+//! **Set from input:**
+//! - `aws.ssm.parameter_name` — for `GetParameter`, the parameter name
+//! - `aws.ssm.parameter_names` — for `GetParameters`, the parameter names
+//! - `aws.ssm.parameter_path`, `aws.ssm.recursive` — for
+//!   `GetParametersByPath`, the path and whether the hierarchy below it is
+//!   read
+//! - `aws.ssm.with_decryption` — whether `SecureString` values are decrypted
+//!
+//! **Set from output:**
+//! - `aws.ssm.parameter_version`, `aws.ssm.parameter_type` — for
+//!   `GetParameter`, the version and type of the parameter returned
+//! - `aws.ssm.returned_count` — for `GetParameters` and `GetParametersByPath`,
+//!   the number of parameters returned
+//! - `aws.ssm.invalid_parameter_count`, `aws.ssm.invalid_parameters` — for
+//!   `GetParameters`, the number and names of the parameters that were not
+//!   found
+//!
+//! `GetParameters` reports names it could not find inside a successful
+//! response. When at least one name is invalid, the span status is set to
+//! error.
+//!
+//! ## Parameter values
+//!
+//! Parameter values are never recorded, whatever their type. Outputs are only
+//! read through the private `metadata` views, which destructure the version,
+//! type and count information of the SDK output and never name a parameter
+//! value field; the extractor itself never holds an SDK output. The crate's
+//! `clippy.toml` additionally lists the parameter value accessor under
+//! `disallowed-methods` as a backstop.
+
+// SSM attribute extraction — downcasts Input/Output to concrete aws-sdk-ssm
+// types and extracts parameter names, paths and counts.
+
+use aws_sdk_ssm::operation::{
+    get_parameter::GetParameterInput, get_parameters::GetParametersInput,
+    get_parameters_by_path::GetParametersByPathInput,
+};
+use aws_smithy_runtime_api::client::interceptors::context;
+
+use super::super::{AttributeExtractor, SpanWrite};
+use super::set_strings;
+use crate::span_write::Status;
+
+/// Attribute key for the parameter name requested by `GetParameter`.
+const AWS_SSM_PARAMETER_NAME: &str = "aws.ssm.parameter_name";
+/// Attribute key for the parameter names requested by `GetParameters`.
+const AWS_SSM_PARAMETER_NAMES: &str = "aws.ssm.parameter_names";
+/// Attribute key for the path requested by `GetParametersByPath`.
+const AWS_SSM_PARAMETER_PATH: &str = "aws.ssm.parameter_path";
+/// Attribute key flagging a recursive `GetParametersByPath`.
+const AWS_SSM_RECURSIVE: &str = "aws.ssm.recursive";
+/// Attribute key flagging that `SecureString` values are decrypted.
+const AWS_SSM_WITH_DECRYPTION: &str = "aws.ssm.with_decryption";
+/// Attribute key for the version of the parameter returned by `GetParameter`.
+const AWS_SSM_PARAMETER_VERSION: &str = "aws.ssm.parameter_version";
+/// Attribute key for the type of the parameter returned by `GetParameter`.
+const AWS_SSM_PARAMETER_TYPE: &str = "aws.ssm.parameter_type";
+/// Attribute key for the number of parameters returned.
+const AWS_SSM_RETURNED_COUNT: &str = "aws.ssm.returned_count";
+/// Attribute key for the number of names `GetParameters` could not find.
+const AWS_SSM_INVALID_PARAMETER_COUNT: &str = "aws.ssm.invalid_parameter_count";
+/// Attribute key for the names `GetParameters` could not find.
+const AWS_SSM_INVALID_PARAMETERS: &str = "aws.ssm.invalid_parameters";
+
+/// Attribute extractor for SSM Parameter Store SDK calls.
+///
+/// `SSMExtractor` implements [`AttributeExtractor`] and is automatically used
+/// by [`DefaultExtractor`] when the `extract-ssm` feature is enabled. You only
+/// need to construct it directly if you are composing a custom extraction
+/// pipeline.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes.
+///
+/// [`DefaultExtractor`]: crate::interceptor::DefaultExtractor
+#[derive(Debug, Default)]
+pub struct SSMExtractor {
+    _private: (),
+}
+
+impl SSMExtractor {
+    /// Creates a new `SSMExtractor`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::extract::ssm::SSMExtractor;
+    ///
+    /// let extractor = SSMExtractor::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+/// Extracts SSM Parameter Store OTel attributes from SDK inputs and outputs.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes and which operations they apply to.
+impl<SW: SpanWrite> AttributeExtractor<SW> for SSMExtractor {
+    fn extract_input(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        input: &context::Input,
+        span: &mut SW,
+    ) {
+        match operation {
+            "GetParameter" => {
+                let i = input
+                    .downcast_ref::<GetParameterInput>()
+                    .expect("correct type");
+                if let Some(name) = i.name().filter(|s| !s.is_empty()) {
+                    span.set_attribute(AWS_SSM_PARAMETER_NAME, name.to_owned());
+                }
+                set_with_decryption(span, i.with_decryption());
+            }
+            "GetParameters" => {
+                let i = input
+                    .downcast_ref::<GetParametersInput>()
+                    .expect("correct type");
+                set_strings(span, AWS_SSM_PARAMETER_NAMES, i.names());
+                set_with_decryption(span, i.with_decryption());
+            }
+            "GetParametersByPath" => {
+                let i = input
+                    .downcast_ref::<GetParametersByPathInput>()
+                    .expect("correct type");
+                if let Some(path) = i.path().filter(|s| !s.is_empty()) {
+                    span.set_attribute(AWS_SSM_PARAMETER_PATH, path.to_owned());
+                }
+                span.set_attribute(AWS_SSM_RECURSIVE, i.recursive().unwrap_or_default());
+                set_with_decryption(span, i.with_decryption());
+            }
+            _ => {}
+        }
+    }
+
+    fn extract_output(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        output: &context::Output,
+        span: &mut SW,
+    ) {
+        match operation {
+            "GetParameter" => {
+                if let Some(parameter) = metadata::ParameterVersion::of(output) {
+                    span.set_attribute(AWS_SSM_PARAMETER_VERSION, parameter.version);
+                    if let Some(parameter_type) = parameter.parameter_type {
+                        span.set_attribute(
+                            AWS_SSM_PARAMETER_TYPE,
+                            parameter_type.as_str().to_owned(),
+                        );
+                    }
+                }
+            }
+            "GetParameters" => {
+                let outcome = metadata::ParametersOutcome::of_get_parameters(output);
+                let returned = outcome.returned;
+                let invalid = outcome.invalid.len();
+                span.set_attribute(AWS_SSM_RETURNED_COUNT, returned as i64);
+                span.set_attribute(AWS_SSM_INVALID_PARAMETER_COUNT, invalid as i64);
+                if invalid > 0 {
+                    set_strings(span, AWS_SSM_INVALID_PARAMETERS, outcome.invalid);
+                    span.set_status(Status::error(format!(
+                        "{} of {} parameters invalid",
+                        invalid,
+                        returned + invalid
+                    )));
+                }
+            }
+            "GetParametersByPath" => {
+                let outcome = metadata::ParametersOutcome::of_get_parameters_by_path(output);
+                span.set_attribute(AWS_SSM_RETURNED_COUNT, outcome.returned as i64);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Sets `aws.ssm.with_decryption`, which defaults to `false` like SSM.
+fn set_with_decryption(span: &mut impl SpanWrite, with_decryption: Option<bool>) {
+    span.set_attribute(AWS_SSM_WITH_DECRYPTION, with_decryption.unwrap_or_default());
+}
+
+/// Metadata views over the Parameter Store outputs.
+///
+/// This is the only code that reads an SDK output. Each view destructures the
+/// fields it needs and nothing else, so the parameter value field is never
+/// named.
+mod metadata {
+    use aws_sdk_ssm::{
+        operation::{
+            get_parameter::GetParameterOutput, get_parameters::GetParametersOutput,
+            get_parameters_by_path::GetParametersByPathOutput,
+        },
+        types::{Parameter, ParameterType},
+    };
+    use aws_smithy_runtime_api::client::interceptors::context;
+
+    /// Version and type of the parameter returned by `GetParameter`.
+    pub(super) struct ParameterVersion<'a> {
+        pub(super) version: i64,
+        pub(super) parameter_type: Option<&'a ParameterType>,
+    }
+
+    impl<'a> ParameterVersion<'a> {
+        pub(super) fn of(output: &'a context::Output) -> Option<Self> {
+            let GetParameterOutput { parameter, .. } = output
+                .downcast_ref::<GetParameterOutput>()
+                .expect("correct type");
+            let Parameter {
+                version, r#type, ..
+            } = parameter.as_ref()?;
+            Some(Self {
+                version: *version,
+                parameter_type: r#type.as_ref(),
+            })
+        }
+    }
+
+    /// Returned count and invalid names of a multi-parameter read.
+    pub(super) struct ParametersOutcome<'a> {
+        pub(super) returned: usize,
+        pub(super) invalid: &'a [String],
+    }
+
+    impl<'a> ParametersOutcome<'a> {
+        pub(super) fn of_get_parameters(output: &'a context::Output) -> Self {
+            let GetParametersOutput {
+                parameters,
+                invalid_parameters,
+                ..
+            } = output
+                .downcast_ref::<GetParametersOutput>()
+                .expect("correct type");
+            Self {
+                returned: parameters.as_ref().map_or(0, Vec::len),
+                invalid: invalid_parameters.as_deref().unwrap_or_default(),
+            }
+        }
+
+        pub(super) fn of_get_parameters_by_path(output: &'a context::Output) -> Self {
+            let GetParametersByPathOutput { parameters, .. } = output
+                .downcast_ref::<GetParametersByPathOutput>()
+                .expect("correct type");
+            Self {
+                returned: parameters.as_ref().map_or(0, Vec::len),
+                invalid: &[],
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_ssm::{
+        operation::{
+            get_parameter::{GetParameterError, GetParameterOutput},
+            get_parameters::GetParametersOutput,
+            get_parameters_by_path::GetParametersByPathOutput,
+        },
+        types::{Parameter, ParameterType, error::ParameterNotFound},
+    };
+    use opentelemetry::{Array, Value};
+
+    use crate::span_write::{SpanContext, SpanWrite, Status};
+
+    struct TestSpan {
+        attributes: Vec<(&'static str, Value)>,
+        status: Option<Status>,
+    }
+
+    impl TestSpan {
+        fn new() -> Self {
+            Self {
+                attributes: vec![],
+                status: None,
+            }
+        }
+
+        fn get(&self, key: &str) -> Option<&Value> {
+            self.attributes
+                .iter()
+                .rev()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v)
+        }
+
+        fn contains(&self, needle: &str) -> bool {
+            self.attributes
+                .iter()
+                .any(|(_, value)| value.to_string().contains(needle))
+        }
+    }
+
+    impl SpanWrite for TestSpan {
+        fn set_attribute(&mut self, key: &'static str, value: impl Into<Value>) {
+            self.attributes.push((key, value.into()));
+        }
+
+        fn set_status(&mut self, code: Status) {
+            self.status = Some(code);
+        }
+
+        fn span_context(&self) -> SpanContext {
+            SpanContext::empty_context()
+        }
+    }
+
+    const PARAMETER_VALUE: &str = "hunter2-do-not-record";
+
+    fn parameter(name: &str) -> Parameter {
+        Parameter::builder()
+            .name(name)
+            .r#type(ParameterType::SecureString)
+            .value(PARAMETER_VALUE)
+            .version(4)
+            .build()
+    }
+
+    // Tests for SSMExtractor — 4 consolidated tests
+
+    #[test]
+    fn extract_get_parameter() {
+        let extractor = SSMExtractor::new();
+
+        let sdk_input = GetParameterInput::builder()
+            .name("/app/db/password")
+            .with_decryption(true)
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "SSM",
+            "GetParameter",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_SSM_PARAMETER_NAME),
+            Some(&Value::from("/app/db/password"))
+        );
+        assert_eq!(span.get(AWS_SSM_WITH_DECRYPTION), Some(&Value::Bool(true)));
+        assert!(!span.contains(PARAMETER_VALUE));
+
+        let sdk_output = GetParameterOutput::builder()
+            .parameter(parameter("/app/db/password"))
+            .build();
+        extractor.extract_output(
+            "SSM",
+            "GetParameter",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(span.get(AWS_SSM_PARAMETER_VERSION), Some(&Value::I64(4)));
+        assert_eq!(
+            span.get(AWS_SSM_PARAMETER_TYPE),
+            Some(&Value::from("SecureString"))
+        );
+        assert!(!span.contains(PARAMETER_VALUE));
+    }
+
+    #[test]
+    fn extract_get_parameters_with_invalid_names() {
+        let extractor = SSMExtractor::new();
+
+        let sdk_input = GetParametersInput::builder()
+            .names("/app/a")
+            .names("/app/b")
+            .names("/app/missing")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "SSM",
+            "GetParameters",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_SSM_PARAMETER_NAMES),
+            Some(&Value::Array(Array::String(vec![
+                "/app/a".into(),
+                "/app/b".into(),
+                "/app/missing".into()
+            ])))
+        );
+        assert_eq!(span.get(AWS_SSM_WITH_DECRYPTION), Some(&Value::Bool(false)));
+        assert!(!span.contains(PARAMETER_VALUE));
+
+        let sdk_output = GetParametersOutput::builder()
+            .parameters(parameter("/app/a"))
+            .parameters(parameter("/app/b"))
+            .invalid_parameters("/app/missing")
+            .build();
+        extractor.extract_output(
+            "SSM",
+            "GetParameters",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(span.get(AWS_SSM_RETURNED_COUNT), Some(&Value::I64(2)));
+        assert_eq!(
+            span.get(AWS_SSM_INVALID_PARAMETER_COUNT),
+            Some(&Value::I64(1))
+        );
+        assert_eq!(
+            span.get(AWS_SSM_INVALID_PARAMETERS),
+            Some(&Value::Array(Array::String(vec!["/app/missing".into()])))
+        );
+        assert_eq!(
+            span.status,
+            Some(Status::error("1 of 3 parameters invalid"))
+        );
+        assert!(!span.contains(PARAMETER_VALUE));
+    }
+
+    #[test]
+    fn extract_get_parameters_by_path() {
+        let extractor = SSMExtractor::new();
+
+        let sdk_input = GetParametersByPathInput::builder()
+            .path("/app/")
+            .recursive(true)
+            .with_decryption(true)
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "SSM",
+            "GetParametersByPath",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_SSM_PARAMETER_PATH),
+            Some(&Value::from("/app/"))
+        );
+        assert_eq!(span.get(AWS_SSM_RECURSIVE), Some(&Value::Bool(true)));
+        assert!(!span.contains(PARAMETER_VALUE));
+
+        let sdk_output = GetParametersByPathOutput::builder()
+            .parameters(parameter("/app/a"))
+            .parameters(parameter("/app/b"))
+            .build();
+        extractor.extract_output(
+            "SSM",
+            "GetParametersByPath",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(span.get(AWS_SSM_RETURNED_COUNT), Some(&Value::I64(2)));
+        assert!(span.status.is_none());
+        assert!(!span.contains(PARAMETER_VALUE));
+    }
+
+    #[test]
+    fn extract_error_records_no_parameter_value() {
+        let extractor = SSMExtractor::new();
+        let error = GetParameterError::ParameterNotFound(
+            ParameterNotFound::builder()
+                .message(PARAMETER_VALUE)
+                .build(),
+        );
+        let mut span = TestSpan::new();
+        extractor.extract_error(
+            "SSM",
+            "GetParameter",
+            &context::Error::erase(error),
+            &mut span,
+        );
+        assert!(!span.contains(PARAMETER_VALUE));
+        assert!(span.status.is_none());
+    }
+}
@@ -0,0 +1,343 @@
+//! STS attribute extraction.
+//!
+//! This module provides [`STSExtractor`], which implements
+//! [`super::super::AttributeExtractor`] for the STS `AssumeRole`,
+//! `AssumeRoleWithWebIdentity`, `AssumeRoleWithSAML` and `GetCallerIdentity`
+//! SDK calls. It is automatically used by [`super::super::DefaultExtractor`]
+//! when the `extract-sts` feature is enabled.
+//!
+//! ## Extracted attributes
+//!
+//! **Set from the `AssumeRole*` operations:**
+//! - `aws.sts.role_arn` — the ARN of the role to assume
+//! - `aws.sts.role_session_name` — the session name, for `AssumeRole` and
+//!   `AssumeRoleWithWebIdentity`
+//! - `aws.sts.duration_seconds` — the session duration requested, when given
+//! - `aws.sts.source_identity` — the source identity given to `AssumeRole`, or
+//!   the one returned by any of them
+//! - `aws.sts.assumed_role_arn` — from the output, the ARN of the assumed role
+//!   session
+//!
+//! **Set from `GetCallerIdentity` output:**
+//! - `aws.sts.caller.account` — the AWS account ID of the caller
+//! - `aws.sts.caller.arn` — the ARN of the caller
+//!
+//! ## Credentials
+//!
+//! Temporary credentials, web identity tokens, SAML assertions, MFA token
+//! codes and external IDs are never recorded. The crate's `clippy.toml` lists
+//! the accessors of the credentials and tokens under `disallowed-methods`, so
+//! any code in this crate that reads them fails the lint.
+
+// STS attribute extraction — downcasts Input/Output to concrete aws-sdk-sts
+// types and extracts role and caller identity attributes.
+
+use aws_sdk_sts::operation::{
+    assume_role::{AssumeRoleInput, AssumeRoleOutput},
+    assume_role_with_saml::{AssumeRoleWithSamlInput, AssumeRoleWithSamlOutput},
+    assume_role_with_web_identity::{
+        AssumeRoleWithWebIdentityInput, AssumeRoleWithWebIdentityOutput,
+    },
+    get_caller_identity::GetCallerIdentityOutput,
+};
+use aws_sdk_sts::types::AssumedRoleUser;
+use aws_smithy_runtime_api::client::interceptors::context;
+
+use super::super::{AttributeExtractor, SpanWrite};
+use super::set_str;
+
+/// Attribute key for the ARN of the role to assume.
+const AWS_STS_ROLE_ARN: &str = "aws.sts.role_arn";
+/// Attribute key for the role session name.
+const AWS_STS_ROLE_SESSION_NAME: &str = "aws.sts.role_session_name";
+/// Attribute key for the session duration requested, in seconds.
+const AWS_STS_DURATION_SECONDS: &str = "aws.sts.duration_seconds";
+/// Attribute key for the source identity.
+const AWS_STS_SOURCE_IDENTITY: &str = "aws.sts.source_identity";
+/// Attribute key for the ARN of the assumed role session.
+const AWS_STS_ASSUMED_ROLE_ARN: &str = "aws.sts.assumed_role_arn";
+/// Attribute key for the account ID returned by `GetCallerIdentity`.
+const AWS_STS_CALLER_ACCOUNT: &str = "aws.sts.caller.account";
+/// Attribute key for the caller ARN returned by `GetCallerIdentity`.
+const AWS_STS_CALLER_ARN: &str = "aws.sts.caller.arn";
+
+/// Attribute extractor for STS SDK calls.
+///
+/// `STSExtractor` implements [`AttributeExtractor`] and is automatically used
+/// by [`DefaultExtractor`] when the `extract-sts` feature is enabled. You only
+/// need to construct it directly if you are composing a custom extraction
+/// pipeline.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes.
+///
+/// [`DefaultExtractor`]: crate::interceptor::DefaultExtractor
+#[derive(Debug, Default)]
+pub struct STSExtractor {
+    _private: (),
+}
+
+impl STSExtractor {
+    /// Creates a new `STSExtractor`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::extract::sts::STSExtractor;
+    ///
+    /// let extractor = STSExtractor::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+/// Extracts STS OTel attributes from SDK inputs and outputs.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes and which operations they apply to.
+impl<SW: SpanWrite> AttributeExtractor<SW> for STSExtractor {
+    fn extract_input(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        input: &context::Input,
+        span: &mut SW,
+    ) {
+        match operation {
+            "AssumeRole" => {
+                let i = input
+                    .downcast_ref::<AssumeRoleInput>()
+                    .expect("correct type");
+                set_role(
+                    span,
+                    i.role_arn(),
+                    i.role_session_name(),
+                    i.duration_seconds(),
+                );
+                set_str(span, AWS_STS_SOURCE_IDENTITY, i.source_identity());
+            }
+            "AssumeRoleWithWebIdentity" => {
+                let i = input
+                    .downcast_ref::<AssumeRoleWithWebIdentityInput>()
+                    .expect("correct type");
+                set_role(
+                    span,
+                    i.role_arn(),
+                    i.role_session_name(),
+                    i.duration_seconds(),
+                );
+            }
+            "AssumeRoleWithSAML" => {
+                let i = input
+                    .downcast_ref::<AssumeRoleWithSamlInput>()
+                    .expect("correct type");
+                set_role(span, i.role_arn(), None, i.duration_seconds());
+            }
+            _ => {}
+        }
+    }
+
+    fn extract_output(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        output: &context::Output,
+        span: &mut SW,
+    ) {
+        match operation {
+            "AssumeRole" => {
+                let o = output
+                    .downcast_ref::<AssumeRoleOutput>()
+                    .expect("correct type");
+                set_assumed_role(span, o.assumed_role_user(), o.source_identity());
+            }
+            "AssumeRoleWithWebIdentity" => {
+                let o = output
+                    .downcast_ref::<AssumeRoleWithWebIdentityOutput>()
+                    .expect("correct type");
+                set_assumed_role(span, o.assumed_role_user(), o.source_identity());
+            }
+            "AssumeRoleWithSAML" => {
+                let o = output
+                    .downcast_ref::<AssumeRoleWithSamlOutput>()
+                    .expect("correct type");
+                set_assumed_role(span, o.assumed_role_user(), o.source_identity());
+            }
+            "GetCallerIdentity" => {
+                let o = output
+                    .downcast_ref::<GetCallerIdentityOutput>()
+                    .expect("correct type");
+                set_str(span, AWS_STS_CALLER_ACCOUNT, o.account());
+                set_str(span, AWS_STS_CALLER_ARN, o.arn());
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Sets the role ARN, session name and requested duration of an `AssumeRole*`
+/// call.
+fn set_role(
+    span: &mut impl SpanWrite,
+    role_arn: Option<&str>,
+    role_session_name: Option<&str>,
+    duration_seconds: Option<i32>,
+) {
+    set_str(span, AWS_STS_ROLE_ARN, role_arn);
+    set_str(span, AWS_STS_ROLE_SESSION_NAME, role_session_name);
+    if let Some(duration_seconds) = duration_seconds {
+        span.set_attribute(AWS_STS_DURATION_SECONDS, i64::from(duration_seconds));
+    }
+}
+
+/// Sets the assumed role session ARN and source identity of an `AssumeRole*`
+/// output, leaving its credentials alone.
+fn set_assumed_role(
+    span: &mut impl SpanWrite,
+    assumed_role_user: Option<&AssumedRoleUser>,
+    source_identity: Option<&str>,
+) {
+    set_str(
+        span,
+        AWS_STS_ASSUMED_ROLE_ARN,
+        assumed_role_user.map(|user| user.arn()),
+    );
+    set_str(span, AWS_STS_SOURCE_IDENTITY, source_identity);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_sts::types::Credentials;
+    use opentelemetry::Value;
+
+    use crate::span_write::{SpanContext, SpanWrite, Status};
+
+    struct TestSpan {
+        attributes: Vec<(&'static str, Value)>,
+    }
+
+    impl TestSpan {
+        fn new() -> Self {
+            Self { attributes: vec![] }
+        }
+
+        fn get(&self, key: &str) -> Option<&Value> {
+            self.attributes
+                .iter()
+                .rev()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v)
+        }
+
+        fn contains(&self, needle: &str) -> bool {
+            self.attributes
+                .iter()
+                .any(|(_, value)| value.to_string().contains(needle))
+        }
+    }
+
+    impl SpanWrite for TestSpan {
+        fn set_attribute(&mut self, key: &'static str, value: impl Into<Value>) {
+            self.attributes.push((key, value.into()));
+        }
+
+        fn set_status(&mut self, _code: Status) {}
+
+        fn span_context(&self) -> SpanContext {
+            SpanContext::empty_context()
+        }
+    }
+
+    const ROLE_ARN: &str = "arn:aws:iam::123456789012:role/deployer";
+    const SESSION_ARN: &str = "arn:aws:sts::123456789012:assumed-role/deployer/ci";
+
+    // Tests for STSExtractor — 2 consolidated tests
+
+    #[test]
+    fn extract_assume_role_with_web_identity() {
+        let extractor = STSExtractor::new();
+
+        let sdk_input = AssumeRoleWithWebIdentityInput::builder()
+            .role_arn(ROLE_ARN)
+            .role_session_name("ci")
+            .web_identity_token("eyJhbGciOi.secret-jwt")
+            .duration_seconds(900)
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "STS",
+            "AssumeRoleWithWebIdentity",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(span.get(AWS_STS_ROLE_ARN), Some(&Value::from(ROLE_ARN)));
+        assert_eq!(
+            span.get(AWS_STS_ROLE_SESSION_NAME),
+            Some(&Value::from("ci"))
+        );
+        assert_eq!(span.get(AWS_STS_DURATION_SECONDS), Some(&Value::I64(900)));
+
+        let sdk_output = AssumeRoleWithWebIdentityOutput::builder()
+            .credentials(
+                Credentials::builder()
+                    .access_key_id("ASIAEXAMPLE")
+                    .secret_access_key("secret-access-key")
+                    .session_token("secret-session-token")
+                    .expiration(aws_smithy_types::DateTime::from_secs(0))
+                    .build()
+                    .unwrap(),
+            )
+            .assumed_role_user(
+                AssumedRoleUser::builder()
+                    .assumed_role_id("AROAEXAMPLE:ci")
+                    .arn(SESSION_ARN)
+                    .build()
+                    .unwrap(),
+            )
+            .source_identity("alice")
+            .build();
+        extractor.extract_output(
+            "STS",
+            "AssumeRoleWithWebIdentity",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_STS_ASSUMED_ROLE_ARN),
+            Some(&Value::from(SESSION_ARN))
+        );
+        assert_eq!(
+            span.get(AWS_STS_SOURCE_IDENTITY),
+            Some(&Value::from("alice"))
+        );
+        assert!(!span.contains("secret"));
+        assert!(!span.contains("ASIAEXAMPLE"));
+    }
+
+    #[test]
+    fn extract_get_caller_identity() {
+        let sdk_output = GetCallerIdentityOutput::builder()
+            .account("123456789012")
+            .arn(SESSION_ARN)
+            .user_id("AROAEXAMPLE:ci")
+            .build();
+        let mut span = TestSpan::new();
+        STSExtractor::new().extract_output(
+            "STS",
+            "GetCallerIdentity",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_STS_CALLER_ACCOUNT),
+            Some(&Value::from("123456789012"))
+        );
+        assert_eq!(
+            span.get(AWS_STS_CALLER_ARN),
+            Some(&Value::from(SESSION_ARN))
+        );
+    }
+}
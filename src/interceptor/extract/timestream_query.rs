@@ -0,0 +1,278 @@
+//! Timestream Query attribute extraction following the OTel database semantic
+//! conventions.
+//!
+//! This module provides [`TimestreamQueryExtractor`], which implements
+//! [`super::super::AttributeExtractor`] for the Timestream `Query` and
+//! `CancelQuery` SDK calls. It is automatically used by
+//! [`super::super::DefaultExtractor`] when the `extract-timestream-query`
+//! feature is enabled.
+//!
+//! `Query` is paginated: every page of the same query returns the same query
+//! ID, so the spans of one paginated query can be tied together.
+//!
+//! ## Extracted attributes
+//!
+//! **Set from input:**
+//! - `db.system.name` — always `"aws.timestream"`
+//! - `db.query.text` — for `Query`, the query with literals replaced by `?`
+//! - `aws.timestream.query_id` — for `CancelQuery`, the query cancelled
+//!
+//! **Set from output:**
+//! - `aws.timestream.query_id` — for `Query`, the query ID
+//! - `db.response.returned_rows` — for `Query`, the number of rows in the page
+//! - `aws.timestream.bytes_scanned` — for `Query`, the bytes scanned so far
+//! - `aws.timestream.bytes_metered` — for `Query`, the bytes metered so far
+//!
+//! Result rows are never recorded.
+
+// Timestream Query attribute extraction — downcasts Input/Output to concrete
+// aws-sdk-timestreamquery types and extracts query attributes.
+
+use aws_sdk_timestreamquery::operation::{
+    cancel_query::CancelQueryInput,
+    query::{QueryInput, QueryOutput},
+};
+use aws_smithy_runtime_api::client::interceptors::context;
+use opentelemetry_semantic_conventions::attribute as semco;
+
+use super::super::{AttributeExtractor, SpanWrite};
+use super::sanitize::sanitize_literals;
+
+/// `db.system.name` value for Timestream.
+const DB_SYSTEM_NAME_AWS_TIMESTREAM: &str = "aws.timestream";
+
+/// Attribute key for the Timestream query ID.
+const AWS_TIMESTREAM_QUERY_ID: &str = "aws.timestream.query_id";
+/// Attribute key for the bytes scanned by the query so far.
+const AWS_TIMESTREAM_BYTES_SCANNED: &str = "aws.timestream.bytes_scanned";
+/// Attribute key for the bytes metered for the query so far.
+const AWS_TIMESTREAM_BYTES_METERED: &str = "aws.timestream.bytes_metered";
+
+/// Attribute extractor for Timestream Query SDK calls.
+///
+/// `TimestreamQueryExtractor` implements [`AttributeExtractor`] and is
+/// automatically used by [`DefaultExtractor`] when the
+/// `extract-timestream-query` feature is enabled. You only need to construct
+/// it directly if you are composing a custom extraction pipeline.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes.
+///
+/// [`DefaultExtractor`]: crate::interceptor::DefaultExtractor
+#[derive(Debug, Default)]
+pub struct TimestreamQueryExtractor {
+    _private: (),
+}
+
+impl TimestreamQueryExtractor {
+    /// Creates a new `TimestreamQueryExtractor`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::extract::timestream_query::TimestreamQueryExtractor;
+    ///
+    /// let extractor = TimestreamQueryExtractor::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+/// Extracts database OTel attributes from Timestream Query SDK inputs and
+/// outputs.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes and which operations they apply to.
+impl<SW: SpanWrite> AttributeExtractor<SW> for TimestreamQueryExtractor {
+    fn extract_input(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        input: &context::Input,
+        span: &mut SW,
+    ) {
+        match operation {
+            "Query" => {
+                let i = input.downcast_ref::<QueryInput>().expect("correct type");
+                span.set_attribute(
+                    crate::interceptor::DB_SYSTEM_NAME,
+                    DB_SYSTEM_NAME_AWS_TIMESTREAM,
+                );
+                if let Some(query) = i.query_string().filter(|s| !s.is_empty()) {
+                    span.set_attribute(
+                        semco::DB_QUERY_TEXT,
+                        sanitize_literals(query, &['\''], &['"'], false, false),
+                    );
+                }
+            }
+            "CancelQuery" => {
+                let i = input
+                    .downcast_ref::<CancelQueryInput>()
+                    .expect("correct type");
+                span.set_attribute(
+                    crate::interceptor::DB_SYSTEM_NAME,
+                    DB_SYSTEM_NAME_AWS_TIMESTREAM,
+                );
+                if let Some(query_id) = i.query_id().filter(|s| !s.is_empty()) {
+                    span.set_attribute(AWS_TIMESTREAM_QUERY_ID, query_id.to_owned());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn extract_output(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        output: &context::Output,
+        span: &mut SW,
+    ) {
+        if operation == "Query" {
+            let o = output.downcast_ref::<QueryOutput>().expect("correct type");
+            if !o.query_id().is_empty() {
+                span.set_attribute(AWS_TIMESTREAM_QUERY_ID, o.query_id().to_owned());
+            }
+            span.set_attribute(semco::DB_RESPONSE_RETURNED_ROWS, o.rows().len() as i64);
+            if let Some(status) = o.query_status() {
+                span.set_attribute(
+                    AWS_TIMESTREAM_BYTES_SCANNED,
+                    status.cumulative_bytes_scanned(),
+                );
+                span.set_attribute(
+                    AWS_TIMESTREAM_BYTES_METERED,
+                    status.cumulative_bytes_metered(),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_timestreamquery::types::{QueryStatus, Row};
+    use opentelemetry::Value;
+
+    use crate::span_write::{SpanContext, SpanWrite, Status};
+
+    struct TestSpan {
+        attributes: Vec<(&'static str, Value)>,
+    }
+
+    impl TestSpan {
+        fn new() -> Self {
+            Self { attributes: vec![] }
+        }
+
+        fn get(&self, key: &str) -> Option<&Value> {
+            self.attributes
+                .iter()
+                .rev()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v)
+        }
+    }
+
+    impl SpanWrite for TestSpan {
+        fn set_attribute(&mut self, key: &'static str, value: impl Into<Value>) {
+            self.attributes.push((key, value.into()));
+        }
+
+        fn set_status(&mut self, _code: Status) {}
+
+        fn span_context(&self) -> SpanContext {
+            SpanContext::empty_context()
+        }
+    }
+
+    // Tests for TimestreamQueryExtractor — 2 consolidated tests
+
+    #[test]
+    fn extract_query() {
+        let extractor = TimestreamQueryExtractor::new();
+        let sdk_input = QueryInput::builder()
+            .query_string(
+                "SELECT avg(measure_value::double) FROM \"iot\".\"sensors\" \
+                 WHERE device_id = 'dev-42' AND time > ago(15m) LIMIT 100",
+            )
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "Timestream Query",
+            "Query",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(crate::interceptor::DB_SYSTEM_NAME),
+            Some(&Value::from("aws.timestream"))
+        );
+        assert_eq!(
+            span.get(semco::DB_QUERY_TEXT),
+            Some(&Value::from(
+                "SELECT avg(measure_value::double) FROM \"iot\".\"sensors\" \
+                 WHERE device_id = ? AND time > ago(?m) LIMIT ?"
+            ))
+        );
+
+        let sdk_output = QueryOutput::builder()
+            .query_id("AEBQEAMY")
+            .rows(Row::builder().set_data(Some(vec![])).build().unwrap())
+            .rows(Row::builder().set_data(Some(vec![])).build().unwrap())
+            .set_column_info(Some(vec![]))
+            .query_status(
+                QueryStatus::builder()
+                    .progress_percentage(100.0)
+                    .cumulative_bytes_scanned(4096)
+                    .cumulative_bytes_metered(10_000_000)
+                    .build(),
+            )
+            .build()
+            .unwrap();
+        extractor.extract_output(
+            "Timestream Query",
+            "Query",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_TIMESTREAM_QUERY_ID),
+            Some(&Value::from("AEBQEAMY"))
+        );
+        assert_eq!(
+            span.get(semco::DB_RESPONSE_RETURNED_ROWS),
+            Some(&Value::I64(2))
+        );
+        assert_eq!(
+            span.get(AWS_TIMESTREAM_BYTES_SCANNED),
+            Some(&Value::I64(4096))
+        );
+        assert_eq!(
+            span.get(AWS_TIMESTREAM_BYTES_METERED),
+            Some(&Value::I64(10_000_000))
+        );
+    }
+
+    #[test]
+    fn extract_cancel_query() {
+        let sdk_input = CancelQueryInput::builder()
+            .query_id("AEBQEAMY")
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        TimestreamQueryExtractor::new().extract_input(
+            "Timestream Query",
+            "CancelQuery",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_TIMESTREAM_QUERY_ID),
+            Some(&Value::from("AEBQEAMY"))
+        );
+        assert_eq!(span.get(semco::DB_QUERY_TEXT), None);
+    }
+}
@@ -0,0 +1,318 @@
+//! Timestream Write attribute extraction following the OTel database semantic
+//! conventions.
+//!
+//! This module provides [`TimestreamWriteExtractor`], which implements
+//! [`super::super::AttributeExtractor`] for the Timestream `WriteRecords` SDK
+//! call. It is automatically used by [`super::super::DefaultExtractor`] when
+//! the `extract-timestream-write` feature is enabled.
+//!
+//! ## Extracted attributes
+//!
+//! **Set from input:**
+//! - `db.system.name` — always `"aws.timestream"`
+//! - `db.namespace` — the database name
+//! - `db.collection.name` — the table name
+//! - `aws.timestream.record_count` — the number of records written
+//!
+//! **Set from output:**
+//! - `aws.timestream.records_ingested` — the number of records ingested
+//! - `aws.timestream.records_ingested.memory_store` — the number of records
+//!   ingested into the memory store
+//! - `aws.timestream.records_ingested.magnetic_store` — the number of records
+//!   ingested into the magnetic store
+//!
+//! **Set from error:**
+//! - `aws.timestream.rejected_record_count` — on a `RejectedRecordsException`,
+//!   the number of records rejected
+//! - `aws.timestream.rejected_record_indexes` — on a
+//!   `RejectedRecordsException`, the indexes of the rejected records in the
+//!   request, capped at 100 entries
+//!
+//! Dimensions and measure values are never recorded.
+
+// Timestream Write attribute extraction — downcasts Input/Output/Error to
+// concrete aws-sdk-timestreamwrite types and extracts ingestion attributes.
+
+use aws_sdk_timestreamwrite::operation::write_records::{
+    WriteRecordsError, WriteRecordsInput, WriteRecordsOutput,
+};
+use aws_smithy_runtime_api::client::interceptors::context;
+use opentelemetry::{Array, Value};
+use opentelemetry_semantic_conventions::attribute as semco;
+
+use super::super::{AttributeExtractor, SpanWrite};
+use super::set_str;
+
+/// `db.system.name` value for Timestream.
+const DB_SYSTEM_NAME_AWS_TIMESTREAM: &str = "aws.timestream";
+
+/// Attribute key for the number of records written.
+const AWS_TIMESTREAM_RECORD_COUNT: &str = "aws.timestream.record_count";
+/// Attribute key for the number of records ingested.
+const AWS_TIMESTREAM_RECORDS_INGESTED: &str = "aws.timestream.records_ingested";
+/// Attribute key for the number of records ingested into the memory store.
+const AWS_TIMESTREAM_RECORDS_INGESTED_MEMORY_STORE: &str =
+    "aws.timestream.records_ingested.memory_store";
+/// Attribute key for the number of records ingested into the magnetic store.
+const AWS_TIMESTREAM_RECORDS_INGESTED_MAGNETIC_STORE: &str =
+    "aws.timestream.records_ingested.magnetic_store";
+/// Attribute key for the number of rejected records.
+const AWS_TIMESTREAM_REJECTED_RECORD_COUNT: &str = "aws.timestream.rejected_record_count";
+/// Attribute key for the request indexes of the rejected records.
+const AWS_TIMESTREAM_REJECTED_RECORD_INDEXES: &str = "aws.timestream.rejected_record_indexes";
+
+/// Maximum number of entries recorded in
+/// `aws.timestream.rejected_record_indexes`.
+const MAX_REJECTED_RECORD_INDEXES: usize = 100;
+
+/// Attribute extractor for Timestream Write SDK calls.
+///
+/// `TimestreamWriteExtractor` implements [`AttributeExtractor`] and is
+/// automatically used by [`DefaultExtractor`] when the
+/// `extract-timestream-write` feature is enabled. You only need to construct
+/// it directly if you are composing a custom extraction pipeline.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes.
+///
+/// [`DefaultExtractor`]: crate::interceptor::DefaultExtractor
+#[derive(Debug, Default)]
+pub struct TimestreamWriteExtractor {
+    _private: (),
+}
+
+impl TimestreamWriteExtractor {
+    /// Creates a new `TimestreamWriteExtractor`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::extract::timestream_write::TimestreamWriteExtractor;
+    ///
+    /// let extractor = TimestreamWriteExtractor::new();
+    /// ```
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+/// Extracts database OTel attributes from Timestream Write SDK inputs,
+/// outputs and errors.
+///
+/// See the [module-level documentation](self) for the full list of extracted
+/// attributes and which operations they apply to.
+impl<SW: SpanWrite> AttributeExtractor<SW> for TimestreamWriteExtractor {
+    fn extract_input(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        input: &context::Input,
+        span: &mut SW,
+    ) {
+        if operation == "WriteRecords" {
+            let i = input
+                .downcast_ref::<WriteRecordsInput>()
+                .expect("correct type");
+            span.set_attribute(
+                crate::interceptor::DB_SYSTEM_NAME,
+                DB_SYSTEM_NAME_AWS_TIMESTREAM,
+            );
+            set_str(span, semco::DB_NAMESPACE, i.database_name());
+            set_str(span, semco::DB_COLLECTION_NAME, i.table_name());
+            span.set_attribute(AWS_TIMESTREAM_RECORD_COUNT, i.records().len() as i64);
+        }
+    }
+
+    fn extract_output(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        output: &context::Output,
+        span: &mut SW,
+    ) {
+        if operation == "WriteRecords" {
+            let o = output
+                .downcast_ref::<WriteRecordsOutput>()
+                .expect("correct type");
+            if let Some(ingested) = o.records_ingested() {
+                span.set_attribute(AWS_TIMESTREAM_RECORDS_INGESTED, i64::from(ingested.total()));
+                span.set_attribute(
+                    AWS_TIMESTREAM_RECORDS_INGESTED_MEMORY_STORE,
+                    i64::from(ingested.memory_store()),
+                );
+                span.set_attribute(
+                    AWS_TIMESTREAM_RECORDS_INGESTED_MAGNETIC_STORE,
+                    i64::from(ingested.magnetic_store()),
+                );
+            }
+        }
+    }
+
+    fn extract_error(
+        &self,
+        _service: crate::interceptor::Service,
+        operation: crate::interceptor::Operation,
+        error: &context::Error,
+        span: &mut SW,
+    ) {
+        if operation == "WriteRecords" {
+            if let Some(WriteRecordsError::RejectedRecordsException(e)) =
+                error.downcast_ref::<WriteRecordsError>()
+            {
+                let rejected = e.rejected_records();
+                span.set_attribute(AWS_TIMESTREAM_REJECTED_RECORD_COUNT, rejected.len() as i64);
+                let indexes: Vec<i64> = rejected
+                    .iter()
+                    .take(MAX_REJECTED_RECORD_INDEXES)
+                    .map(|record| i64::from(record.record_index()))
+                    .collect();
+                if !indexes.is_empty() {
+                    span.set_attribute(
+                        AWS_TIMESTREAM_REJECTED_RECORD_INDEXES,
+                        Value::Array(Array::I64(indexes)),
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_timestreamwrite::types::error::RejectedRecordsException;
+    use aws_sdk_timestreamwrite::types::{Record, RecordsIngested, RejectedRecord};
+
+    use crate::span_write::{SpanContext, SpanWrite, Status};
+
+    struct TestSpan {
+        attributes: Vec<(&'static str, Value)>,
+    }
+
+    impl TestSpan {
+        fn new() -> Self {
+            Self { attributes: vec![] }
+        }
+
+        fn get(&self, key: &str) -> Option<&Value> {
+            self.attributes
+                .iter()
+                .rev()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v)
+        }
+    }
+
+    impl SpanWrite for TestSpan {
+        fn set_attribute(&mut self, key: &'static str, value: impl Into<Value>) {
+            self.attributes.push((key, value.into()));
+        }
+
+        fn set_status(&mut self, _code: Status) {}
+
+        fn span_context(&self) -> SpanContext {
+            SpanContext::empty_context()
+        }
+    }
+
+    // Tests for TimestreamWriteExtractor — 2 consolidated tests
+
+    #[test]
+    fn extract_write_records() {
+        let extractor = TimestreamWriteExtractor::new();
+        let record = |value: &str| {
+            Record::builder()
+                .measure_name("temperature")
+                .measure_value(value)
+                .build()
+        };
+        let sdk_input = WriteRecordsInput::builder()
+            .database_name("iot")
+            .table_name("sensors")
+            .records(record("21.5"))
+            .records(record("22.0"))
+            .records(record("22.4"))
+            .build()
+            .unwrap();
+        let mut span = TestSpan::new();
+        extractor.extract_input(
+            "Timestream Write",
+            "WriteRecords",
+            &context::Input::erase(sdk_input),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(crate::interceptor::DB_SYSTEM_NAME),
+            Some(&Value::from("aws.timestream"))
+        );
+        assert_eq!(span.get(semco::DB_NAMESPACE), Some(&Value::from("iot")));
+        assert_eq!(
+            span.get(semco::DB_COLLECTION_NAME),
+            Some(&Value::from("sensors"))
+        );
+        assert_eq!(span.get(AWS_TIMESTREAM_RECORD_COUNT), Some(&Value::I64(3)));
+        assert!(
+            span.attributes
+                .iter()
+                .all(|(_, value)| !value.to_string().contains("21.5"))
+        );
+
+        let sdk_output = WriteRecordsOutput::builder()
+            .records_ingested(
+                RecordsIngested::builder()
+                    .total(3)
+                    .memory_store(2)
+                    .magnetic_store(1)
+                    .build(),
+            )
+            .build();
+        extractor.extract_output(
+            "Timestream Write",
+            "WriteRecords",
+            &context::Output::erase(sdk_output),
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_TIMESTREAM_RECORDS_INGESTED),
+            Some(&Value::I64(3))
+        );
+        assert_eq!(
+            span.get(AWS_TIMESTREAM_RECORDS_INGESTED_MEMORY_STORE),
+            Some(&Value::I64(2))
+        );
+        assert_eq!(
+            span.get(AWS_TIMESTREAM_RECORDS_INGESTED_MAGNETIC_STORE),
+            Some(&Value::I64(1))
+        );
+    }
+
+    #[test]
+    fn extract_rejected_records() {
+        let rejected = RejectedRecordsException::builder()
+            .message("One or more records have been rejected.")
+            .rejected_records(
+                RejectedRecord::builder()
+                    .record_index(0)
+                    .reason("The record timestamp is outside the time range")
+                    .build(),
+            )
+            .rejected_records(RejectedRecord::builder().record_index(2).build())
+            .build();
+        let error = context::Error::erase(WriteRecordsError::RejectedRecordsException(rejected));
+        let mut span = TestSpan::new();
+        TimestreamWriteExtractor::new().extract_error(
+            "Timestream Write",
+            "WriteRecords",
+            &error,
+            &mut span,
+        );
+        assert_eq!(
+            span.get(AWS_TIMESTREAM_REJECTED_RECORD_COUNT),
+            Some(&Value::I64(2))
+        );
+        assert_eq!(
+            span.get(AWS_TIMESTREAM_REJECTED_RECORD_INDEXES),
+            Some(&Value::Array(Array::I64(vec![0, 2])))
+        );
+    }
+}
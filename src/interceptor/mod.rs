@@ -11,7 +11,8 @@
 //!
 //! For each SDK call the interceptor runs four extraction phases in order:
 //!
-//! 1. **Input** — before serialization; extracts table names, bucket names, etc.
+//! 1. **Input** — before serialization; extracts table names, bucket names, etc.,
+//!    then lets extractors modify the input (e.g. to inject trace context).
 //! 2. **Request** — after serialization; extracts HTTP-level request attributes.
 //! 3. **Response** — before deserialization; extracts HTTP status, request ID,
 //!    and extended request ID.
@@ -20,7 +21,8 @@
 //!
 //! Within each phase the dispatch order is:
 //!
-//! 1. Built-in per-service extractors (DynamoDB, S3, SQS — feature-gated).
+//! 1. Built-in per-service extractors (DynamoDB, S3, SQS, … — one per
+//!    enabled `extract-*` feature, see [`extract`]).
 //! 2. User-registered [`AttributeExtractor`] implementations.
 //! 3. User-registered closure hooks, filtered by [`ServiceFilter`].
 //!
@@ -227,7 +229,7 @@ impl ServiceFilter {
 ///
 /// Register your implementation with
 /// [`DefaultExtractor::register_attribute_extractor`]. It will be called after
-/// the built-in per-service extractors (DynamoDB, S3, SQS) and before any
+/// the built-in per-service extractors (DynamoDB, S3, SQS, …) and before any
 /// closure hooks.
 ///
 /// # Examples
@@ -276,6 +278,24 @@ pub trait AttributeExtractor<SW: SpanWrite> {
     ) {
     }
 
+    /// Modify the SDK input before serialization.
+    ///
+    /// Called once per SDK call, right after [`extract_input`], with mutable
+    /// access to the type-erased input. Built-in extractors use this phase to
+    /// inject trace context into outgoing messages (e.g. SNS message
+    /// attributes) when configured to. Downcast `input` with
+    /// `input.downcast_mut::<MyOperationInput>()`.
+    ///
+    /// [`extract_input`]: AttributeExtractor::extract_input
+    fn modify_input(
+        &self,
+        _service: Service,
+        _operation: Operation,
+        _input: &mut context::Input,
+        _span: &mut SW,
+    ) {
+    }
+
     /// Extract attributes from the serialized HTTP request.
     ///
     /// Called once per SDK call, after the input has been serialized into an
@@ -341,6 +361,10 @@ pub trait AttributeExtractor<SW: SpanWrite> {
 /// Boxed closure type for input-phase extraction hooks.
 type InputHook<SW> =
     Box<dyn for<'a> Fn(Service<'a>, Operation<'a>, &'a context::Input, &'a mut SW) + Send + Sync>;
+/// Boxed closure type for input-modification hooks.
+type ModifyInputHook<SW> = Box<
+    dyn for<'a> Fn(Service<'a>, Operation<'a>, &'a mut context::Input, &'a mut SW) + Send + Sync,
+>;
 /// Boxed closure type for request-phase extraction hooks.
 type RequestHook<SW> =
     Box<dyn for<'a> Fn(Service<'a>, Operation<'a>, &'a http::Request, &'a mut SW) + Send + Sync>;
@@ -360,8 +384,8 @@ type ErrorHook<SW> =
 /// each SDK call it:
 ///
 /// 1. Dispatches to the appropriate built-in service extractor
-///    ([`DynamoDBExtractor`], [`S3Extractor`], [`SQSExtractor`]) when the
-///    corresponding feature is enabled.
+///    ([`DynamoDBExtractor`], [`S3Extractor`], [`SQSExtractor`], … — see
+///    [`extract`]) when the corresponding feature is enabled.
 /// 2. Calls every [`AttributeExtractor`] registered via
 ///    [`register_attribute_extractor`].
 /// 3. Calls every closure hook registered via the `register_*_hook` methods,
@@ -398,16 +422,61 @@ type ErrorHook<SW> =
 /// [`OtelInterceptor`]: crate::interceptor::otel::OtelInterceptor
 pub struct DefaultExtractor<SW: SpanWrite> {
     // Default extractors
+    #[cfg(feature = "extract-athena")]
+    athena_extractor: extract::athena::AthenaExtractor,
+    #[cfg(feature = "extract-bedrock")]
+    bedrock_extractor: extract::bedrock::BedrockExtractor,
+    #[cfg(feature = "extract-bedrock-agent")]
+    bedrock_agent_extractor: extract::bedrock_agent::BedrockAgentExtractor,
+    #[cfg(feature = "extract-cloudwatch")]
+    cloudwatch_extractor: extract::cloudwatch::CloudWatchExtractor,
     #[cfg(feature = "extract-dynamodb")]
     dynamodb_extractor: extract::dynamodb::DynamoDBExtractor,
+    #[cfg(feature = "extract-eventbridge")]
+    eventbridge_extractor: extract::eventbridge::EventBridgeExtractor,
+    #[cfg(feature = "extract-firehose")]
+    firehose_extractor: extract::firehose::FirehoseExtractor,
+    #[cfg(feature = "extract-kinesis")]
+    kinesis_extractor: extract::kinesis::KinesisExtractor,
+    #[cfg(feature = "extract-kms")]
+    kms_extractor: extract::kms::KMSExtractor,
+    #[cfg(feature = "extract-lambda-client")]
+    lambda_client_extractor: extract::lambda_client::LambdaClientExtractor,
+    #[cfg(feature = "extract-logs")]
+    logs_extractor: extract::logs::CloudWatchLogsExtractor,
+    #[cfg(feature = "extract-neptunedata")]
+    neptunedata_extractor: extract::neptunedata::NeptuneDataExtractor,
+    #[cfg(feature = "extract-rdsdata")]
+    rdsdata_extractor: extract::rdsdata::RDSDataExtractor,
+    #[cfg(feature = "extract-redshift-data")]
+    redshift_data_extractor: extract::redshift_data::RedshiftDataExtractor,
     #[cfg(feature = "extract-s3")]
     s3_extractor: extract::s3::S3Extractor,
+    #[cfg(feature = "extract-sagemaker-runtime")]
+    sagemaker_runtime_extractor: extract::sagemaker_runtime::SageMakerRuntimeExtractor,
+    #[cfg(feature = "extract-secretsmanager")]
+    secretsmanager_extractor: extract::secretsmanager::SecretsManagerExtractor,
+    #[cfg(feature = "extract-sesv2")]
+    sesv2_extractor: extract::sesv2::SESv2Extractor,
+    #[cfg(feature = "extract-sfn")]
+    sfn_extractor: extract::sfn::SFNExtractor,
+    #[cfg(feature = "extract-sns")]
+    sns_extractor: extract::sns::SNSExtractor,
     #[cfg(feature = "extract-sqs")]
     sqs_extractor: extract::sqs::SQSExtractor,
+    #[cfg(feature = "extract-ssm")]
+    ssm_extractor: extract::ssm::SSMExtractor,
+    #[cfg(feature = "extract-sts")]
+    sts_extractor: extract::sts::STSExtractor,
+    #[cfg(feature = "extract-timestream-query")]
+    timestream_query_extractor: extract::timestream_query::TimestreamQueryExtractor,
+    #[cfg(feature = "extract-timestream-write")]
+    timestream_write_extractor: extract::timestream_write::TimestreamWriteExtractor,
     // User-registered trait-based extractors, run after built-in.
     custom_extractors: Vec<Box<dyn AttributeExtractor<SW> + Send + Sync>>,
     // User-registered closures, each scoped by a ServiceFilter, run last.
     input_hooks: Vec<(ServiceFilter, InputHook<SW>)>,
+    modify_input_hooks: Vec<(ServiceFilter, ModifyInputHook<SW>)>,
     request_hooks: Vec<(ServiceFilter, RequestHook<SW>)>,
     response_hooks: Vec<(ServiceFilter, ResponseHook<SW>)>,
     output_hooks: Vec<(ServiceFilter, OutputHook<SW>)>,
@@ -424,14 +493,60 @@ impl<SW: SpanWrite> DefaultExtractor<SW> {
     /// Creates a new `DefaultExtractor` with all built-in service extractors and no user extensions.
     fn new() -> Self {
         Self {
+            #[cfg(feature = "extract-athena")]
+            athena_extractor: extract::athena::AthenaExtractor::new(),
+            #[cfg(feature = "extract-bedrock")]
+            bedrock_extractor: extract::bedrock::BedrockExtractor::new(),
+            #[cfg(feature = "extract-bedrock-agent")]
+            bedrock_agent_extractor: extract::bedrock_agent::BedrockAgentExtractor::new(),
+            #[cfg(feature = "extract-cloudwatch")]
+            cloudwatch_extractor: extract::cloudwatch::CloudWatchExtractor::new(),
             #[cfg(feature = "extract-dynamodb")]
             dynamodb_extractor: extract::dynamodb::DynamoDBExtractor::new(),
+            #[cfg(feature = "extract-eventbridge")]
+            eventbridge_extractor: extract::eventbridge::EventBridgeExtractor::new(),
+            #[cfg(feature = "extract-firehose")]
+            firehose_extractor: extract::firehose::FirehoseExtractor::new(),
+            #[cfg(feature = "extract-kinesis")]
+            kinesis_extractor: extract::kinesis::KinesisExtractor::new(),
+            #[cfg(feature = "extract-kms")]
+            kms_extractor: extract::kms::KMSExtractor::new(),
+            #[cfg(feature = "extract-lambda-client")]
+            lambda_client_extractor: extract::lambda_client::LambdaClientExtractor::new(),
+            #[cfg(feature = "extract-logs")]
+            logs_extractor: extract::logs::CloudWatchLogsExtractor::new(),
+            #[cfg(feature = "extract-neptunedata")]
+            neptunedata_extractor: extract::neptunedata::NeptuneDataExtractor::new(),
+            #[cfg(feature = "extract-rdsdata")]
+            rdsdata_extractor: extract::rdsdata::RDSDataExtractor::new(),
+            #[cfg(feature = "extract-redshift-data")]
+            redshift_data_extractor: extract::redshift_data::RedshiftDataExtractor::new(),
             #[cfg(feature = "extract-s3")]
             s3_extractor: extract::s3::S3Extractor::new(),
+            #[cfg(feature = "extract-sagemaker-runtime")]
+            sagemaker_runtime_extractor: extract::sagemaker_runtime::SageMakerRuntimeExtractor::new(
+            ),
+            #[cfg(feature = "extract-secretsmanager")]
+            secretsmanager_extractor: extract::secretsmanager::SecretsManagerExtractor::new(),
+            #[cfg(feature = "extract-sesv2")]
+            sesv2_extractor: extract::sesv2::SESv2Extractor::new(),
+            #[cfg(feature = "extract-sfn")]
+            sfn_extractor: extract::sfn::SFNExtractor::new(),
+            #[cfg(feature = "extract-sns")]
+            sns_extractor: extract::sns::SNSExtractor::new(),
             #[cfg(feature = "extract-sqs")]
             sqs_extractor: extract::sqs::SQSExtractor::new(),
+            #[cfg(feature = "extract-ssm")]
+            ssm_extractor: extract::ssm::SSMExtractor::new(),
+            #[cfg(feature = "extract-sts")]
+            sts_extractor: extract::sts::STSExtractor::new(),
+            #[cfg(feature = "extract-timestream-query")]
+            timestream_query_extractor: extract::timestream_query::TimestreamQueryExtractor::new(),
+            #[cfg(feature = "extract-timestream-write")]
+            timestream_write_extractor: extract::timestream_write::TimestreamWriteExtractor::new(),
             custom_extractors: Vec::new(),
             input_hooks: Vec::new(),
+            modify_input_hooks: Vec::new(),
             request_hooks: Vec::new(),
             response_hooks: Vec::new(),
             output_hooks: Vec::new(),
@@ -469,6 +584,39 @@ impl<SW: SpanWrite> DefaultExtractor<SW> {
         self.input_hooks.push((filter, Box::new(hook)));
     }
 
+    /// Register a closure that may modify the SDK input of matching SDK calls
+    /// before it is serialized.
+    ///
+    /// The hook receives the service name, operation name, the type-erased SDK
+    /// input with mutable access, and a mutable reference to the active span.
+    /// It runs after every input-phase hook and after the built-in service
+    /// extractors had a chance to modify the input.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use awssdk_instrumentation::interceptor::{DefaultInterceptor, ServiceFilter};
+    ///
+    /// let mut interceptor = DefaultInterceptor::new();
+    /// interceptor.extractor.register_modify_input_hook(
+    ///     ServiceFilter::Operation("DynamoDB", "GetItem"),
+    ///     |_service, _operation, input, _span| {
+    ///         if let Some(input) =
+    ///             input.downcast_mut::<aws_sdk_dynamodb::operation::get_item::GetItemInput>()
+    ///         {
+    ///             input.consistent_read = Some(true);
+    ///         }
+    ///     },
+    /// );
+    /// ```
+    pub fn register_modify_input_hook<H>(&mut self, filter: ServiceFilter, hook: H)
+    where
+        H: for<'a> Fn(Service<'a>, Operation<'a>, &'a mut context::Input, &'a mut SW),
+        H: Send + Sync + 'static,
+    {
+        self.modify_input_hooks.push((filter, Box::new(hook)));
+    }
+
     /// Register a closure that runs during the request phase for matching SDK calls.
     ///
     /// The hook receives the service name, operation name, the serialized HTTP
@@ -641,6 +789,129 @@ impl<SW: SpanWrite> DefaultExtractor<SW> {
     {
         self.custom_extractors.push(Box::new(extractor));
     }
+
+    /// Replaces the built-in Bedrock Runtime extractor, e.g. to enable prompt
+    /// and completion capture regardless of the environment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::{
+    ///     DefaultInterceptor, extract::bedrock::BedrockExtractor,
+    /// };
+    ///
+    /// let mut interceptor = DefaultInterceptor::new();
+    /// interceptor
+    ///     .extractor
+    ///     .set_bedrock_extractor(BedrockExtractor::new().with_content_capture(true));
+    /// ```
+    #[cfg(feature = "extract-bedrock")]
+    pub fn set_bedrock_extractor(&mut self, extractor: extract::bedrock::BedrockExtractor) {
+        self.bedrock_extractor = extractor;
+    }
+
+    /// Replaces the built-in EventBridge extractor, e.g. to enable trace header
+    /// injection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::{
+    ///     DefaultInterceptor, extract::eventbridge::EventBridgeExtractor,
+    /// };
+    ///
+    /// let mut interceptor = DefaultInterceptor::new();
+    /// interceptor.extractor.set_eventbridge_extractor(
+    ///     EventBridgeExtractor::new().with_trace_header_injection(true),
+    /// );
+    /// ```
+    #[cfg(feature = "extract-eventbridge")]
+    pub fn set_eventbridge_extractor(
+        &mut self,
+        extractor: extract::eventbridge::EventBridgeExtractor,
+    ) {
+        self.eventbridge_extractor = extractor;
+    }
+
+    /// Replaces the built-in Kinesis extractor, e.g. to enable partition key
+    /// hashing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::{
+    ///     DefaultInterceptor, extract::kinesis::KinesisExtractor,
+    /// };
+    ///
+    /// let mut interceptor = DefaultInterceptor::new();
+    /// interceptor
+    ///     .extractor
+    ///     .set_kinesis_extractor(KinesisExtractor::new().with_partition_key_hashing(true));
+    /// ```
+    #[cfg(feature = "extract-kinesis")]
+    pub fn set_kinesis_extractor(&mut self, extractor: extract::kinesis::KinesisExtractor) {
+        self.kinesis_extractor = extractor;
+    }
+
+    /// Replaces the built-in RDS Data API extractor, e.g. to set the database
+    /// engine of the cluster.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::{
+    ///     DefaultInterceptor, extract::rdsdata::RDSDataExtractor,
+    /// };
+    ///
+    /// let mut interceptor = DefaultInterceptor::new();
+    /// interceptor
+    ///     .extractor
+    ///     .set_rdsdata_extractor(RDSDataExtractor::new().with_db_system_name("mysql"));
+    /// ```
+    #[cfg(feature = "extract-rdsdata")]
+    pub fn set_rdsdata_extractor(&mut self, extractor: extract::rdsdata::RDSDataExtractor) {
+        self.rdsdata_extractor = extractor;
+    }
+
+    /// Replaces the built-in Step Functions extractor, e.g. to enable trace
+    /// header injection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::{
+    ///     DefaultInterceptor, extract::sfn::SFNExtractor,
+    /// };
+    ///
+    /// let mut interceptor = DefaultInterceptor::new();
+    /// interceptor
+    ///     .extractor
+    ///     .set_sfn_extractor(SFNExtractor::new().with_trace_header_injection(true));
+    /// ```
+    #[cfg(feature = "extract-sfn")]
+    pub fn set_sfn_extractor(&mut self, extractor: extract::sfn::SFNExtractor) {
+        self.sfn_extractor = extractor;
+    }
+
+    /// Replaces the built-in SNS extractor, e.g. to enable trace context
+    /// injection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use awssdk_instrumentation::interceptor::{
+    ///     DefaultInterceptor, extract::sns::SNSExtractor,
+    /// };
+    ///
+    /// let mut interceptor = DefaultInterceptor::new();
+    /// interceptor
+    ///     .extractor
+    ///     .set_sns_extractor(SNSExtractor::new().with_trace_context_injection(true));
+    /// ```
+    #[cfg(feature = "extract-sns")]
+    pub fn set_sns_extractor(&mut self, extractor: extract::sns::SNSExtractor) {
+        self.sns_extractor = extractor;
+    }
 }
 
 /// Dispatches an extraction phase to built-in service extractors, custom extractors, and closure hooks.
@@ -648,18 +919,106 @@ macro_rules! call_extractors {
     ($self:ident $service:ident $operation:ident $method:ident $hooks:ident $parameter:ident $span:ident) => {
         // Internal extractors
         match $service {
+            #[cfg(feature = "extract-athena")]
+            "Athena" => $self
+                .athena_extractor
+                .$method($service, $operation, $parameter, $span),
+            #[cfg(feature = "extract-bedrock")]
+            "Bedrock Runtime" => $self
+                .bedrock_extractor
+                .$method($service, $operation, $parameter, $span),
+            #[cfg(feature = "extract-bedrock-agent")]
+            "Bedrock Agent Runtime" => $self
+                .bedrock_agent_extractor
+                .$method($service, $operation, $parameter, $span),
+            #[cfg(feature = "extract-cloudwatch")]
+            "CloudWatch" => $self
+                .cloudwatch_extractor
+                .$method($service, $operation, $parameter, $span),
             #[cfg(feature = "extract-dynamodb")]
             "DynamoDB" => $self
                 .dynamodb_extractor
                 .$method($service, $operation, $parameter, $span),
+            #[cfg(feature = "extract-eventbridge")]
+            "EventBridge" => $self
+                .eventbridge_extractor
+                .$method($service, $operation, $parameter, $span),
+            #[cfg(feature = "extract-firehose")]
+            "Firehose" => $self
+                .firehose_extractor
+                .$method($service, $operation, $parameter, $span),
+            #[cfg(feature = "extract-kinesis")]
+            "Kinesis" => $self
+                .kinesis_extractor
+                .$method($service, $operation, $parameter, $span),
+            #[cfg(feature = "extract-kms")]
+            "KMS" => $self
+                .kms_extractor
+                .$method($service, $operation, $parameter, $span),
+            #[cfg(feature = "extract-lambda-client")]
+            "Lambda" => $self
+                .lambda_client_extractor
+                .$method($service, $operation, $parameter, $span),
+            #[cfg(feature = "extract-logs")]
+            "CloudWatch Logs" => $self
+                .logs_extractor
+                .$method($service, $operation, $parameter, $span),
+            #[cfg(feature = "extract-neptunedata")]
+            "neptunedata" => $self
+                .neptunedata_extractor
+                .$method($service, $operation, $parameter, $span),
+            #[cfg(feature = "extract-rdsdata")]
+            "RDS Data" => $self
+                .rdsdata_extractor
+                .$method($service, $operation, $parameter, $span),
+            #[cfg(feature = "extract-redshift-data")]
+            "Redshift Data" => $self
+                .redshift_data_extractor
+                .$method($service, $operation, $parameter, $span),
             #[cfg(feature = "extract-s3")]
             "S3" => $self
                 .s3_extractor
                 .$method($service, $operation, $parameter, $span),
+            #[cfg(feature = "extract-sagemaker-runtime")]
+            "SageMaker Runtime" => $self
+                .sagemaker_runtime_extractor
+                .$method($service, $operation, $parameter, $span),
+            #[cfg(feature = "extract-secretsmanager")]
+            "Secrets Manager" => $self
+                .secretsmanager_extractor
+                .$method($service, $operation, $parameter, $span),
+            #[cfg(feature = "extract-sesv2")]
+            "SESv2" => $self
+                .sesv2_extractor
+                .$method($service, $operation, $parameter, $span),
+            #[cfg(feature = "extract-sfn")]
+            "SFN" => $self
+                .sfn_extractor
+                .$method($service, $operation, $parameter, $span),
+            #[cfg(feature = "extract-sns")]
+            "SNS" => $self
+                .sns_extractor
+                .$method($service, $operation, $parameter, $span),
             #[cfg(feature = "extract-sqs")]
             "SQS" => $self
                 .sqs_extractor
                 .$method($service, $operation, $parameter, $span),
+            #[cfg(feature = "extract-ssm")]
+            "SSM" => $self
+                .ssm_extractor
+                .$method($service, $operation, $parameter, $span),
+            #[cfg(feature = "extract-sts")]
+            "STS" => $self
+                .sts_extractor
+                .$method($service, $operation, $parameter, $span),
+            #[cfg(feature = "extract-timestream-query")]
+            "Timestream Query" => $self
+                .timestream_query_extractor
+                .$method($service, $operation, $parameter, $span),
+            #[cfg(feature = "extract-timestream-write")]
+            "Timestream Write" => $self
+                .timestream_write_extractor
+                .$method($service, $operation, $parameter, $span),
             _ => {}
         }
 
@@ -741,6 +1100,23 @@ impl<SW: SpanWrite> DefaultExtractor<SW> {
         Ok(())
     }
 
+    /// Runs the input modification phase: dispatches to all registered extractors and hooks
+    /// with mutable access to the SDK input.
+    fn modify_before_serialization(
+        &self,
+        context: &mut context::BeforeSerializationInterceptorContextMut<'_>,
+        cfg: &mut ConfigBag,
+        span: &mut SW,
+    ) -> Result<(), BoxError> {
+        let (service, operation) = extract_service_operation(cfg);
+
+        let input = context.input_mut();
+
+        call_extractors!(self service operation modify_input modify_input_hooks input span);
+
+        Ok(())
+    }
+
     /// Runs the request extraction phase: dispatches to all registered extractors and hooks with the serialized HTTP request.
     fn read_after_serialization(
         &self,
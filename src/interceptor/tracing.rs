@@ -21,8 +21,8 @@ use aws_smithy_runtime_api::{
             Intercept,
             context::{
                 BeforeDeserializationInterceptorContextRef,
-                BeforeSerializationInterceptorContextRef, BeforeTransmitInterceptorContextRef,
-                FinalizerInterceptorContextRef,
+                BeforeSerializationInterceptorContextMut, BeforeSerializationInterceptorContextRef,
+                BeforeTransmitInterceptorContextRef, FinalizerInterceptorContextRef,
             },
         },
         runtime_components::RuntimeComponents,
@@ -42,7 +42,8 @@ use super::{
 /// `TracingInterceptor` implements the AWS SDK [`Intercept`] trait and hooks
 /// into the four SDK lifecycle phases (before serialization, after serialization,
 /// before deserialization, after execution) to extract OTel semantic-convention
-/// attributes from each SDK call.
+/// attributes from each SDK call. It also gives extractors a chance to modify
+/// the SDK input before serialization, e.g. to inject trace context.
 ///
 /// Attributes are written to the `tracing::Span` that the AWS SDK creates for
 /// the operation. The `tracing-opentelemetry` bridge then forwards those
@@ -151,6 +152,24 @@ impl Intercept for TracingInterceptor {
         }
     }
 
+    fn modify_before_serialization(
+        &self,
+        context: &mut BeforeSerializationInterceptorContextMut<'_>,
+        _runtime_components: &RuntimeComponents,
+        cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        let mut so_span = std::mem::take(
+            cfg.get_mut_from_interceptor_state::<StorableOption<Span>>()
+                .ok_or("No StorableOption<Span> in the ConfigBag")?,
+        );
+        if let Some(span) = so_span.as_mut() {
+            self.extractor
+                .modify_before_serialization(context, cfg, span)?;
+        }
+        cfg.interceptor_state().store_put(so_span);
+        Ok(())
+    }
+
     fn read_after_serialization(
         &self,
         context: &BeforeTransmitInterceptorContextRef<'_>,
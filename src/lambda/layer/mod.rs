@@ -57,9 +57,8 @@ use lambda_runtime::{
 };
 use pin_project::{pin_project, pinned_drop};
 
-use utils::XRayTraceHeader;
-
 use crate::span_write::SpanWrite;
+use crate::xray::XRayTraceHeader;
 
 // Tower Layer for Lambda invocations — creates a span per invocation,
 // extracts _X_AMZN_TRACE_ID, sets invocation attributes, flushes exporter.
@@ -11,7 +11,8 @@ use opentelemetry_semantic_conventions::attribute as semco;
 use pin_project::{pin_project, pinned_drop};
 use tokio::task::futures::TaskLocalFuture;
 
-use super::{InstrumentedFuture, Instrumentor, utils::XRayTraceHeader};
+use super::{InstrumentedFuture, Instrumentor};
+use crate::xray::XRayTraceHeader;
 
 /// [`Instrumentor`] implementation for the `otel-backend` feature.
 ///
@@ -8,7 +8,8 @@ use tracing::instrument::Instrumented;
 use tracing::{Instrument, Span};
 use tracing_opentelemetry::OpenTelemetrySpanExt;
 
-use super::{InstrumentedFuture, Instrumentor, utils::XRayTraceHeader};
+use super::{InstrumentedFuture, Instrumentor};
+use crate::xray::XRayTraceHeader;
 
 /// [`Instrumentor`] implementation for the `tracing-backend` feature.
 ///
@@ -97,8 +97,8 @@
 //! ```
 //!
 //! The [`interceptor::DefaultExtractor`] inside the interceptor dispatches to
-//! per-service extractors (DynamoDB, S3, SQS) and then runs any user-registered
-//! hooks or [`interceptor::AttributeExtractor`] implementations.
+//! per-service extractors (DynamoDB, S3, SQS, … — one per enabled `extract-*`
+//! feature) and then runs any user-registered hooks or [`interceptor::AttributeExtractor`] implementations.
 //!
 //! ## Lambda support
 //!
@@ -198,11 +198,33 @@
 //!
 //! ## Service attribute extraction
 //!
-//! | Feature            | Default | Description |
-//! |--------------------|---------|-------------|
-//! | `extract-dynamodb` | ✅      | DynamoDB OTel semantic-convention attributes |
-//! | `extract-s3`       |         | S3 OTel semantic-convention attributes |
-//! | `extract-sqs`      |         | SQS OTel semantic-convention attributes |
+//! | Feature                     | Default | Description |
+//! |-----------------------------|---------|-------------|
+//! | `extract-athena`            |         | Athena query attributes with sanitized SQL |
+//! | `extract-bedrock`           |         | Bedrock Runtime GenAI attributes (opt-in prompt capture) |
+//! | `extract-bedrock-agent`     |         | Bedrock Agent Runtime GenAI agent and knowledge base attributes |
+//! | `extract-cloudwatch`        |         | CloudWatch metric namespace, data point count and metric names |
+//! | `extract-dynamodb`          | ✅      | DynamoDB OTel semantic-convention attributes |
+//! | `extract-eventbridge`       |         | EventBridge `PutEvents` attributes and optional trace header injection |
+//! | `extract-firehose`          |         | Firehose attributes (delivery stream, record counts, failed puts) |
+//! | `extract-kinesis`           |         | Kinesis Data Streams attributes (stream, shard, records, consumer lag) |
+//! | `extract-kms`               |         | KMS key IDs, algorithms and payload sizes (never key material) |
+//! | `extract-lambda-client`     |         | Lambda `Invoke` FaaS client attributes (invoked function, invocation type, function errors) |
+//! | `extract-logs`              |         | CloudWatch Logs log group, batch and Logs Insights query attributes |
+//! | `extract-neptunedata`       |         | Neptune Data API openCypher and Gremlin query attributes with sanitized queries |
+//! | `extract-rdsdata`           |         | RDS Data API database attributes with sanitized SQL |
+//! | `extract-redshift-data`     |         | Redshift Data API statement attributes with sanitized SQL |
+//! | `extract-s3`                |         | S3 OTel semantic-convention attributes |
+//! | `extract-sagemaker-runtime` |         | SageMaker Runtime endpoint, variant and payload size attributes |
+//! | `extract-secretsmanager`    |         | Secrets Manager secret IDs, versions and batch counts (never secret values) |
+//! | `extract-sesv2`             |         | SES v2 sending attributes (never recipient addresses) |
+//! | `extract-sfn`               |         | Step Functions execution attributes, hashed task tokens and optional trace header injection |
+//! | `extract-sns`               |         | SNS OTel semantic-convention attributes and optional trace context injection |
+//! | `extract-sqs`               |         | SQS OTel semantic-convention attributes |
+//! | `extract-ssm`               |         | SSM Parameter Store names, paths and counts (never parameter values) |
+//! | `extract-sts`               |         | STS role, session and caller identity attributes (never credentials) |
+//! | `extract-timestream-query`  |         | Timestream query attributes (sanitized query, query ID, bytes scanned) |
+//! | `extract-timestream-write`  |         | Timestream ingestion attributes (database, table, record counts, rejected records) |
 //!
 //! ## Export
 //!
@@ -279,6 +301,14 @@ pub mod span_write;
 
 pub mod init;
 
+#[cfg(any(
+    feature = "env-lambda",
+    feature = "extract-sqs",
+    feature = "extract-eventbridge",
+    feature = "extract-sfn"
+))]
+mod xray;
+
 #[cfg(feature = "export-xray")]
 pub use opentelemetry_aws;
 
@@ -25,7 +25,10 @@ mod otel;
 
 /// Re-export of [`opentelemetry::Value`] for use in [`SpanWrite`] implementations
 /// and [`crate::interceptor::AttributeExtractor`] methods.
-pub use opentelemetry::{Value, trace::Status};
+pub use opentelemetry::{
+    KeyValue, Value,
+    trace::{SpanContext, Status},
+};
 use opentelemetry_semantic_conventions::trace::HTTP_RESPONSE_STATUS_CODE;
 
 /// Backend-agnostic interface for writing attributes and status into a span.
@@ -98,6 +101,30 @@ pub trait SpanWrite {
     /// when you need to refine the status.
     fn set_status(&mut self, code: Status);
 
+    /// Adds an event with the given name and attributes to the span.
+    ///
+    /// Extractors use events to flag noteworthy conditions that do not warrant
+    /// an error status, such as a DynamoDB batch call returning unprocessed
+    /// items. The default implementation discards the event, so existing
+    /// custom implementations keep compiling.
+    fn add_event(&mut self, _name: &'static str, _attributes: Vec<KeyValue>) {}
+
+    /// Adds a link from this span to the span identified by `span_context`.
+    ///
+    /// Extractors use links to tie together spans of a single logical
+    /// operation that the SDK performs as several calls, such as the parts of
+    /// an S3 multipart upload. The default implementation discards the link.
+    fn add_link(&mut self, _span_context: SpanContext, _attributes: Vec<KeyValue>) {}
+
+    /// Returns the [`SpanContext`] of this span.
+    ///
+    /// Extractors read it to let later spans link back to this one. The default
+    /// implementation returns an invalid (empty) context, which extractors
+    /// treat as "nothing to link to".
+    fn span_context(&self) -> SpanContext {
+        SpanContext::empty_context()
+    }
+
     /// Sets the `http.response.status_code` attribute from an HTTP status code.
     ///
     /// This is a convenience wrapper around [`set_attribute`] that converts the
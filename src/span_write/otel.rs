@@ -6,7 +6,7 @@ use opentelemetry::{
     trace::{Span, TraceContextExt},
 };
 
-use super::{SpanWrite, Status, Value};
+use super::{SpanContext, SpanWrite, Status, Value};
 
 /// [`SpanWrite`] impl for OTel's [`BoxedSpan`], setting attributes and status directly on the span.
 impl SpanWrite for BoxedSpan {
@@ -17,6 +17,18 @@ impl SpanWrite for BoxedSpan {
     fn set_status(&mut self, code: Status) {
         Span::set_status(self, code);
     }
+
+    fn add_event(&mut self, name: &'static str, attributes: Vec<KeyValue>) {
+        Span::add_event(self, name, attributes);
+    }
+
+    fn add_link(&mut self, span_context: SpanContext, attributes: Vec<KeyValue>) {
+        Span::add_link(self, span_context, attributes);
+    }
+
+    fn span_context(&self) -> SpanContext {
+        Span::span_context(self).clone()
+    }
 }
 
 /// [`SpanWrite`] impl for OTel [`Context`], forwarding attribute and status writes to the active span.
@@ -28,4 +40,16 @@ impl SpanWrite for Context {
     fn set_status(&mut self, code: Status) {
         self.span().set_status(code);
     }
+
+    fn add_event(&mut self, name: &'static str, attributes: Vec<KeyValue>) {
+        self.span().add_event(name, attributes);
+    }
+
+    fn add_link(&mut self, span_context: SpanContext, attributes: Vec<KeyValue>) {
+        self.span().add_link(span_context, attributes);
+    }
+
+    fn span_context(&self) -> SpanContext {
+        self.span().span_context().clone()
+    }
 }
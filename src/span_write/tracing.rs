@@ -1,9 +1,10 @@
 //! [`SpanWrite`] implementation for the `tracing` backend.
 
+use opentelemetry::trace::TraceContextExt;
 use tracing::Span;
 use tracing_opentelemetry::OpenTelemetrySpanExt;
 
-use super::{SpanWrite, Status, Value};
+use super::{KeyValue, SpanContext, SpanWrite, Status, Value};
 
 /// [`SpanWrite`] impl for `tracing::Span`, delegating to [`OpenTelemetrySpanExt`].
 impl SpanWrite for Span {
@@ -14,4 +15,19 @@ impl SpanWrite for Span {
     fn set_status(&mut self, status: Status) {
         OpenTelemetrySpanExt::set_status(self, status);
     }
+
+    fn add_event(&mut self, name: &'static str, attributes: Vec<KeyValue>) {
+        OpenTelemetrySpanExt::add_event(self, name, attributes);
+    }
+
+    fn add_link(&mut self, span_context: SpanContext, attributes: Vec<KeyValue>) {
+        OpenTelemetrySpanExt::add_link_with_attributes(self, span_context, attributes);
+    }
+
+    fn span_context(&self) -> SpanContext {
+        OpenTelemetrySpanExt::context(self)
+            .span()
+            .span_context()
+            .clone()
+    }
 }
@@ -0,0 +1,212 @@
+//! X-Ray trace header parsing and formatting, shared by the Lambda layer and
+//! the extractors that read or write trace context propagated through AWS
+//! services.
+
+use opentelemetry::{SpanId, TraceFlags, TraceId};
+
+/// Parsed X-Ray trace header containing the trace ID, parent span ID, and sampling flag.
+#[derive(Debug)]
+pub(crate) struct XRayTraceHeader {
+    pub(crate) trace_id: TraceId,
+    pub(crate) parent_id: SpanId,
+    pub(crate) sampled: TraceFlags,
+}
+impl XRayTraceHeader {
+    /// `Root` field key in the X-Ray trace header.
+    const ROOT: &str = "Root";
+    /// `Parent` field key in the X-Ray trace header.
+    const PARENT: &str = "Parent";
+    /// `Sampled` field key in the X-Ray trace header.
+    const SAMPLE: &str = "Sampled";
+    /// `Lineage` field key in the X-Ray trace header (ignored during parsing).
+    const LINEAGE: &str = "Lineage";
+    /// Delimiter between key-value pairs in the X-Ray trace header.
+    const HEADER_DELIMITER: &str = ";";
+
+    /// Builds the header of a valid span context, the span becoming the
+    /// `Parent` of the receiving side.
+    #[cfg(any(feature = "extract-eventbridge", feature = "extract-sfn"))]
+    pub(crate) fn from_span_context(
+        span_context: &opentelemetry::trace::SpanContext,
+    ) -> Option<Self> {
+        span_context.is_valid().then(|| Self {
+            trace_id: span_context.trace_id(),
+            parent_id: span_context.span_id(),
+            sampled: span_context.trace_flags() & TraceFlags::SAMPLED,
+        })
+    }
+}
+/// Formats the header as `Root=1-<epoch>-<unique>;Parent=<span>;Sampled=<0|1>`,
+/// splitting the trace ID the way X-Ray does.
+impl core::fmt::Display for XRayTraceHeader {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let trace_id = self.trace_id.to_string();
+        let (epoch, unique) = trace_id.split_at(8);
+        write!(
+            f,
+            "{}=1-{epoch}-{unique}{delim}{}={}{delim}{}={}",
+            Self::ROOT,
+            Self::PARENT,
+            self.parent_id,
+            Self::SAMPLE,
+            u8::from(self.sampled.is_sampled()),
+            delim = Self::HEADER_DELIMITER,
+        )
+    }
+}
+/// Parses an X-Ray trace header string (e.g. from `_X_AMZN_TRACE_ID` or the SQS
+/// `AWSTraceHeader` system attribute) into an [`XRayTraceHeader`].
+impl core::str::FromStr for XRayTraceHeader {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut xray_header = Self {
+            trace_id: TraceId::INVALID,
+            parent_id: SpanId::INVALID,
+            sampled: TraceFlags::SAMPLED,
+        };
+        let mut trace_id_collected = false;
+        let mut parent_id_collected = false;
+        let mut sampled_collected = false;
+
+        fn map_err(e: impl ToString) -> String {
+            e.to_string()
+        }
+        for (key, value) in s
+            .split(Self::HEADER_DELIMITER)
+            .filter_map(|part| part.split_once('='))
+        {
+            match key {
+                Self::ROOT if !trace_id_collected => {
+                    xray_header.trace_id =
+                        TraceId::from_hex(&value.split('-').skip(1).collect::<String>())
+                            .map_err(map_err)?;
+                    trace_id_collected = true;
+                }
+                Self::PARENT if !parent_id_collected => {
+                    xray_header.parent_id = SpanId::from_hex(value).map_err(map_err)?;
+                    parent_id_collected = true;
+                }
+                Self::SAMPLE if !sampled_collected => {
+                    xray_header.sampled = match value {
+                        "0" => TraceFlags::NOT_SAMPLED,
+                        "1" => TraceFlags::SAMPLED,
+                        _ => return Err("Invalid Trace header".to_owned()),
+                    };
+                    sampled_collected = true;
+                }
+                Self::LINEAGE => {
+                    // Ignored
+                }
+                // Ignore unrecognized keys — the X-Ray header format may be extended
+                // with new fields in the future
+                _ => {}
+            }
+        }
+
+        if !(trace_id_collected && parent_id_collected && sampled_collected) {
+            return Err("Invalid Trace header".to_owned());
+        }
+
+        Ok(xray_header)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::{SpanId, TraceFlags, TraceId};
+
+    // Tests for XRayTraceHeader::from_str — comprehensive
+
+    #[test]
+    fn xray_trace_header_valid_sampled_1() {
+        let header = "Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=1";
+        let parsed: XRayTraceHeader = header.parse().unwrap();
+
+        assert_eq!(
+            parsed.trace_id,
+            TraceId::from_hex("5759e988bd862e3fe1be46a994272793").unwrap()
+        );
+        assert_eq!(
+            parsed.parent_id,
+            SpanId::from_hex("53995c3f42cd8ad8").unwrap()
+        );
+        assert_eq!(parsed.sampled, TraceFlags::SAMPLED);
+    }
+
+    #[test]
+    fn xray_trace_header_display_round_trip() {
+        let header = "Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=0";
+        let parsed: XRayTraceHeader = header.parse().unwrap();
+        assert_eq!(parsed.to_string(), header);
+    }
+
+    #[test]
+    fn xray_trace_header_valid_sampled_0() {
+        let header = "Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=0";
+        let parsed: XRayTraceHeader = header.parse().unwrap();
+
+        assert_eq!(
+            parsed.trace_id,
+            TraceId::from_hex("5759e988bd862e3fe1be46a994272793").unwrap()
+        );
+        assert_eq!(
+            parsed.parent_id,
+            SpanId::from_hex("53995c3f42cd8ad8").unwrap()
+        );
+        assert_eq!(parsed.sampled, TraceFlags::NOT_SAMPLED);
+    }
+
+    #[test]
+    fn xray_trace_header_valid_with_lineage_field() {
+        let header = "Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=1;Lineage=a87bd80c:1|68fd508a:5|c512fbe3:2";
+        let parsed: XRayTraceHeader = header.parse().unwrap();
+
+        assert_eq!(
+            parsed.trace_id,
+            TraceId::from_hex("5759e988bd862e3fe1be46a994272793").unwrap()
+        );
+        assert_eq!(
+            parsed.parent_id,
+            SpanId::from_hex("53995c3f42cd8ad8").unwrap()
+        );
+        assert_eq!(parsed.sampled, TraceFlags::SAMPLED);
+    }
+
+    #[test]
+    fn xray_trace_header_valid_with_unknown_fields() {
+        let header = "Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=1;FutureField=somevalue";
+        let parsed: XRayTraceHeader = header.parse().unwrap();
+
+        assert_eq!(
+            parsed.trace_id,
+            TraceId::from_hex("5759e988bd862e3fe1be46a994272793").unwrap()
+        );
+        assert_eq!(
+            parsed.parent_id,
+            SpanId::from_hex("53995c3f42cd8ad8").unwrap()
+        );
+        assert_eq!(parsed.sampled, TraceFlags::SAMPLED);
+    }
+
+    #[test]
+    fn xray_trace_header_missing_parent_field() {
+        let header = "Root=1-5759e988-bd862e3fe1be46a994272793;Sampled=1";
+        let result: Result<XRayTraceHeader, _> = header.parse();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn xray_trace_header_invalid_sampled_value() {
+        let header = "Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=2";
+        let result: Result<XRayTraceHeader, _> = header.parse();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn xray_trace_header_empty_string() {
+        let result: Result<XRayTraceHeader, _> = "".parse();
+        assert!(result.is_err());
+    }
+}